@@ -1,8 +1,11 @@
+use crate::batch::run_pipeline;
+use crate::errors::error;
 use crate::options::{
-    BatchOptions, CacheOptions, FileOptions, Options, SharedOptions, SpectrogramOptions,
+    BatchOptions, CacheOptions, ClientOptions, FileOptions, ImageHostOptions, LoudnessOptions,
+    NamingOptions, Options, RunnerOptions, SharedOptions, SpectrogramOptions, TagOptions,
     TargetOptions, VerifyOptions,
 };
-use crate::queue::Queue;
+use crate::queue::{Queue, QueueItem};
 use crate::source::*;
 use crate::spectrogram::SpectrogramCommand;
 use crate::transcode::TranscodeCommand;
@@ -13,18 +16,43 @@ use di::{injectable, Ref, RefMut};
 use log::{debug, error, info, trace, warn};
 use reqwest::StatusCode;
 use rogue_logging::Error;
-use tokio::time::sleep;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::sync::Mutex;
+use tokio::time::{sleep_until, Instant};
+use tokio::try_join;
 
 /// Batch a FLAC source is suitable for transcoding.
+///
+/// Items are processed by a pipeline of concurrent stages connected by channels:
+///
+/// 1. [`BatchCommand::prepare`] gets and verifies each source up to `verify_ahead` items
+///    ahead of `process`
+/// 2. [`BatchCommand::process`] generates spectrograms and transcodes
+/// 3. [`BatchCommand::upload`] uploads after `wait_before_upload`
+/// 4. [`BatchCommand::record`] saves each item to the queue
+///
+/// Verification of the next sources overlaps with the current transcode, and uploads wait
+/// independently so the [`JobRunner`](crate::jobs::JobRunner) isn't left idle. Jobs still
+/// share the CPU budget of [`RunnerOptions`].
+///
+/// The API client is locked for the duration of each request so API calls by `prepare` and
+/// `upload` are serialized by a gate. `prepare` only holds the gate while it gets the
+/// source and its torrent file so uploads don't wait for verification.
 #[injectable]
 pub struct BatchCommand {
     cache_options: Ref<CacheOptions>,
     shared_options: Ref<SharedOptions>,
+    naming_options: Ref<NamingOptions>,
     verify_options: Ref<VerifyOptions>,
     target_options: Ref<TargetOptions>,
     spectrogram_options: Ref<SpectrogramOptions>,
     file_options: Ref<FileOptions>,
     batch_options: Ref<BatchOptions>,
+    client_options: Ref<ClientOptions>,
+    image_host_options: Ref<ImageHostOptions>,
+    loudness_options: Ref<LoudnessOptions>,
+    tag_options: Ref<TagOptions>,
+    runner_options: Ref<RunnerOptions>,
     source_provider: RefMut<SourceProvider>,
     verify: RefMut<VerifyCommand>,
     spectrogram: Ref<SpectrogramCommand>,
@@ -37,27 +65,23 @@ impl BatchCommand {
     /// Execute [`BatchCommand`] from the CLI.
     ///
     /// Returns `true` if the batch process succeeds.
-    #[allow(clippy::too_many_lines)]
     pub async fn execute_cli(&mut self) -> Result<bool, Error> {
         if !self.cache_options.validate()
             || !self.shared_options.validate()
+            || !self.naming_options.validate()
             || !self.verify_options.validate()
             || !self.target_options.validate()
             || !self.spectrogram_options.validate()
             || !self.file_options.validate()
             || !self.batch_options.validate()
+            || !self.client_options.validate()
+            || !self.image_host_options.validate()
+            || !self.loudness_options.validate()
+            || !self.tag_options.validate()
+            || !self.runner_options.validate()
         {
             return Ok(false);
         }
-        let mut queue = self.queue.write().expect("Queue should be writeable");
-        let mut source_provider = self
-            .source_provider
-            .write()
-            .expect("SourceProvider should be writable");
-        let spectrogram_enabled = self
-            .batch_options
-            .spectrogram
-            .expect("spectrogram should be set");
         let transcode_enabled = self
             .batch_options
             .transcode
@@ -72,15 +96,26 @@ impl BatchCommand {
             .indexer
             .clone()
             .expect("indexer should be set");
-        let limit = self.batch_options.get_limit();
-        let items = queue
-            .get_unprocessed(
-                indexer.clone(),
-                transcode_enabled,
-                upload_enabled,
-                retry_failed_transcodes,
-            )
-            .await?;
+        let items = {
+            let mut queue = self.queue.write().expect("Queue should be writeable");
+            let hashes = queue
+                .get_unprocessed(
+                    indexer.clone(),
+                    transcode_enabled,
+                    upload_enabled,
+                    retry_failed_transcodes,
+                )
+                .await?;
+            let mut items = Vec::new();
+            for hash in hashes {
+                if let Some(item) = queue.get(hash)? {
+                    items.push(item);
+                } else {
+                    error!("{} to retrieve {hash} from the queue", "Failed".bold());
+                }
+            }
+            items
+        };
         if items.is_empty() {
             info!(
                 "{} items in the queue for {}",
@@ -96,120 +131,276 @@ impl BatchCommand {
             items.len(),
             indexer.to_uppercase()
         );
-        let mut count = 0;
-        for hash in items {
-            let Some(mut item) = queue.get(hash)? else {
-                error!("{} to retrieve {hash} from the queue", "Failed".bold());
-                continue;
-            };
-            trace!("{} {item}", "Processing".bold());
-            let Some(id) = item.id else {
-                debug!("{} {item} as it doesn't have an id", "Skipping".bold());
-                let status = VerifyStatus::from_issue(SourceIssue::IdError {
-                    details: "missing id".to_owned(),
-                });
-                item.verify = Some(status);
-                queue.set(item).await?;
-                continue;
-            };
-            let source = match source_provider.get(id).await {
-                Ok(source) => source,
-                Err(issue) => {
-                    if let SourceIssue::ApiResponse {
-                        action: _,
-                        status_code,
-                        error,
-                    } = issue.clone()
-                    {
-                        let reason = StatusCode::from_u16(status_code).map_or_else(
-                            |_| status_code.to_string(),
-                            |sc| sc.canonical_reason().unwrap_or("").to_owned(),
-                        );
-                        if status_code == 429 || status_code >= 500 {
-                            warn!("{} {item} due to {reason}", "Skipping".bold());
-                            warn!("{error}");
-                            warn!("This is likely to be a temporary issue with the API.");
-                            warn!("If it persists, please submit an issue on GitHub.");
-                        } else {
-                            debug!("{} {item} due to {reason}", "Skipping".bold());
-                            debug!("{error}");
-                            item.verify = Some(VerifyStatus::from_issue(issue));
-                            queue.set(item).await?;
-                        }
+        // The stages are boxed as together their futures are large
+        let count = Box::pin(self.run_stages(items)).await?;
+        info!("{} batch process of {count} items", "Completed".bold());
+        Ok(true)
+    }
+
+    /// Run the stages of the batch on `items`.
+    ///
+    /// Returns the number of items that count towards the batch limit.
+    async fn run_stages(&self, items: Vec<QueueItem>) -> Result<usize, Error> {
+        let verify_ahead = self
+            .batch_options
+            .verify_ahead
+            .expect("verify_ahead should be set");
+        let limit = self.batch_options.get_limit();
+        let (upload_tx, upload_rx) = unbounded_channel();
+        let (record_tx, record_rx) = unbounded_channel();
+        let api_gate = &Mutex::new(());
+        let pipeline = run_pipeline(
+            items,
+            verify_ahead,
+            limit,
+            {
+                let record = record_tx.clone();
+                move |item| self.prepare(item, api_gate, record.clone())
+            },
+            {
+                let record = record_tx.clone();
+                move |(item, source)| self.process(item, source, upload_tx.clone(), record.clone())
+            },
+            {
+                let record = record_tx.clone();
+                move |(item, _source)| {
+                    debug!(
+                        "{} {item} as the batch limit was reached",
+                        "Skipping".bold()
+                    );
+                    // The verify status is recorded so it isn't repeated by the next batch
+                    send(&record, item)
+                }
+            },
+        );
+        let (count, (), ()) = try_join!(
+            pipeline,
+            self.upload(upload_rx, api_gate, record_tx),
+            self.record(record_rx),
+        )?;
+        Ok(count)
+    }
+
+    /// Get the [`Source`] of an item and verify it.
+    ///
+    /// Returns the verified item and its [`Source`] to be processed.
+    ///
+    /// Items that can't be verified are sent to [`BatchCommand::record`].
+    async fn prepare(
+        &self,
+        mut item: QueueItem,
+        api_gate: &Mutex<()>,
+        record: UnboundedSender<QueueItem>,
+    ) -> Result<Option<(QueueItem, Source)>, Error> {
+        trace!("{} {item}", "Processing".bold());
+        let Some(id) = item.id else {
+            debug!("{} {item} as it doesn't have an id", "Skipping".bold());
+            let status = VerifyStatus::from_issue(SourceIssue::IdError {
+                details: "missing id".to_owned(),
+            });
+            item.verify = Some(status);
+            send(&record, item)?;
+            return Ok(None);
+        };
+        let content_dir = item
+            .client
+            .as_ref()
+            .map(|client| client.save_path.as_path());
+        let gate = api_gate.lock().await;
+        let source = match self
+            .source_provider
+            .write()
+            .expect("SourceProvider should be writable")
+            .get_with_content_dir(id, content_dir)
+            .await
+        {
+            Ok(source) => source,
+            Err(issue) => {
+                if let SourceIssue::ApiResponse {
+                    action: _,
+                    status_code,
+                    error,
+                } = issue.clone()
+                {
+                    let reason = StatusCode::from_u16(status_code).map_or_else(
+                        |_| status_code.to_string(),
+                        |sc| sc.canonical_reason().unwrap_or("").to_owned(),
+                    );
+                    if status_code == 429 || status_code >= 500 {
+                        warn!("{} {item} due to {reason}", "Skipping".bold());
+                        warn!("{error}");
+                        warn!("This is likely to be a temporary issue with the API.");
+                        warn!("If it persists, please submit an issue on GitHub.");
                     } else {
-                        debug!("{} {item}", "Skipping".bold());
-                        debug!("{issue}");
+                        debug!("{} {item} due to {reason}", "Skipping".bold());
+                        debug!("{error}");
                         item.verify = Some(VerifyStatus::from_issue(issue));
-                        queue.set(item).await?;
-                    }
-                    continue;
-                }
-            };
-            let status = self
-                .verify
-                .write()
-                .expect("VerifyCommand should be writeable")
-                .execute(&source)
-                .await;
-            if status.verified {
-                debug!("{} {}", "Verified".bold(), source);
-                item.verify = Some(status);
-            } else {
-                debug!("{} {source}", "Skipping".bold());
-                debug!("{} for transcoding {}", "Unsuitable".bold(), source);
-                if let Some(issues) = &status.issues {
-                    for issue in issues {
-                        debug!("{issue}");
+                        send(&record, item)?;
                     }
+                } else {
+                    debug!("{} {item}", "Skipping".bold());
+                    debug!("{issue}");
+                    item.verify = Some(VerifyStatus::from_issue(issue));
+                    send(&record, item)?;
                 }
-                item.verify = Some(status);
-                queue.set(item).await?;
-                continue;
+                return Ok(None);
             }
-            if spectrogram_enabled {
-                let status = self.spectrogram.execute(&source).await;
-                if let Some(error) = &status.error {
-                    warn!("{error}");
-                }
-                item.spectrogram = Some(status);
+        };
+        let mut verify = self
+            .verify
+            .write()
+            .expect("VerifyCommand should be writeable");
+        if let Err(issue) = verify.download_torrent(&source).await {
+            debug!("{} {item}", "Skipping".bold());
+            debug!("{issue}");
+            item.verify = Some(VerifyStatus::from_issue(issue));
+            send(&record, item)?;
+            return Ok(None);
+        }
+        // Verification makes no other API calls so uploads can continue while it runs
+        drop(gate);
+        let status = verify.execute(&source).await;
+        drop(verify);
+        if let Some(warnings) = &status.warnings {
+            for warning in warnings {
+                warn!("{warning}");
             }
-            if transcode_enabled {
-                let status = self.transcode.execute(&source).await;
-                if let Some(error) = &status.error {
-                    error.log();
-                }
-                if status.success {
-                    item.transcode = Some(status);
-                } else {
-                    item.transcode = Some(status);
-                    queue.set(item).await?;
-                    continue;
-                }
-                if upload_enabled {
-                    if let Some(wait_before_upload) = self.batch_options.get_wait_before_upload() {
-                        info!("{} {wait_before_upload:?} before upload", "Waiting".bold());
-                        sleep(wait_before_upload).await;
-                    }
-                    let status = self
-                        .upload
-                        .write()
-                        .expect("UploadCommand should be writeable")
-                        .execute(&source)
-                        .await;
-                    // Errors were already logged in UploadCommand::Execute()
-                    item.upload = Some(status);
+        }
+        if status.verified {
+            debug!("{} {}", "Verified".bold(), source);
+            item.verify = Some(status);
+        } else {
+            debug!("{} {source}", "Skipping".bold());
+            debug!("{} for transcoding {}", "Unsuitable".bold(), source);
+            if let Some(issues) = &status.issues {
+                for issue in issues {
+                    debug!("{issue}");
                 }
             }
-            queue.set(item).await?;
-            count += 1;
-            if let Some(limit) = limit {
-                if count >= limit {
-                    info!("{} batch limit: {limit}", "Reached".bold());
-                    break;
-                }
+            item.verify = Some(status);
+            send(&record, item)?;
+            return Ok(None);
+        }
+        Ok(Some((item, source)))
+    }
+
+    /// Generate spectrograms and transcode a verified item.
+    ///
+    /// Transcoded items are sent to [`BatchCommand::upload`] if upload is enabled,
+    /// otherwise to [`BatchCommand::record`].
+    ///
+    /// Returns `true` if the item counts towards the batch limit.
+    async fn process(
+        &self,
+        mut item: QueueItem,
+        source: Source,
+        upload: UnboundedSender<PendingUpload>,
+        record: UnboundedSender<QueueItem>,
+    ) -> Result<bool, Error> {
+        let spectrogram_enabled = self
+            .batch_options
+            .spectrogram
+            .expect("spectrogram should be set");
+        let transcode_enabled = self
+            .batch_options
+            .transcode
+            .expect("transcode should be set");
+        let upload_enabled = self.batch_options.upload.expect("upload should be set");
+        if spectrogram_enabled {
+            let status = self.spectrogram.execute(&source).await;
+            if let Some(error) = &status.error {
+                warn!("{error}");
             }
+            item.spectrogram = Some(status);
+        }
+        if transcode_enabled {
+            let status = self.transcode.execute(&source).await;
+            if let Some(error) = &status.error {
+                error.log();
+            }
+            let success = status.success;
+            item.transcode = Some(status);
+            if !success {
+                send(&record, item)?;
+                return Ok(false);
+            }
+        }
+        if upload_enabled {
+            let wait_before_upload = self.batch_options.get_wait_before_upload();
+            if let Some(wait_before_upload) = wait_before_upload {
+                info!(
+                    "{} {wait_before_upload:?} before uploading {source}",
+                    "Waiting".bold()
+                );
+            }
+            let ready = Instant::now() + wait_before_upload.unwrap_or_default();
+            send(
+                &upload,
+                PendingUpload {
+                    item,
+                    source,
+                    ready,
+                },
+            )?;
+        } else {
+            send(&record, item)?;
         }
-        info!("{} batch process of {count} items", "Completed".bold());
         Ok(true)
     }
+
+    /// Upload each transcoded item once its `wait_before_upload` has elapsed.
+    ///
+    /// Uploaded items are sent to [`BatchCommand::record`].
+    async fn upload(
+        &self,
+        mut pending: UnboundedReceiver<PendingUpload>,
+        api_gate: &Mutex<()>,
+        record: UnboundedSender<QueueItem>,
+    ) -> Result<(), Error> {
+        while let Some(PendingUpload {
+            mut item,
+            source,
+            ready,
+        }) = pending.recv().await
+        {
+            sleep_until(ready).await;
+            let _gate = api_gate.lock().await;
+            let status = self
+                .upload
+                .write()
+                .expect("UploadCommand should be writeable")
+                .execute(&source, item.spectrogram.as_ref())
+                .await;
+            // Errors were already logged in UploadCommand::Execute()
+            item.upload = Some(status);
+            send(&record, item)?;
+        }
+        Ok(())
+    }
+
+    /// Save each item to the queue.
+    async fn record(&self, mut items: UnboundedReceiver<QueueItem>) -> Result<(), Error> {
+        while let Some(item) = items.recv().await {
+            self.queue
+                .write()
+                .expect("Queue should be writeable")
+                .set(item)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+/// A transcoded item waiting to be uploaded.
+struct PendingUpload {
+    item: QueueItem,
+    source: Source,
+    /// Time after which the item can be uploaded
+    ready: Instant,
+}
+
+fn send<T>(sender: &UnboundedSender<T>, value: T) -> Result<(), Error> {
+    sender
+        .send(value)
+        .map_err(|_| error("send to batch stage", "Stage has stopped".to_owned()))
 }
@@ -0,0 +1,72 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use colored::Colorize;
+use log::info;
+use rogue_logging::Error;
+use tokio::sync::mpsc::channel;
+use tokio::try_join;
+
+/// Run `prepare` on each item then `process` each prepared item in order.
+///
+/// Preparation runs ahead of processing by up to `ahead` items so the next items are ready
+/// as soon as processing finishes. `prepare` returns `None` for an item that should not
+/// be processed.
+///
+/// `process` returns `true` if the item counts towards `limit`. Once `limit` is reached no
+/// more items are prepared and the items that were prepared ahead are passed to `skip`
+/// instead of `process` so their preparation isn't lost.
+///
+/// Returns the number of items that count towards `limit`.
+pub async fn run_pipeline<T, U, P, PF, X, XF, S>(
+    items: Vec<T>,
+    ahead: usize,
+    limit: Option<usize>,
+    mut prepare: P,
+    mut process: X,
+    mut skip: S,
+) -> Result<usize, Error>
+where
+    P: FnMut(T) -> PF,
+    PF: Future<Output = Result<Option<U>, Error>>,
+    X: FnMut(U) -> XF,
+    XF: Future<Output = Result<bool, Error>>,
+    S: FnMut(U) -> Result<(), Error>,
+{
+    let (sender, mut receiver) = channel(ahead);
+    let limit_reached = &AtomicBool::new(false);
+    let producer = async move {
+        for item in items {
+            if limit_reached.load(Ordering::SeqCst) {
+                break;
+            }
+            let Some(prepared) = prepare(item).await? else {
+                continue;
+            };
+            if sender.send(prepared).await.is_err() {
+                break;
+            }
+        }
+        Ok(())
+    };
+    let consumer = async move {
+        let mut count = 0;
+        while let Some(prepared) = receiver.recv().await {
+            if limit_reached.load(Ordering::SeqCst) {
+                skip(prepared)?;
+                continue;
+            }
+            if !process(prepared).await? {
+                continue;
+            }
+            count += 1;
+            if limit.is_some_and(|limit| count >= limit) {
+                info!("{} batch limit: {count}", "Reached".bold());
+                limit_reached.store(true, Ordering::SeqCst);
+            }
+        }
+        Ok(count)
+    };
+    let (count, ()) = try_join!(consumer, producer)?;
+    Ok(count)
+}
@@ -1,3 +1,7 @@
 pub use batch_command::*;
+pub use batch_pipeline::*;
 
 pub(crate) mod batch_command;
+pub(crate) mod batch_pipeline;
+#[cfg(test)]
+mod tests;
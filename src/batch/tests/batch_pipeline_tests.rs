@@ -0,0 +1,155 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::time::sleep;
+
+use crate::batch::run_pipeline;
+
+#[tokio::test]
+async fn run_pipeline_processes_in_order() {
+    // Arrange
+    let items: Vec<u64> = (0..10).collect();
+    let processed = Arc::new(Mutex::new(Vec::new()));
+
+    // Act
+    let count = run_pipeline(
+        items,
+        3,
+        None,
+        |item| async move {
+            // Later items are prepared faster so order must not depend on timing
+            sleep(Duration::from_millis(10 - item)).await;
+            Ok((item % 3 != 0).then_some(item))
+        },
+        |item| {
+            let processed = processed.clone();
+            async move {
+                processed
+                    .lock()
+                    .expect("lock should not be poisoned")
+                    .push(item);
+                Ok(true)
+            }
+        },
+        |_| Ok(()),
+    )
+    .await
+    .expect("pipeline should not fail");
+
+    // Assert
+    let processed = processed
+        .lock()
+        .expect("lock should not be poisoned")
+        .clone();
+    assert_eq!(processed, vec![1, 2, 4, 5, 7, 8]);
+    assert_eq!(count, 6);
+}
+
+#[tokio::test]
+async fn run_pipeline_prepares_ahead() {
+    // Arrange
+    let items: Vec<usize> = (0..10).collect();
+    let prepared = Arc::new(AtomicUsize::new(0));
+    let prepared_while_processing = Arc::new(Mutex::new(Vec::new()));
+
+    // Act
+    run_pipeline(
+        items,
+        1,
+        None,
+        |item| {
+            let prepared = prepared.clone();
+            async move {
+                prepared.fetch_add(1, Ordering::SeqCst);
+                Ok(Some(item))
+            }
+        },
+        |_| {
+            let prepared = prepared.clone();
+            let prepared_while_processing = prepared_while_processing.clone();
+            async move {
+                sleep(Duration::from_millis(50)).await;
+                prepared_while_processing
+                    .lock()
+                    .expect("lock should not be poisoned")
+                    .push(prepared.load(Ordering::SeqCst));
+                Ok(true)
+            }
+        },
+        |_| Ok(()),
+    )
+    .await
+    .expect("pipeline should not fail");
+
+    // Assert
+    let prepared_while_processing = prepared_while_processing
+        .lock()
+        .expect("lock should not be poisoned")
+        .clone();
+    assert_eq!(prepared_while_processing.len(), 10);
+    // The item being processed, one item in the channel and one item waiting to send
+    assert_eq!(prepared_while_processing.first(), Some(&3));
+    for (index, count) in prepared_while_processing.iter().enumerate() {
+        assert!(
+            *count <= index + 3,
+            "prepared {count} items while processing {index}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn run_pipeline_stops_at_limit() {
+    // Arrange
+    let items: Vec<usize> = (0..10).collect();
+    let prepared = Arc::new(AtomicUsize::new(0));
+    let processed = Arc::new(Mutex::new(Vec::new()));
+    let skipped = Arc::new(Mutex::new(Vec::new()));
+
+    // Act
+    let count = run_pipeline(
+        items,
+        1,
+        Some(2),
+        |item| {
+            let prepared = prepared.clone();
+            async move {
+                prepared.fetch_add(1, Ordering::SeqCst);
+                Ok(Some(item))
+            }
+        },
+        |item| {
+            let processed = processed.clone();
+            async move {
+                sleep(Duration::from_millis(10)).await;
+                processed
+                    .lock()
+                    .expect("lock should not be poisoned")
+                    .push(item);
+                // The first item does not count towards the limit
+                Ok(item != 0)
+            }
+        },
+        |item| {
+            skipped
+                .lock()
+                .expect("lock should not be poisoned")
+                .push(item);
+            Ok(())
+        },
+    )
+    .await
+    .expect("pipeline should not fail");
+
+    // Assert
+    let processed = processed
+        .lock()
+        .expect("lock should not be poisoned")
+        .clone();
+    let skipped = skipped.lock().expect("lock should not be poisoned").clone();
+    assert_eq!(count, 2);
+    assert_eq!(processed, vec![0, 1, 2]);
+    // Items prepared ahead of the limit are skipped rather than lost
+    assert_eq!(skipped, vec![3, 4]);
+    assert_eq!(prepared.load(Ordering::SeqCst), 5);
+}
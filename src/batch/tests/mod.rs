@@ -0,0 +1 @@
+mod batch_pipeline_tests;
@@ -3,11 +3,13 @@ use clap::Subcommand;
 use crate::options::source_arg::SourceArg;
 use crate::options::verify_options::VerifyOptions;
 use crate::options::{
-    BatchOptions, CacheOptions, FileOptions, QueueAddArgs, RunnerOptions, SharedOptions,
-    SpectrogramOptions, TargetOptions, UploadOptions,
+    BatchOptions, CacheOptions, ClientOptions, FileOptions, ImageHostOptions, LoudnessOptions,
+    NamingOptions, QueueAddArgs, RunnerOptions, SharedOptions, SpectrogramOptions, TagOptions,
+    TargetOptions, UploadOptions,
 };
 
 /// Cli sub-commands and arguments
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand, Debug, Clone)]
 pub enum CommandArguments {
     /// Read the config file if it exists and concatenate default values.
@@ -18,6 +20,8 @@ pub enum CommandArguments {
         #[command(flatten)]
         shared: SharedOptions,
         #[command(flatten)]
+        naming: NamingOptions,
+        #[command(flatten)]
         target: TargetOptions,
         #[command(flatten)]
         verify: VerifyOptions,
@@ -31,6 +35,14 @@ pub enum CommandArguments {
         batch: BatchOptions,
         #[command(flatten)]
         cache: CacheOptions,
+        #[command(flatten)]
+        client: ClientOptions,
+        #[command(flatten)]
+        image_host: ImageHostOptions,
+        #[command(flatten)]
+        loudness: LoudnessOptions,
+        #[command(flatten)]
+        tag: TagOptions,
     },
 
     /// Add FLAC sources to the queue without transcoding
@@ -46,8 +58,12 @@ pub enum CommandArguments {
         #[command(flatten)]
         shared: SharedOptions,
         #[command(flatten)]
+        naming: NamingOptions,
+        #[command(flatten)]
         spectrogram: SpectrogramOptions,
         #[command(flatten)]
+        image_host: ImageHostOptions,
+        #[command(flatten)]
         runner: RunnerOptions,
     },
 
@@ -58,11 +74,17 @@ pub enum CommandArguments {
         #[command(flatten)]
         shared: SharedOptions,
         #[command(flatten)]
+        naming: NamingOptions,
+        #[command(flatten)]
         target: TargetOptions,
         #[command(flatten)]
         file: FileOptions,
         #[command(flatten)]
         runner: RunnerOptions,
+        #[command(flatten)]
+        loudness: LoudnessOptions,
+        #[command(flatten)]
+        tag: TagOptions,
     },
 
     /// Upload transcodes of a FLAC source.
@@ -72,9 +94,17 @@ pub enum CommandArguments {
         #[command(flatten)]
         shared: SharedOptions,
         #[command(flatten)]
+        naming: NamingOptions,
+        #[command(flatten)]
         target: TargetOptions,
         #[command(flatten)]
         upload: UploadOptions,
+        #[command(flatten)]
+        client: ClientOptions,
+        #[command(flatten)]
+        image_host: ImageHostOptions,
+        #[command(flatten)]
+        tag: TagOptions,
     },
 
     /// Verify a FLAC source is suitable for transcoding.
@@ -84,15 +114,21 @@ pub enum CommandArguments {
         #[command(flatten)]
         shared: SharedOptions,
         #[command(flatten)]
+        naming: NamingOptions,
+        #[command(flatten)]
         target: TargetOptions,
         #[command(flatten)]
         verify: VerifyOptions,
+        #[command(flatten)]
+        loudness: LoudnessOptions,
+        #[command(flatten)]
+        tag: TagOptions,
     },
 }
 
 #[derive(Subcommand, Debug, Clone)]
 pub enum QueueCommandArguments {
-    /// Add a directory of `.torrent` files to the queue
+    /// Add a directory of `.torrent` files, or the completed torrents of a torrent client, to the queue
     Add {
         #[command(flatten)]
         shared: SharedOptions,
@@ -100,6 +136,8 @@ pub enum QueueCommandArguments {
         cache: CacheOptions,
         #[command(flatten)]
         args: QueueAddArgs,
+        #[command(flatten)]
+        client: ClientOptions,
     },
 
     /// List the sources in the queue
@@ -22,15 +22,6 @@ pub const FLAC: &str = "flac.exe";
 #[cfg(not(target_os = "windows"))]
 pub const FLAC: &str = "flac";
 
-// TODO MUST confirm binary name on windows
-/// Path to the imagemagick convert binary.
-#[cfg(target_os = "windows")]
-pub const CONVERT: &str = "convert.exe";
-
-/// Path to the imagemagick convert binary.
-#[cfg(not(target_os = "windows"))]
-pub const CONVERT: &str = "convert";
-
 /// Path to the imdl binary.
 #[cfg(target_os = "windows")]
 pub const IMDL: &str = "imdl.exe";
@@ -38,11 +29,3 @@ pub const IMDL: &str = "imdl.exe";
 /// Path to the imdl binary.
 #[cfg(not(target_os = "windows"))]
 pub const IMDL: &str = "imdl";
-
-/// Path to the eyeD3 binary.
-#[cfg(target_os = "windows")]
-pub const EYED3: &str = "eyeD3.exe";
-
-/// Path to the imdl binary.
-#[cfg(not(target_os = "windows"))]
-pub const EYED3: &str = "eyeD3";
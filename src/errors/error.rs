@@ -3,6 +3,18 @@ use tokio::task::JoinError;
 
 use crate::errors::CommandError;
 
+/// Domain of a file system error.
+pub const IO_DOMAIN: &str = "file system";
+
+/// Domain of a process that was terminated by a signal.
+pub const SIGNAL_DOMAIN: &str = "process signal";
+
+/// Domain of a process that exited with a non-zero exit code.
+pub const EXIT_DOMAIN: &str = "process exit";
+
+/// Domain of a job that exceeded its timeout.
+pub const TIMEOUT_DOMAIN: &str = "timeout";
+
 #[allow(clippy::absolute_paths)]
 pub fn error(action: &str, message: String) -> Error {
     Error {
@@ -39,15 +51,24 @@ pub fn io_error(error: std::io::Error, action: &str) -> Error {
     Error {
         action: action.to_owned(),
         message: error.to_string(),
-        domain: Some("file system".to_owned()),
+        domain: Some(IO_DOMAIN.to_owned()),
         ..Error::default()
     }
 }
 
-pub fn output_error(error: CommandError, action: &str, domain: &str) -> Error {
+/// Create an [`Error`] for a process that failed.
+///
+/// The domain is [`SIGNAL_DOMAIN`] if the process was terminated by a signal, otherwise
+/// [`EXIT_DOMAIN`], and `origin` prefixes the message.
+pub fn output_error(error: CommandError, action: &str, origin: &str) -> Error {
+    let domain = if error.exit_signal.is_some() {
+        SIGNAL_DOMAIN
+    } else {
+        EXIT_DOMAIN
+    };
     Error {
         action: action.to_owned(),
-        message: error.to_string(),
+        message: format!("{origin}: {error}"),
         domain: Some(domain.to_owned()),
         ..Error::default()
     }
@@ -79,3 +100,13 @@ pub fn yaml_error(error: serde_yaml::Error, action: &str) -> Error {
         ..Error::default()
     }
 }
+
+pub fn http_error(error: reqwest::Error, action: &str) -> Error {
+    Error {
+        action: action.to_owned(),
+        message: error.to_string(),
+        domain: Some("HTTP".to_owned()),
+        status_code: error.status().map(|status| status.as_u16()),
+        ..Error::default()
+    }
+}
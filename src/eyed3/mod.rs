@@ -1,5 +0,0 @@
-pub use eyed3_command::*;
-
-pub(crate) mod eyed3_command;
-#[cfg(test)]
-mod tests;
@@ -1 +0,0 @@
-mod eyed3_tests;
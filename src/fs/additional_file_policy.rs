@@ -0,0 +1,86 @@
+use crate::errors::error;
+use crate::formats::TargetFormat;
+use crate::fs::{AdditionalFile, FileAction, FileRule};
+use crate::options::FileOptions;
+use globset::{Error as GlobError, Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use rogue_logging::Error;
+
+/// Decide which [`FileAction`] applies to an [`AdditionalFile`] for a [`TargetFormat`].
+pub struct AdditionalFilePolicy {
+    include: GlobSet,
+    exclude: GlobSet,
+    rules: Vec<FileRule>,
+}
+
+impl AdditionalFilePolicy {
+    /// Create an [`AdditionalFilePolicy`] from [`FileOptions`].
+    pub fn new(options: &FileOptions) -> Result<Self, Error> {
+        let include = options
+            .additional_include
+            .as_ref()
+            .expect("additional_include should be set");
+        let exclude = options
+            .additional_exclude
+            .as_ref()
+            .expect("additional_exclude should be set");
+        let rules = options
+            .additional_rules
+            .clone()
+            .expect("additional_rules should be set");
+        Ok(Self {
+            include: get_glob_set(include)?,
+            exclude: get_glob_set(exclude)?,
+            rules,
+        })
+    }
+
+    /// Parse a glob pattern.
+    ///
+    /// `*` does not match the path separator, use `**` to match subdirectories.
+    pub fn get_glob(pattern: &str) -> Result<Glob, GlobError> {
+        GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .case_insensitive(true)
+            .build()
+    }
+
+    /// Get the [`FileAction`] for a `file` transcoded to `target`.
+    ///
+    /// Files outside the include globs, inside the exclude globs, or without
+    /// a matching rule are excluded.
+    #[must_use]
+    pub fn get_action(&self, file: &AdditionalFile, target: TargetFormat) -> FileAction {
+        let relative_path = file.sub_dir.join(&file.file_name);
+        if !self.include.is_match(&relative_path) || self.exclude.is_match(&relative_path) {
+            return FileAction::Exclude;
+        }
+        let Some(extension) = file.path.extension() else {
+            return FileAction::Exclude;
+        };
+        let extension = extension.to_string_lossy();
+        self.rules
+            .iter()
+            .find(|rule| rule.is_match(&extension, target))
+            .map_or(FileAction::Exclude, |rule| rule.action)
+    }
+
+    /// Can the output of `file` for the `from` target be reused for the `to` target?
+    ///
+    /// The output is only the same if the same [`FileAction`] applies to both targets.
+    #[must_use]
+    pub fn is_shared(&self, file: &AdditionalFile, from: TargetFormat, to: TargetFormat) -> bool {
+        self.get_action(file, from) == self.get_action(file, to)
+    }
+}
+
+fn get_glob_set(patterns: &[String]) -> Result<GlobSet, Error> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = AdditionalFilePolicy::get_glob(pattern)
+            .map_err(|e| error("parse additional file glob", e.to_string()))?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map_err(|e| error("build additional file globs", e.to_string()))
+}
@@ -2,7 +2,7 @@ use crate::fs::DirectoryReader;
 use crate::fs::*;
 use colored::Colorize;
 use log::trace;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// A collection of [`FlacFile`].
 pub struct Collector;
@@ -28,12 +28,13 @@ impl Collector {
         collection
     }
 
-    /// Create [`AdditionalFile`] for each additonal file in a directory.
+    /// Create [`AdditionalFile`] for each file in a directory up to `max_depth` subdirectories.
+    ///
+    /// Use [`AdditionalFilePolicy`] to decide which are included.
     #[must_use]
-    pub fn get_additional(source_dir: &PathBuf) -> Vec<AdditionalFile> {
+    pub fn get_additional(source_dir: &PathBuf, max_depth: usize) -> Vec<AdditionalFile> {
         let paths = DirectoryReader::new()
-            .with_max_depth(1)
-            .with_extensions(vec!["jpg", "jpeg", "png"])
+            .with_max_depth(max_depth)
             .read(source_dir)
             .expect("Source directory should be readable");
         let mut collection = Vec::new();
@@ -48,4 +49,22 @@ impl Collector {
         );
         collection
     }
+
+    /// Get the path of each `.log` file in a directory.
+    #[must_use]
+    pub fn get_logs(source_dir: &Path) -> Vec<PathBuf> {
+        DirectoryReader::new()
+            .with_extension("log")
+            .read(source_dir)
+            .expect("Source directory should be readable")
+    }
+
+    /// Get the path of each `.cue` file in a directory.
+    #[must_use]
+    pub fn get_cues(source_dir: &Path) -> Vec<PathBuf> {
+        DirectoryReader::new()
+            .with_extension("cue")
+            .read(source_dir)
+            .expect("Source directory should be readable")
+    }
 }
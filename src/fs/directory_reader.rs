@@ -20,13 +20,6 @@ impl DirectoryReader {
         self
     }
 
-    pub fn with_extensions(&mut self, extensions: Vec<&str>) -> &mut Self {
-        for extension in extensions {
-            self.included_extensions.push(extension.to_owned());
-        }
-        self
-    }
-
     pub fn with_max_depth(&mut self, max_depth: usize) -> &mut Self {
         self.max_depth = Some(max_depth);
         self
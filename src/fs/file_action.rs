@@ -0,0 +1,30 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Action applied to an additional file when it is added to a transcode.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum FileAction {
+    /// Copy the file, or hard link it if `hard_link` is set
+    Copy,
+    /// Hard link the file
+    HardLink,
+    /// Compress the image if it is larger than `max_file_size`, otherwise copy it
+    Compress,
+    /// Exclude the file
+    Exclude,
+}
+
+impl Display for FileAction {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let output = match self {
+            FileAction::Copy => "copy",
+            FileAction::HardLink => "hard-link",
+            FileAction::Compress => "compress",
+            FileAction::Exclude => "exclude",
+        };
+        output.fmt(formatter)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::formats::TargetFormat;
+use crate::fs::FileAction;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Action for additional files with an extension.
+///
+/// Written as `<extension>=<action>` or `<extension>=<action>@<target>,<target>`
+/// to only apply to some target formats.
+///
+/// Examples: `png=compress`, `pdf=copy`, `log=copy@flac`, `txt=exclude@320,v0`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct FileRule {
+    /// Lowercase extension without the leading dot
+    pub extension: String,
+    pub action: FileAction,
+    /// Target formats the rule applies to
+    ///
+    /// If `None` the rule applies to all target formats.
+    pub targets: Option<Vec<TargetFormat>>,
+}
+
+impl FileRule {
+    /// Does the rule apply to a file with `extension` transcoded to `target`?
+    #[must_use]
+    pub fn is_match(&self, extension: &str, target: TargetFormat) -> bool {
+        self.extension.eq_ignore_ascii_case(extension)
+            && self
+                .targets
+                .as_ref()
+                .is_none_or(|targets| targets.contains(&target))
+    }
+}
+
+impl FromStr for FileRule {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (extension, action) = value
+            .split_once('=')
+            .ok_or_else(|| format!("expected <extension>=<action>: {value}"))?;
+        let (action, targets) = match action.split_once('@') {
+            Some((action, targets)) => {
+                let targets = targets
+                    .split(',')
+                    .map(|target| TargetFormat::from_str(target.trim(), true))
+                    .collect::<Result<Vec<_>, _>>()?;
+                (action, Some(targets))
+            }
+            None => (action, None),
+        };
+        let extension = extension.trim().trim_start_matches('.').to_lowercase();
+        if extension.is_empty() {
+            return Err(format!("extension is empty: {value}"));
+        }
+        Ok(FileRule {
+            extension,
+            action: FileAction::from_str(action.trim(), true)?,
+            targets,
+        })
+    }
+}
+
+impl TryFrom<String> for FileRule {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        FileRule::from_str(&value)
+    }
+}
+
+impl From<FileRule> for String {
+    fn from(rule: FileRule) -> Self {
+        rule.to_string()
+    }
+}
+
+impl Display for FileRule {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{}={}", self.extension, self.action)?;
+        if let Some(targets) = &self.targets {
+            let targets: Vec<String> = targets
+                .iter()
+                .map(|target| target.get_name().to_lowercase())
+                .collect();
+            write!(formatter, "@{}", targets.join(","))?;
+        }
+        Ok(())
+    }
+}
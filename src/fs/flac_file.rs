@@ -39,11 +39,6 @@ impl FlacFile {
         }
     }
 
-    #[must_use]
-    pub fn get_path_string(&self) -> String {
-        self.path.to_string_lossy().into_owned()
-    }
-
     pub fn get_stream_info(&self) -> Result<StreamInfo, claxon::Error> {
         let reader = FlacReader::open(&self.path)?;
         Ok(reader.streaminfo())
@@ -0,0 +1,30 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// ID3 version to write to MP3 files.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+pub enum Id3Version {
+    /// ID3v2.3
+    ///
+    /// Better supported by older players.
+    #[serde(rename = "v2.3")]
+    #[value(name = "v2.3")]
+    V23,
+    /// ID3v2.4
+    #[default]
+    #[serde(rename = "v2.4")]
+    #[value(name = "v2.4")]
+    V24,
+}
+
+impl Display for Id3Version {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let output = match self {
+            Id3Version::V23 => "v2.3",
+            Id3Version::V24 => "v2.4",
+        };
+        output.fmt(formatter)
+    }
+}
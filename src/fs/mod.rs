@@ -1,17 +1,29 @@
 pub use additional_file::*;
+pub use additional_file_policy::*;
 pub use collector::*;
 pub use copy_dir::*;
 pub use directory_reader::*;
+pub use file_action::*;
+pub use file_rule::*;
 pub use flac_file::*;
+pub use id3_version::*;
 pub use path_manager::*;
+pub use tag_fixer::*;
+pub use tag_rules::*;
 pub(crate) use tags::*;
 
 mod additional_file;
+pub(crate) mod additional_file_policy;
 pub(crate) mod collector;
 pub(crate) mod copy_dir;
 pub(crate) mod directory_reader;
+pub(crate) mod file_action;
+pub(crate) mod file_rule;
 pub(crate) mod flac_file;
+pub(crate) mod id3_version;
 pub(crate) mod path_manager;
+pub(crate) mod tag_fixer;
+pub(crate) mod tag_rules;
 pub(crate) mod tags;
 #[cfg(test)]
 mod tests;
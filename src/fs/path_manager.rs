@@ -1,22 +1,45 @@
-use std::fs::create_dir;
-use std::path::PathBuf;
-
 use crate::formats::TargetFormat;
-use crate::fs::FlacFile;
+use crate::fs::{Collector, FlacFile};
 use crate::imdl::ImdlCommand;
-use crate::naming::{SpectrogramName, TranscodeName};
-use crate::options::{CacheOptions, SharedOptions};
-use crate::source::Source;
+use crate::naming::{NameTemplates, Shortener, TrackName};
+use crate::options::{CacheOptions, NamingOptions, SharedOptions, TargetOptions};
+use crate::source::{Source, MAX_PATH_LENGTH};
 use di::{injectable, Ref};
 use rogue_logging::Error;
+use std::collections::HashMap;
+use std::fs::create_dir;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Minimum length of a file name before truncating is no longer worthwhile.
+const MIN_TRUNCATED_LENGTH: usize = 16;
 
-#[injectable]
 pub struct PathManager {
     shared_options: Ref<SharedOptions>,
     cache_options: Ref<CacheOptions>,
+    target_options: Ref<TargetOptions>,
+    templates: NameTemplates,
+    /// Transcode directory names that have already been shortened.
+    shortened_names: Mutex<HashMap<(u32, TargetFormat), String>>,
 }
 
+#[injectable]
 impl PathManager {
+    #[must_use]
+    pub fn new(
+        shared_options: Ref<SharedOptions>,
+        cache_options: Ref<CacheOptions>,
+        target_options: Ref<TargetOptions>,
+        naming_options: Ref<NamingOptions>,
+    ) -> Self {
+        Self {
+            shared_options,
+            cache_options,
+            target_options,
+            templates: NameTemplates::new(&naming_options),
+            shortened_names: Mutex::new(HashMap::new()),
+        }
+    }
     #[must_use]
     pub fn get_cache_dir(&self) -> PathBuf {
         self.cache_options
@@ -40,6 +63,22 @@ impl PathManager {
         torrents_dir.join(format!("{id}.{indexer}.torrent"))
     }
 
+    /// Get the path of the [`JobJournal`](crate::jobs::JobJournal) of a source.
+    #[must_use]
+    pub fn get_journal_path(&self, source: &Source) -> PathBuf {
+        let id = source.torrent.id;
+        let indexer = self
+            .shared_options
+            .indexer
+            .clone()
+            .expect("indexer should be set");
+        let journal_dir = self.get_cache_dir().join("journal");
+        if !journal_dir.is_dir() {
+            let _ = create_dir(&journal_dir);
+        }
+        journal_dir.join(format!("{id}.{indexer}.jsonl"))
+    }
+
     #[must_use]
     pub fn get_output_dir(&self) -> PathBuf {
         self.shared_options
@@ -51,13 +90,13 @@ impl PathManager {
     #[must_use]
     pub fn get_spectrogram_dir(&self, source: &Source) -> PathBuf {
         self.get_output_dir()
-            .join(SpectrogramName::get(&source.metadata))
+            .join(self.templates.get_spectrogram_name(&source.metadata))
     }
 
     #[must_use]
     pub fn get_transcode_target_dir(&self, source: &Source, target: TargetFormat) -> PathBuf {
         self.get_output_dir()
-            .join(TranscodeName::get(&source.metadata, target))
+            .join(self.get_transcode_name(source, target))
     }
 
     #[must_use]
@@ -68,10 +107,15 @@ impl PathManager {
         flac: &FlacFile,
     ) -> PathBuf {
         let extension = target.get_file_extension();
-        let filename = flac.file_name.clone() + "." + extension.as_str();
-        self.get_transcode_target_dir(source, target)
-            .join(&flac.sub_dir)
-            .join(filename)
+        let dir = self
+            .get_transcode_target_dir(source, target)
+            .join(&flac.sub_dir);
+        let file_name = if self.is_shorten_enabled() {
+            get_shortened_file_name(&dir, flac, &extension)
+        } else {
+            flac.file_name.clone()
+        };
+        dir.join(file_name + "." + extension.as_str())
     }
 
     #[must_use]
@@ -81,7 +125,10 @@ impl PathManager {
         target: TargetFormat,
         include_indexer: bool,
     ) -> PathBuf {
-        let mut filename = TranscodeName::get(&source.metadata, target);
+        let transcode_name = self.get_transcode_name(source, target);
+        let mut filename =
+            self.templates
+                .get_torrent_name(&source.metadata, target, &transcode_name);
         if include_indexer {
             let indexer = self
                 .shared_options
@@ -147,4 +194,74 @@ impl PathManager {
             Ok(None)
         }
     }
+
+    fn is_shorten_enabled(&self) -> bool {
+        self.target_options
+            .shorten_paths
+            .expect("shorten_paths should be set")
+    }
+
+    /// Get the name of the transcode directory.
+    ///
+    /// If shortening is enabled and the track names alone are not enough to keep every path
+    /// within [`MAX_PATH_LENGTH`] then the parenthetical suffix of the album is removed.
+    fn get_transcode_name(&self, source: &Source, target: TargetFormat) -> String {
+        let name = self.templates.get_transcode_name(&source.metadata, target);
+        if !self.is_shorten_enabled() || !source.directory.is_dir() {
+            return name;
+        }
+        let key = (source.torrent.id, target);
+        let mut names = self
+            .shortened_names
+            .lock()
+            .expect("shortened names should not be poisoned");
+        if let Some(name) = names.get(&key) {
+            return name.clone();
+        }
+        let name = match Shortener::shorten_album(&source.metadata) {
+            Some(metadata) if !self.is_within_limit(source, target, &name) => {
+                self.templates.get_transcode_name(&metadata, target)
+            }
+            _ => name,
+        };
+        names.insert(key, name.clone());
+        name
+    }
+
+    /// Check if every transcode path fits without truncating the file names.
+    fn is_within_limit(&self, source: &Source, target: TargetFormat, name: &str) -> bool {
+        let extension = target.get_file_extension();
+        let dir = self.get_output_dir().join(name);
+        Collector::get_flacs(&source.directory).iter().all(|flac| {
+            let dir = dir.join(&flac.sub_dir);
+            let available = get_available_length(&dir, &extension);
+            flac.file_name.len() <= available
+                || TrackName::get(flac).is_some_and(|x| x.len() <= available)
+        })
+    }
+}
+
+/// Get the shortened file name of a transcode, without the extension.
+///
+/// The original file name is kept if it fits, otherwise the name derived from the tags is used,
+/// and finally the name is truncated.
+fn get_shortened_file_name(dir: &Path, flac: &FlacFile, extension: &str) -> String {
+    let available = get_available_length(dir, extension);
+    if flac.file_name.len() <= available {
+        return flac.file_name.clone();
+    }
+    let file_name = TrackName::get(flac)
+        .filter(|x| x.len() < flac.file_name.len())
+        .unwrap_or_else(|| flac.file_name.clone());
+    if available < MIN_TRUNCATED_LENGTH {
+        return file_name;
+    }
+    Shortener::truncate(&file_name, available).unwrap_or(file_name)
+}
+
+/// Get the length available for a file name in `dir` before reaching [`MAX_PATH_LENGTH`].
+#[allow(clippy::as_conversions, clippy::cast_sign_loss)]
+fn get_available_length(dir: &Path, extension: &str) -> usize {
+    let used = dir.to_string_lossy().len() + extension.len() + 2;
+    (MAX_PATH_LENGTH.max(0) as usize).saturating_sub(used)
 }
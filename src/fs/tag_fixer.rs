@@ -0,0 +1,172 @@
+use crate::fs::{fix_track_numbering, FlacFile};
+use crate::naming::join_humanized;
+use crate::source::Source;
+use html_escape::decode_html_entities;
+use lofty::prelude::Accessor;
+use lofty::tag::ItemKey::Composer;
+use lofty::tag::Tag;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+/// A tag value inferred by [`TagFixer`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SupplementedTag {
+    /// Name of the tag as reported by [`TagVerifier`].
+    pub tag: &'static str,
+    pub value: String,
+    /// Where the value was inferred from.
+    pub origin: &'static str,
+}
+
+/// Fill missing tags from the API metadata and file names.
+pub struct TagFixer;
+
+impl TagFixer {
+    /// Fill the missing tags of a [`FlacFile`] that [`TagVerifier`] checks.
+    ///
+    /// Only `tags` are changed, the source file is never modified.
+    pub fn execute(tags: &mut Tag, flac: &FlacFile, source: &Source) -> Vec<SupplementedTag> {
+        let mut supplemented = Vec::new();
+        let file_name = parse_file_name(&flac.file_name);
+        let known_artist = get_artist(source);
+        if tags.artist().is_none() {
+            let inferred = known_artist
+                .clone()
+                .map(|artist| (artist, "API metadata"))
+                .or_else(|| {
+                    let (artist, _) = split_artist_title(file_name.as_ref()?.title.as_str())?;
+                    Some((artist, "file name"))
+                });
+            if let Some((artist, origin)) = inferred {
+                tags.set_artist(artist.clone());
+                supplemented.push(SupplementedTag {
+                    tag: "artist",
+                    value: artist,
+                    origin,
+                });
+            }
+        }
+        if tags.album().is_none() {
+            let album = source.metadata.album.clone();
+            tags.set_album(album.clone());
+            supplemented.push(SupplementedTag {
+                tag: "album",
+                value: album,
+                origin: "API metadata",
+            });
+        }
+        if tags.title().is_none() {
+            if let Some(file_name) = &file_name {
+                let title = match split_artist_title(&file_name.title) {
+                    Some((artist, title))
+                        if known_artist.as_deref().is_none_or(|known| known == artist) =>
+                    {
+                        title
+                    }
+                    _ => file_name.title.clone(),
+                };
+                tags.set_title(title.clone());
+                supplemented.push(SupplementedTag {
+                    tag: "title",
+                    value: title,
+                    origin: "file name",
+                });
+            }
+        }
+        if tags.get(&Composer).is_none() {
+            if let Some(composer) = get_composer(source) {
+                tags.insert_text(Composer, composer.clone());
+                supplemented.push(SupplementedTag {
+                    tag: "composer",
+                    value: composer,
+                    origin: "API metadata",
+                });
+            }
+        }
+        let mut numbered = tags.clone();
+        if !fix_track_numbering(&mut numbered) {
+            let inferred = file_name
+                .as_ref()
+                .and_then(|file_name| Some((file_name.track?, "file name")))
+                .or_else(|| Some((get_track_from_file_list(flac, source)?, "file list")));
+            if let Some((track, origin)) = inferred {
+                tags.set_track(track);
+                supplemented.push(SupplementedTag {
+                    tag: "track_number",
+                    value: track.to_string(),
+                    origin,
+                });
+            }
+        }
+        supplemented
+    }
+}
+
+/// Track number and title parsed from a file name.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct ParsedFileName {
+    pub track: Option<u32>,
+    pub title: String,
+}
+
+/// Parse file names such as `01 - Title`, `01. Title`, `1-01 Title` or `Title`.
+pub(crate) fn parse_file_name(file_name: &str) -> Option<ParsedFileName> {
+    let re = Regex::new(r"^(?:\d{1,2}-)?(\d{1,3})(?:\s*[-._)]\s*|\s+)(.+)$").ok()?;
+    if let Some(captures) = re.captures(file_name) {
+        let track = captures.get(1)?.as_str().parse().ok();
+        let title = captures.get(2)?.as_str().trim().to_owned();
+        if !title.is_empty() {
+            return Some(ParsedFileName { track, title });
+        }
+    }
+    let title = file_name.trim();
+    if title.is_empty() {
+        return None;
+    }
+    Some(ParsedFileName {
+        track: None,
+        title: title.to_owned(),
+    })
+}
+
+/// Split an `Artist - Title` string.
+pub(crate) fn split_artist_title(value: &str) -> Option<(String, String)> {
+    let (artist, title) = value.split_once(" - ")?;
+    let artist = artist.trim();
+    let title = title.trim();
+    if artist.is_empty() || title.is_empty() {
+        return None;
+    }
+    Some((artist.to_owned(), title.to_owned()))
+}
+
+fn get_artist(source: &Source) -> Option<String> {
+    let artists = &source.metadata.artists;
+    if artists.is_empty() || artists.len() > 2 {
+        return None;
+    }
+    Some(join_humanized(artists))
+}
+
+fn get_composer(source: &Source) -> Option<String> {
+    let composers = &source.metadata.composers;
+    if composers.is_empty() || composers.len() > 2 {
+        return None;
+    }
+    Some(join_humanized(composers))
+}
+
+/// Get the position of a [`FlacFile`] within its directory of the torrent file list.
+fn get_track_from_file_list(flac: &FlacFile, source: &Source) -> Option<u32> {
+    let file_name = format!("{}.flac", flac.file_name);
+    let api_flacs = source.torrent.get_flacs();
+    let siblings: Vec<PathBuf> = api_flacs
+        .iter()
+        .map(|path| PathBuf::from(decode_html_entities(&path.to_string_lossy()).to_string()))
+        .filter(|path| path.parent().unwrap_or(Path::new("")) == flac.sub_dir)
+        .collect();
+    let index = siblings
+        .iter()
+        .position(|path| path.file_name().is_some_and(|name| *name == *file_name))?;
+    u32::try_from(index + 1).ok()
+}
@@ -0,0 +1,95 @@
+use crate::errors::error;
+use crate::fs::*;
+use crate::options::TagOptions;
+use lofty::tag::{ItemKey, Tag};
+use regex::Regex;
+use rogue_logging::Error;
+
+/// Default regex patterns of ripper or encoder junk in comments.
+pub const DEFAULT_TAG_JUNK: [&str; 3] = [
+    r"(?i)^\s*(?:ripped|encoded|created|extracted)\s+(?:by|with|using)\b",
+    r"(?i)^\s*(?:EAC|Exact Audio Copy|XLD|X Lossless Decoder|dBpoweramp|CUERipper|foobar2000)\b",
+    r"(?i)^\s*(?:reference\s+)?(?:libFLAC|LAME|Lavf|Lavc)\b",
+];
+
+/// Rules applied to the tags of each transcode.
+pub struct TagRules {
+    include: Option<Vec<ItemKey>>,
+    exclude: Vec<ItemKey>,
+    junk: Vec<Regex>,
+    trim: bool,
+    artist_separators: Vec<String>,
+    disc_from_directory: bool,
+}
+
+impl TagRules {
+    /// Create [`TagRules`] from [`TagOptions`].
+    pub fn new(options: &TagOptions) -> Result<Self, Error> {
+        let include = options
+            .tag_include
+            .as_ref()
+            .map(|names| names.iter().map(|name| get_item_key(name)).collect());
+        let exclude = options
+            .tag_exclude
+            .as_ref()
+            .expect("tag_exclude should be set")
+            .iter()
+            .map(|name| get_item_key(name))
+            .collect();
+        let junk = if options
+            .strip_tag_junk
+            .expect("strip_tag_junk should be set")
+        {
+            options
+                .tag_junk
+                .as_ref()
+                .expect("tag_junk should be set")
+                .iter()
+                .map(|pattern| {
+                    Regex::new(pattern).map_err(|e| error("parse tag junk pattern", e.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            include,
+            exclude,
+            junk,
+            trim: options.tag_trim.expect("tag_trim should be set"),
+            artist_separators: options
+                .artist_separators
+                .clone()
+                .expect("artist_separators should be set"),
+            disc_from_directory: options
+                .disc_from_directory
+                .expect("disc_from_directory should be set"),
+        })
+    }
+
+    /// Are there no rules to apply?
+    pub fn is_empty(&self) -> bool {
+        self.include.is_none()
+            && self.exclude.is_empty()
+            && self.junk.is_empty()
+            && !self.trim
+            && self.artist_separators.is_empty()
+            && !self.disc_from_directory
+    }
+
+    /// Apply the rules to the Vorbis comments of a [`FlacFile`].
+    pub fn apply(&self, tags: &mut Tag, flac: &FlacFile) {
+        if let Some(include) = &self.include {
+            retain_keys(tags, include);
+        }
+        remove_keys(tags, &self.exclude);
+        remove_junk_comments(tags, &self.junk);
+        if self.trim {
+            trim_values(tags);
+        }
+        split_artists(tags, &self.artist_separators);
+        if self.disc_from_directory {
+            set_disc_from_directory(tags, &flac.sub_dir);
+        }
+    }
+}
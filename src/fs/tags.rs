@@ -1,12 +1,23 @@
-use crate::errors::error;
+use crate::errors::{error, io_error};
 use crate::fs::FlacFile;
-use lofty::file::TaggedFileExt;
+use lofty::config::{ParseOptions, WriteOptions};
+use lofty::file::{AudioFile, TaggedFileExt};
+use lofty::flac::FlacFile as FlacTaggedFile;
+use lofty::ogg::{OggPictureStorage, VorbisComments};
 use lofty::probe::Probe;
-use lofty::tag::ItemKey::TrackNumber;
-use lofty::tag::{Accessor, Tag, TagType};
+use lofty::tag::ItemKey::{AlbumArtist, Comment, TrackArtist, TrackNumber};
+use lofty::tag::{Accessor, ItemKey, ItemValue, Tag, TagItem, TagType};
 use log::trace;
 use regex::Regex;
 use rogue_logging::Error;
+use std::fs::File;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// Pattern of a `CD1` or `Disc 2` style directory.
+static DISC_DIRECTORY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(?:cd|disc|disk)[\s._-]*(\d+)(?:\D.*)?$").expect("regex should compile")
+});
 
 pub(crate) fn get_vorbis_tags(flac: &FlacFile) -> Result<Tag, Error> {
     let file = Probe::open(flac.path.clone())
@@ -23,6 +34,33 @@ pub(crate) fn get_vorbis_tags(flac: &FlacFile) -> Result<Tag, Error> {
     }
 }
 
+/// Replace the Vorbis comments of a FLAC file with `tags`.
+///
+/// Pictures in `tags` are only added if the FLAC file has none.
+pub(crate) fn save_vorbis_comments(tags: Tag, path: &Path) -> Result<(), Error> {
+    let mut file = File::open(path).map_err(|e| io_error(e, "open FLAC"))?;
+    let mut flac = FlacTaggedFile::read_from(&mut file, ParseOptions::new())
+        .map_err(|e| error("read FLAC tags", e.to_string()))?;
+    drop(file);
+    let vendor = flac
+        .vorbis_comments()
+        .map(|comments| comments.vendor().to_owned());
+    let mut comments = VorbisComments::from(tags);
+    let pictures = comments.remove_pictures();
+    if flac.pictures().is_empty() {
+        for (picture, info) in pictures {
+            flac.insert_picture(picture, Some(info))
+                .map_err(|e| error("write FLAC pictures", e.to_string()))?;
+        }
+    }
+    if let Some(vendor) = vendor {
+        comments.set_vendor(vendor);
+    }
+    flac.set_vorbis_comments(comments);
+    flac.save_to_path(path, WriteOptions::default())
+        .map_err(|e| error("write FLAC tags", e.to_string()))
+}
+
 pub(crate) fn convert_to_id3v2(tags: &mut Tag) {
     tags.re_map(TagType::Id3v2);
 }
@@ -99,6 +137,131 @@ pub(crate) fn get_numeric_from_total_format(input: &str) -> Option<(u32, u32)> {
     Some((track_number, track_total))
 }
 
+/// Get the [`ItemKey`] of a Vorbis comment field name.
+pub(crate) fn get_item_key(name: &str) -> ItemKey {
+    ItemKey::from_key(TagType::VorbisComments, &name.to_uppercase())
+}
+
+/// Is `key` one of `keys`?
+///
+/// Unknown keys are compared case insensitively as Vorbis comment field names are.
+pub(crate) fn is_key_in(key: &ItemKey, keys: &[ItemKey]) -> bool {
+    keys.iter().any(|other| match (key, other) {
+        (ItemKey::Unknown(key), ItemKey::Unknown(other)) => key.eq_ignore_ascii_case(other),
+        _ => key == other,
+    })
+}
+
+/// Remove items with a key in `keys`.
+pub(crate) fn remove_keys(tags: &mut Tag, keys: &[ItemKey]) {
+    tags.retain(|item| {
+        let is_removed = is_key_in(item.key(), keys);
+        if is_removed {
+            trace!("Removing excluded {:?} tag", item.key());
+        }
+        !is_removed
+    });
+}
+
+/// Remove items without a key in `keys`.
+pub(crate) fn retain_keys(tags: &mut Tag, keys: &[ItemKey]) {
+    tags.retain(|item| {
+        let is_retained = is_key_in(item.key(), keys);
+        if !is_retained {
+            trace!("Removing {:?} tag as it is not included", item.key());
+        }
+        is_retained
+    });
+}
+
+/// Remove comments matching any of `patterns`.
+pub(crate) fn remove_junk_comments(tags: &mut Tag, patterns: &[Regex]) {
+    tags.retain(|item| {
+        if item.key() != &Comment {
+            return true;
+        }
+        let Some(text) = item.value().text() else {
+            return true;
+        };
+        let is_junk = patterns.iter().any(|pattern| pattern.is_match(text));
+        if is_junk {
+            trace!("Removing junk comment: {text}");
+        }
+        !is_junk
+    });
+}
+
+/// Trim leading and trailing whitespace from text values.
+///
+/// Items that are empty after trimming are removed.
+pub(crate) fn trim_values(tags: &mut Tag) {
+    let items: Vec<TagItem> = tags.items().cloned().collect();
+    tags.retain(|_| false);
+    for item in items {
+        let (key, value) = item.consume();
+        let value = match value {
+            ItemValue::Text(text) => {
+                let trimmed = text.trim();
+                if trimmed.is_empty() {
+                    trace!("Removing empty {key:?} tag");
+                    continue;
+                }
+                ItemValue::Text(trimmed.to_owned())
+            }
+            value => value,
+        };
+        tags.push_unchecked(TagItem::new(key, value));
+    }
+}
+
+/// Split multi-valued artist fields into an item per artist.
+pub(crate) fn split_artists(tags: &mut Tag, separators: &[String]) {
+    if separators.is_empty() {
+        return;
+    }
+    for key in [TrackArtist, AlbumArtist] {
+        let values: Vec<String> = tags.take_strings(&key).collect();
+        for value in values {
+            let mut artists = vec![value.clone()];
+            for separator in separators {
+                artists = artists
+                    .iter()
+                    .flat_map(|artist| artist.split(separator.as_str()))
+                    .map(|artist| artist.trim().to_owned())
+                    .filter(|artist| !artist.is_empty())
+                    .collect();
+            }
+            if artists.len() > 1 {
+                trace!("Splitting {key:?} tag: {value}");
+            }
+            for artist in artists {
+                tags.push_unchecked(TagItem::new(key.clone(), ItemValue::Text(artist)));
+            }
+        }
+    }
+}
+
+/// Set the disc number from a `CD1` or `Disc 2` style directory if it is not set.
+pub(crate) fn set_disc_from_directory(tags: &mut Tag, sub_dir: &Path) {
+    if tags.disk().is_some() {
+        return;
+    }
+    if let Some(disc_number) = get_disc_from_directory(sub_dir) {
+        trace!(
+            "Setting disc number {disc_number} from directory: {}",
+            sub_dir.display()
+        );
+        tags.set_disk(disc_number);
+    }
+}
+
+/// Get the disc number from a `CD1` or `Disc 2` style directory.
+pub(crate) fn get_disc_from_directory(sub_dir: &Path) -> Option<u32> {
+    let name = sub_dir.file_name()?.to_string_lossy();
+    let captures = DISC_DIRECTORY.captures(&name)?;
+    captures.get(1)?.as_str().parse().ok()
+}
+
 #[allow(dead_code)]
 pub(crate) fn print_tags(tags: &Tag) {
     for item in tags.items() {
@@ -0,0 +1,139 @@
+use std::path::PathBuf;
+
+use crate::formats::TargetFormat;
+use crate::fs::{AdditionalFile, AdditionalFilePolicy, FileAction, FileRule};
+use crate::options::{FileOptions, Options};
+
+#[test]
+fn file_rule_from_str() {
+    // Arrange
+    let values = ["pdf=copy", ".LOG=hard-link@flac", "txt=exclude@320,v0"];
+
+    // Act
+    let rules: Vec<FileRule> = values
+        .iter()
+        .map(|value| value.parse().expect("rule should parse"))
+        .collect();
+
+    // Assert
+    assert_eq!(
+        rules,
+        vec![
+            FileRule {
+                extension: "pdf".to_owned(),
+                action: FileAction::Copy,
+                targets: None,
+            },
+            FileRule {
+                extension: "log".to_owned(),
+                action: FileAction::HardLink,
+                targets: Some(vec![TargetFormat::Flac]),
+            },
+            FileRule {
+                extension: "txt".to_owned(),
+                action: FileAction::Exclude,
+                targets: Some(vec![TargetFormat::_320, TargetFormat::V0]),
+            },
+        ]
+    );
+    assert_eq!(
+        rules.last().expect("should be a rule").to_string(),
+        "txt=exclude@320,v0"
+    );
+}
+
+#[test]
+fn file_rule_from_str_invalid() {
+    assert!("pdf".parse::<FileRule>().is_err());
+    assert!("=copy".parse::<FileRule>().is_err());
+    assert!("pdf=move".parse::<FileRule>().is_err());
+    assert!("pdf=copy@aac".parse::<FileRule>().is_err());
+}
+
+#[test]
+fn additional_file_policy_get_action() {
+    // Arrange
+    let mut options = FileOptions {
+        additional_exclude: Some(vec!["**/*.accurip".to_owned(), "Scans/*".to_owned()]),
+        additional_rules: Some(vec![
+            "log=copy@flac".parse().expect("rule should parse"),
+            "log=exclude".parse().expect("rule should parse"),
+            "pdf=hard-link".parse().expect("rule should parse"),
+            "png=compress".parse().expect("rule should parse"),
+        ]),
+        ..FileOptions::default()
+    };
+    options.apply_defaults();
+    let policy = AdditionalFilePolicy::new(&options).expect("policy should be valid");
+    let source_dir = PathBuf::from("/source");
+    let file = |path: &str| AdditionalFile::new(source_dir.join(path), &source_dir);
+
+    // Act
+    let actions = [
+        policy.get_action(&file("rip.log"), TargetFormat::Flac),
+        policy.get_action(&file("rip.log"), TargetFormat::V0),
+        policy.get_action(&file("Booklet/booklet.PDF"), TargetFormat::_320),
+        policy.get_action(&file("cover.png"), TargetFormat::V0),
+        policy.get_action(&file("Scans/back.png"), TargetFormat::Flac),
+        policy.get_action(&file("info.txt"), TargetFormat::Flac),
+        policy.get_action(&file("CD1/rip.accurip"), TargetFormat::Flac),
+    ];
+
+    // Assert
+    assert_eq!(
+        actions,
+        [
+            FileAction::Copy,
+            FileAction::Exclude,
+            FileAction::HardLink,
+            FileAction::Compress,
+            FileAction::Exclude,
+            FileAction::Exclude,
+            FileAction::Exclude,
+        ]
+    );
+}
+
+#[test]
+fn additional_file_policy_is_shared() {
+    // Arrange
+    let mut options = FileOptions {
+        additional_rules: Some(vec![
+            "png=copy@flac".parse().expect("rule should parse"),
+            "png=compress".parse().expect("rule should parse"),
+            "log=hard-link".parse().expect("rule should parse"),
+        ]),
+        ..FileOptions::default()
+    };
+    options.apply_defaults();
+    let policy = AdditionalFilePolicy::new(&options).expect("policy should be valid");
+    let source_dir = PathBuf::from("/source");
+    let cover = AdditionalFile::new(source_dir.join("cover.png"), &source_dir);
+    let log = AdditionalFile::new(source_dir.join("rip.log"), &source_dir);
+
+    // Act
+    let shared = [
+        policy.is_shared(&cover, TargetFormat::Flac, TargetFormat::_320),
+        policy.is_shared(&cover, TargetFormat::_320, TargetFormat::V0),
+        policy.is_shared(&log, TargetFormat::Flac, TargetFormat::V0),
+    ];
+
+    // Assert
+    assert_eq!(shared, [false, true, true]);
+}
+
+#[test]
+fn file_options_validate_glob() {
+    // Arrange
+    let mut options = FileOptions {
+        additional_include: Some(vec!["**/*.{log,cue".to_owned()]),
+        ..FileOptions::default()
+    };
+    options.apply_defaults();
+
+    // Act
+    let is_valid = options.validate();
+
+    // Assert
+    assert!(!is_valid);
+}
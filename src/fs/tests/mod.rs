@@ -1,2 +1,5 @@
+mod additional_file_policy_tests;
 mod copy_dir_tests;
+mod tag_fixer_tests;
+mod tag_rules_tests;
 mod tags_tests;
@@ -0,0 +1,135 @@
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use gazelle_api::{Artist, Group, MusicInfo, Torrent};
+use lofty::prelude::Accessor;
+use lofty::tag::ItemKey::Composer;
+use lofty::tag::{Tag, TagType};
+
+use crate::formats::SourceFormat;
+use crate::fs::*;
+use crate::source::{Metadata, Source};
+
+fn create_source(artists: &[&str], composers: &[&str], file_list: &str) -> Source {
+    let to_artists = |names: &[&str]| -> Vec<Artist> {
+        names
+            .iter()
+            .map(|name| Artist {
+                id: 1,
+                name: (*name).to_owned(),
+            })
+            .collect()
+    };
+    let group = Group {
+        name: "Album".to_owned(),
+        music_info: Some(MusicInfo {
+            artists: to_artists(artists),
+            composers: to_artists(composers),
+            ..MusicInfo::default()
+        }),
+        ..Group::default()
+    };
+    let torrent = Torrent {
+        file_list: file_list.to_owned(),
+        ..Torrent::default()
+    };
+    Source {
+        metadata: Metadata::new(&group, &torrent),
+        torrent,
+        group,
+        existing: BTreeSet::new(),
+        format: SourceFormat::Flac,
+        directory: PathBuf::from("/source"),
+    }
+}
+
+fn create_flac(path: &str) -> FlacFile {
+    let source_dir = PathBuf::from("/source");
+    FlacFile::new(source_dir.join(path), &source_dir)
+}
+
+#[test]
+fn parse_file_name_formats() {
+    let parse =
+        |file_name: &str| parse_file_name(file_name).map(|parsed| (parsed.track, parsed.title));
+    assert_eq!(parse("01 - Title"), Some((Some(1), "Title".to_owned())));
+    assert_eq!(parse("02. Title"), Some((Some(2), "Title".to_owned())));
+    assert_eq!(parse("1-03 Title"), Some((Some(3), "Title".to_owned())));
+    assert_eq!(parse("04_Title"), Some((Some(4), "Title".to_owned())));
+    assert_eq!(parse("Title"), Some((None, "Title".to_owned())));
+    assert_eq!(parse(""), None);
+}
+
+#[test]
+fn split_artist_title_formats() {
+    assert_eq!(
+        split_artist_title("Artist - Title"),
+        Some(("Artist".to_owned(), "Title".to_owned()))
+    );
+    assert_eq!(split_artist_title("Title"), None);
+    assert_eq!(split_artist_title("Artist - "), None);
+}
+
+#[test]
+fn tag_fixer_supplements_missing_tags() {
+    // Arrange
+    let source = create_source(&["Artist"], &["Composer"], "");
+    let flac = create_flac("05 - Artist - Title.flac");
+    let mut tags = Tag::new(TagType::VorbisComments);
+
+    // Act
+    let supplemented = TagFixer::execute(&mut tags, &flac, &source);
+
+    // Assert
+    assert_eq!(tags.artist().as_deref(), Some("Artist"));
+    assert_eq!(tags.album().as_deref(), Some("Album"));
+    assert_eq!(tags.title().as_deref(), Some("Title"));
+    assert_eq!(tags.get_string(&Composer), Some("Composer"));
+    assert_eq!(tags.track(), Some(5));
+    let names: Vec<&str> = supplemented.iter().map(|tag| tag.tag).collect();
+    assert_eq!(
+        names,
+        vec!["artist", "album", "title", "composer", "track_number"]
+    );
+}
+
+#[test]
+fn tag_fixer_keeps_existing_tags() {
+    // Arrange
+    let source = create_source(&["Artist"], &[], "");
+    let flac = create_flac("05 - Other Title.flac");
+    let mut tags = Tag::new(TagType::VorbisComments);
+    tags.set_artist("Tagged Artist".to_owned());
+    tags.set_album("Tagged Album".to_owned());
+    tags.set_title("Tagged Title".to_owned());
+    tags.set_track(1);
+
+    // Act
+    let supplemented = TagFixer::execute(&mut tags, &flac, &source);
+
+    // Assert
+    assert!(supplemented.is_empty());
+    assert_eq!(tags.artist().as_deref(), Some("Tagged Artist"));
+    assert_eq!(tags.title().as_deref(), Some("Tagged Title"));
+    assert_eq!(tags.track(), Some(1));
+}
+
+#[test]
+fn tag_fixer_various_artists_from_file_name() {
+    // Arrange
+    let source = create_source(
+        &["Artist A", "Artist B", "Artist C"],
+        &[],
+        "CD1/Intro.flac{{{100}}}|||CD1/Artist B - Song.flac{{{200}}}|||CD2/Outro.flac{{{300}}}",
+    );
+    let flac = create_flac("CD1/Artist B - Song.flac");
+    let mut tags = Tag::new(TagType::VorbisComments);
+
+    // Act
+    let _ = TagFixer::execute(&mut tags, &flac, &source);
+
+    // Assert
+    assert_eq!(tags.artist().as_deref(), Some("Artist B"));
+    assert_eq!(tags.title().as_deref(), Some("Song"));
+    assert_eq!(tags.track(), Some(2));
+}
@@ -0,0 +1,31 @@
+use crate::fs::TagRules;
+use crate::options::{Options, TagOptions};
+
+#[test]
+fn tag_rules_default_is_empty() {
+    // Arrange
+    let mut options = TagOptions::default();
+    options.apply_defaults();
+
+    // Act
+    let rules = TagRules::new(&options).expect("rules should be valid");
+
+    // Assert
+    assert!(rules.is_empty());
+}
+
+#[test]
+fn tag_rules_opt_in_is_not_empty() {
+    // Arrange
+    let mut options = TagOptions {
+        strip_tag_junk: Some(true),
+        ..TagOptions::default()
+    };
+    options.apply_defaults();
+
+    // Act
+    let rules = TagRules::new(&options).expect("rules should be valid");
+
+    // Assert
+    assert!(!rules.is_empty());
+}
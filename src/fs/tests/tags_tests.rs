@@ -1,4 +1,22 @@
 use super::super::*;
+use lofty::tag::{Accessor, ItemKey, ItemValue, Tag, TagItem, TagType};
+use regex::Regex;
+use std::path::Path;
+
+fn create_tags(items: &[(ItemKey, &str)]) -> Tag {
+    let mut tags = Tag::new(TagType::VorbisComments);
+    for (key, value) in items {
+        tags.push_unchecked(TagItem::new(
+            key.clone(),
+            ItemValue::Text((*value).to_owned()),
+        ));
+    }
+    tags
+}
+
+fn get_values(tags: &Tag, key: &ItemKey) -> Vec<String> {
+    tags.get_strings(key).map(ToOwned::to_owned).collect()
+}
 
 #[test]
 fn valid_total_formats() {
@@ -33,3 +51,147 @@ fn invalid_vinyl_formats() {
     assert_eq!(get_numeric_from_vinyl_format("12"), None);
     assert_eq!(get_numeric_from_vinyl_format("1A"), None);
 }
+
+#[test]
+fn item_keys_from_vorbis_names() {
+    assert_eq!(get_item_key("artist"), ItemKey::TrackArtist);
+    assert_eq!(get_item_key("ALBUMARTIST"), ItemKey::AlbumArtist);
+    assert!(is_key_in(
+        &ItemKey::Unknown("Source".to_owned()),
+        &[get_item_key("source")]
+    ));
+}
+
+#[test]
+fn remove_and_retain_keys() {
+    // Arrange
+    let items = [
+        (ItemKey::TrackArtist, "Artist"),
+        (ItemKey::TrackTitle, "Title"),
+        (ItemKey::Comment, "Comment"),
+        (ItemKey::Unknown("Source".to_owned()), "CD"),
+    ];
+    let mut excluded = create_tags(&items);
+    let mut included = create_tags(&items);
+
+    // Act
+    remove_keys(
+        &mut excluded,
+        &[get_item_key("COMMENT"), get_item_key("SOURCE")],
+    );
+    retain_keys(
+        &mut included,
+        &[get_item_key("ARTIST"), get_item_key("TITLE")],
+    );
+
+    // Assert
+    let excluded: Vec<&ItemKey> = excluded.items().map(TagItem::key).collect();
+    let included: Vec<&ItemKey> = included.items().map(TagItem::key).collect();
+    assert_eq!(excluded, vec![&ItemKey::TrackArtist, &ItemKey::TrackTitle]);
+    assert_eq!(included, vec![&ItemKey::TrackArtist, &ItemKey::TrackTitle]);
+}
+
+#[test]
+fn remove_junk_comments_default() {
+    // Arrange
+    let mut tags = create_tags(&[
+        (ItemKey::Comment, "Ripped with EAC"),
+        (ItemKey::Comment, "XLD version 20240511 (156.0)"),
+        (ItemKey::Comment, "reference libFLAC 1.4.3 20230623"),
+        (ItemKey::Comment, "Remastered from the original tapes"),
+        (ItemKey::TrackTitle, "Encoded by Somebody"),
+    ]);
+    let patterns: Vec<Regex> = DEFAULT_TAG_JUNK
+        .iter()
+        .map(|pattern| Regex::new(pattern).expect("pattern should be valid"))
+        .collect();
+
+    // Act
+    remove_junk_comments(&mut tags, &patterns);
+
+    // Assert
+    assert_eq!(
+        get_values(&tags, &ItemKey::Comment),
+        vec!["Remastered from the original tapes"]
+    );
+    assert_eq!(
+        get_values(&tags, &ItemKey::TrackTitle),
+        vec!["Encoded by Somebody"]
+    );
+}
+
+#[test]
+fn trim_values_removes_whitespace() {
+    // Arrange
+    let mut tags = create_tags(&[
+        (ItemKey::TrackTitle, "  Title \t"),
+        (ItemKey::Comment, "   "),
+    ]);
+
+    // Act
+    trim_values(&mut tags);
+
+    // Assert
+    assert_eq!(get_values(&tags, &ItemKey::TrackTitle), vec!["Title"]);
+    assert_eq!(tags.get(&ItemKey::Comment), None);
+}
+
+#[test]
+fn split_artists_by_separators() {
+    // Arrange
+    let mut tags = create_tags(&[
+        (ItemKey::TrackArtist, "Artist A; Artist B / Artist C"),
+        (ItemKey::AlbumArtist, "Artist A"),
+        (ItemKey::TrackTitle, "Title; Subtitle"),
+    ]);
+    let separators = vec!["; ".to_owned(), "/".to_owned()];
+
+    // Act
+    split_artists(&mut tags, &separators);
+
+    // Assert
+    assert_eq!(
+        get_values(&tags, &ItemKey::TrackArtist),
+        vec!["Artist A", "Artist B", "Artist C"]
+    );
+    assert_eq!(get_values(&tags, &ItemKey::AlbumArtist), vec!["Artist A"]);
+    assert_eq!(
+        get_values(&tags, &ItemKey::TrackTitle),
+        vec!["Title; Subtitle"]
+    );
+}
+
+#[test]
+fn valid_disc_directories() {
+    assert_eq!(get_disc_from_directory(Path::new("CD1")), Some(1));
+    assert_eq!(get_disc_from_directory(Path::new("cd 02")), Some(2));
+    assert_eq!(get_disc_from_directory(Path::new("Disc 3")), Some(3));
+    assert_eq!(
+        get_disc_from_directory(Path::new("Album/Disk_4 - Live")),
+        Some(4)
+    );
+}
+
+#[test]
+fn invalid_disc_directories() {
+    assert_eq!(get_disc_from_directory(Path::new("")), None);
+    assert_eq!(get_disc_from_directory(Path::new("Scans")), None);
+    assert_eq!(get_disc_from_directory(Path::new("CD")), None);
+    assert_eq!(get_disc_from_directory(Path::new("ABCD1")), None);
+}
+
+#[test]
+fn set_disc_from_directory_only_if_missing() {
+    // Arrange
+    let mut missing = create_tags(&[]);
+    let mut existing = create_tags(&[]);
+    existing.set_disk(3);
+
+    // Act
+    set_disc_from_directory(&mut missing, Path::new("CD2"));
+    set_disc_from_directory(&mut existing, Path::new("CD2"));
+
+    // Assert
+    assert_eq!(missing.disk(), Some(2));
+    assert_eq!(existing.disk(), Some(3));
+}
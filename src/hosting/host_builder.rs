@@ -12,18 +12,21 @@ use crate::built_info::{PKG_HOMEPAGE, PKG_NAME, PKG_VERSION};
 use crate::formats::TargetFormatProvider;
 use crate::fs::PathManager;
 use crate::hosting::Host;
-use crate::jobs::{DebugSubscriber, JobRunner, ProgressBarSubscriber, Publisher};
+use crate::image_host::ImageHostFactory;
+use crate::jobs::{
+    DebugSubscriber, JobFailure, JobRunner, ProgressBarSubscriber, Publisher, Scheduler,
+};
 use crate::options::config_command::ConfigCommand;
 use crate::options::*;
 use crate::queue::queue_summary_command::QueueSummaryCommand;
 use crate::queue::{Queue, QueueAddCommand, QueueListCommand};
 use crate::source::{IdProvider, SourceProvider};
-use crate::spectrogram::{SpectrogramCommand, SpectrogramJobFactory};
+use crate::spectrogram::{SpectrogramCommand, SpectrogramJobFactory, SpectrogramUploader};
+use crate::torrent_client::TorrentClientFactory;
 use crate::transcode::{AdditionalJobFactory, TranscodeCommand, TranscodeJobFactory};
 use crate::upload::UploadCommand;
 use crate::verify::VerifyCommand;
 use gazelle_api::GazelleClientFactory;
-use rogue_logging::Error;
 use rogue_logging::Logger;
 
 pub struct HostBuilder {
@@ -48,11 +51,16 @@ impl HostBuilder {
             .add(OptionsProvider::singleton())
             .add(BatchOptions::singleton())
             .add(CacheOptions::singleton())
+            .add(ClientOptions::singleton())
             .add(FileOptions::singleton())
+            .add(ImageHostOptions::singleton())
+            .add(LoudnessOptions::singleton())
+            .add(NamingOptions::singleton())
             .add(RunnerOptions::singleton())
             .add(SharedOptions::singleton())
             .add(SourceArg::singleton())
             .add(SpectrogramOptions::singleton())
+            .add(TagOptions::singleton())
             .add(TargetOptions::singleton())
             .add(QueueAddArgs::singleton())
             .add(UploadOptions::singleton())
@@ -85,6 +93,7 @@ impl HostBuilder {
                 RefMut::new(Mut::new(api))
             }))
             .add(JobRunner::transient())
+            .add(Scheduler::singleton())
             .add(Publisher::transient())
             .add(DebugSubscriber::transient())
             .add(ProgressBarSubscriber::transient())
@@ -105,13 +114,14 @@ impl HostBuilder {
             // Add spectrogram services
             .add(SpectrogramCommand::transient())
             .add(SpectrogramJobFactory::transient())
+            .add(SpectrogramUploader::transient())
             .add(singleton_as_self().from(|provider| {
                 let options = provider.get_required::<RunnerOptions>();
                 let cpus = options.cpus.expect("cpus should be set") as usize;
                 Arc::new(Semaphore::new(cpus))
             }))
             .add(singleton_as_self().from(|_| {
-                let set: JoinSet<Result<(), Error>> = JoinSet::new();
+                let set: JoinSet<Result<(), JobFailure>> = JoinSet::new();
                 RefMut::new(Mut::new(set))
             }))
             // Add transcode services
@@ -120,6 +130,8 @@ impl HostBuilder {
             .add(AdditionalJobFactory::transient())
             // Add upload services
             .add(UploadCommand::transient().as_mut())
+            .add(TorrentClientFactory::transient())
+            .add(ImageHostFactory::transient())
             // Add verify services
             .add(VerifyCommand::transient().as_mut());
         this
@@ -0,0 +1,79 @@
+use std::path::Path;
+
+use reqwest::multipart::{Form, Part};
+use reqwest::{Client, Response};
+use rogue_logging::Error;
+use serde_json::Value;
+use tokio::fs::read;
+
+use crate::errors::{error, http_error, io_error};
+
+/// Client for an image host accepting a multipart upload.
+///
+/// The image is posted as the `file` field with the API key as the `api_key` field.
+///
+/// The response may be the URL as plain text or a JSON object with the URL as
+/// `url`, `data.url` or `image.url`.
+pub struct GenericImageHostClient {
+    url: String,
+    api_key: String,
+    client: Client,
+}
+
+impl GenericImageHostClient {
+    #[must_use]
+    pub fn new(url: String, api_key: String) -> Self {
+        Self {
+            url,
+            api_key,
+            client: Client::new(),
+        }
+    }
+
+    /// Upload an image.
+    ///
+    /// Returns the URL of the uploaded image.
+    pub async fn upload(&self, path: &Path) -> Result<String, Error> {
+        let buffer = read(path).await.map_err(|e| io_error(e, "read image"))?;
+        let file_name = path
+            .file_name()
+            .map(|x| x.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let part = Part::bytes(buffer)
+            .file_name(file_name)
+            .mime_str("image/png")
+            .map_err(|e| http_error(e, "create image host request"))?;
+        let form = Form::new()
+            .part("file", part)
+            .text("api_key", self.api_key.clone());
+        let body = self
+            .client
+            .post(&self.url)
+            .multipart(form)
+            .send()
+            .await
+            .and_then(Response::error_for_status)
+            .map_err(|e| http_error(e, "upload image to image host"))?
+            .text()
+            .await
+            .map_err(|e| http_error(e, "upload image to image host"))?;
+        get_url(&body).ok_or_else(|| {
+            error(
+                "upload image to image host",
+                format!("image host response did not contain a URL: {body}"),
+            )
+        })
+    }
+}
+
+fn get_url(body: &str) -> Option<String> {
+    let body = body.trim();
+    if body.starts_with("http://") || body.starts_with("https://") {
+        return Some(body.to_owned());
+    }
+    let json: Value = serde_json::from_str(body).ok()?;
+    ["/url", "/data/url", "/image/url"]
+        .iter()
+        .find_map(|pointer| json.pointer(pointer).and_then(Value::as_str))
+        .map(ToOwned::to_owned)
+}
@@ -0,0 +1,31 @@
+use std::path::Path;
+
+use rogue_logging::Error;
+
+use crate::image_host::*;
+
+/// An image host with a supported API.
+pub enum ImageHostClient {
+    Ptpimg(PtpimgClient),
+    Generic(GenericImageHostClient),
+}
+
+impl ImageHostClient {
+    #[must_use]
+    pub fn get_kind(&self) -> ImageHostKind {
+        match self {
+            ImageHostClient::Ptpimg(_) => ImageHostKind::Ptpimg,
+            ImageHostClient::Generic(_) => ImageHostKind::Generic,
+        }
+    }
+
+    /// Upload an image.
+    ///
+    /// Returns the URL of the uploaded image.
+    pub async fn upload(&self, path: &Path) -> Result<String, Error> {
+        match self {
+            ImageHostClient::Ptpimg(client) => client.upload(path).await,
+            ImageHostClient::Generic(client) => client.upload(path).await,
+        }
+    }
+}
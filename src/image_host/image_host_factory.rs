@@ -0,0 +1,32 @@
+use di::{injectable, Ref};
+
+use crate::image_host::*;
+use crate::options::ImageHostOptions;
+
+#[injectable]
+pub struct ImageHostFactory {
+    options: Ref<ImageHostOptions>,
+}
+
+impl ImageHostFactory {
+    /// Create an [`ImageHostClient`] from the [`ImageHostOptions`].
+    ///
+    /// Returns `None` if no image host is configured.
+    #[must_use]
+    pub fn create(&self) -> Option<ImageHostClient> {
+        let kind = self.options.image_host?;
+        let url = self
+            .options
+            .image_host_url
+            .clone()
+            .or_else(|| kind.get_default_url().map(ToOwned::to_owned))?;
+        let api_key = self.options.image_host_api_key.clone()?;
+        let client = match kind {
+            ImageHostKind::Ptpimg => ImageHostClient::Ptpimg(PtpimgClient::new(url, api_key)),
+            ImageHostKind::Generic => {
+                ImageHostClient::Generic(GenericImageHostClient::new(url, api_key))
+            }
+        };
+        Some(client)
+    }
+}
@@ -0,0 +1,35 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Image host with a supported API.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageHostKind {
+    /// ptpimg upload API
+    Ptpimg,
+    /// Multipart upload returning the image URL
+    Generic,
+}
+
+impl ImageHostKind {
+    /// Get the default URL of the host.
+    #[must_use]
+    pub fn get_default_url(&self) -> Option<&str> {
+        match self {
+            ImageHostKind::Ptpimg => Some("https://ptpimg.me"),
+            ImageHostKind::Generic => None,
+        }
+    }
+}
+
+impl Display for ImageHostKind {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let output = match self {
+            ImageHostKind::Ptpimg => "ptpimg",
+            ImageHostKind::Generic => "image host",
+        };
+        output.fmt(formatter)
+    }
+}
@@ -0,0 +1,13 @@
+pub use generic_image_host_client::*;
+pub use image_host_client::*;
+pub use image_host_factory::*;
+pub use image_host_kind::*;
+pub use ptpimg_client::*;
+
+pub(crate) mod generic_image_host_client;
+pub(crate) mod image_host_client;
+pub(crate) mod image_host_factory;
+pub(crate) mod image_host_kind;
+pub(crate) mod ptpimg_client;
+#[cfg(test)]
+mod tests;
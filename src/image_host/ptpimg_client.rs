@@ -0,0 +1,70 @@
+use std::path::Path;
+
+use reqwest::multipart::{Form, Part};
+use reqwest::{Client, Response};
+use rogue_logging::Error;
+use serde::Deserialize;
+use tokio::fs::read;
+
+use crate::errors::{error, http_error, io_error};
+
+/// Client for the ptpimg upload API.
+pub struct PtpimgClient {
+    url: String,
+    api_key: String,
+    client: Client,
+}
+
+#[derive(Deserialize)]
+struct PtpimgImage {
+    code: String,
+    ext: String,
+}
+
+impl PtpimgClient {
+    #[must_use]
+    pub fn new(url: String, api_key: String) -> Self {
+        Self {
+            url,
+            api_key,
+            client: Client::new(),
+        }
+    }
+
+    /// Upload an image.
+    ///
+    /// Returns the URL of the uploaded image.
+    pub async fn upload(&self, path: &Path) -> Result<String, Error> {
+        let buffer = read(path).await.map_err(|e| io_error(e, "read image"))?;
+        let file_name = path
+            .file_name()
+            .map(|x| x.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let part = Part::bytes(buffer)
+            .file_name(file_name)
+            .mime_str("image/png")
+            .map_err(|e| http_error(e, "create ptpimg request"))?;
+        let form = Form::new()
+            .part("file-upload[0]", part)
+            .text("api_key", self.api_key.clone());
+        let url = format!("{}/upload.php", self.url);
+        let images: Vec<PtpimgImage> = self
+            .client
+            .post(url)
+            .multipart(form)
+            .send()
+            .await
+            .and_then(Response::error_for_status)
+            .map_err(|e| http_error(e, "upload image to ptpimg"))?
+            .json()
+            .await
+            .map_err(|e| http_error(e, "deserialize ptpimg response"))?;
+        let image = images.first().ok_or_else(|| {
+            error(
+                "upload image to ptpimg",
+                "ptpimg response did not contain an image".to_owned(),
+            )
+        })?;
+        Ok(format!("{}/{}.{}", self.url, image.code, image.ext))
+    }
+}
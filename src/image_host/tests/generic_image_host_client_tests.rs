@@ -0,0 +1,64 @@
+use serde_json::json;
+
+use crate::image_host::*;
+use crate::testing::{create_png, MockRequest, MockResponse, MockServer};
+
+fn handler(request: &MockRequest) -> MockResponse {
+    match request.path.as_str() {
+        "/text" => MockResponse::text("https://images.example.com/a.png\n"),
+        "/json" => MockResponse::json(&json!({ "url": "https://images.example.com/b.png" })),
+        "/nested" => MockResponse::json(&json!({
+            "status": 200,
+            "image": { "url": "https://images.example.com/c.png" }
+        })),
+        "/invalid" => MockResponse::json(&json!({ "status": "ok" })),
+        _ => MockResponse::status(404),
+    }
+}
+
+#[tokio::test]
+async fn generic_image_host_upload() {
+    // Arrange
+    let server = MockServer::start(handler).await;
+    let path = create_png("generic_image_host_client", "01 Track.flac.full.png");
+    let cases = [
+        ("/text", "https://images.example.com/a.png"),
+        ("/json", "https://images.example.com/b.png"),
+        ("/nested", "https://images.example.com/c.png"),
+    ];
+
+    for (endpoint, expected) in cases {
+        let client = ImageHostClient::Generic(GenericImageHostClient::new(
+            format!("{}{endpoint}", server.url),
+            "secret".to_owned(),
+        ));
+
+        // Act
+        let url = client.upload(&path).await.expect("should upload");
+
+        // Assert
+        assert_eq!(url, expected);
+    }
+    let requests = server.get_requests();
+    let upload = requests.first().expect("should have upload request");
+    let body = upload.get_body_text();
+    assert!(body.contains("name=\"api_key\"\r\n\r\nsecret"));
+    assert!(body.contains("name=\"file\"; filename=\"01 Track.flac.full.png\""));
+}
+
+#[tokio::test]
+async fn generic_image_host_upload_without_url() {
+    // Arrange
+    let server = MockServer::start(handler).await;
+    let client = ImageHostClient::Generic(GenericImageHostClient::new(
+        format!("{}/invalid", server.url),
+        "secret".to_owned(),
+    ));
+    let path = create_png("generic_image_host_client", "01 Track.flac.full.png");
+
+    // Act
+    let result = client.upload(&path).await;
+
+    // Assert
+    assert!(result.is_err());
+}
@@ -0,0 +1,2 @@
+mod generic_image_host_client_tests;
+mod ptpimg_client_tests;
@@ -0,0 +1,47 @@
+use serde_json::json;
+
+use crate::image_host::*;
+use crate::testing::{create_png, MockRequest, MockResponse, MockServer};
+
+fn handler(request: &MockRequest) -> MockResponse {
+    match request.path.as_str() {
+        "/upload.php" => MockResponse::json(&json!([{ "code": "abc123", "ext": "png" }])),
+        _ => MockResponse::status(404),
+    }
+}
+
+#[tokio::test]
+async fn ptpimg_upload() {
+    // Arrange
+    let server = MockServer::start(handler).await;
+    let client =
+        ImageHostClient::Ptpimg(PtpimgClient::new(server.url.clone(), "secret".to_owned()));
+    let path = create_png("ptpimg_client", "01 Track.flac.zoom.png");
+
+    // Act
+    let url = client.upload(&path).await.expect("should upload");
+
+    // Assert
+    assert_eq!(url, format!("{}/abc123.png", server.url));
+    let requests = server.get_requests();
+    let upload = requests.first().expect("should have upload request");
+    assert_eq!(upload.method, "POST");
+    let body = upload.get_body_text();
+    assert!(body.contains("name=\"api_key\"\r\n\r\nsecret"));
+    assert!(body.contains("name=\"file-upload[0]\"; filename=\"01 Track.flac.zoom.png\""));
+}
+
+#[tokio::test]
+async fn ptpimg_upload_rejected() {
+    // Arrange
+    let server = MockServer::start(|_| MockResponse::status(401)).await;
+    let client =
+        ImageHostClient::Ptpimg(PtpimgClient::new(server.url.clone(), "invalid".to_owned()));
+    let path = create_png("ptpimg_client", "01 Track.flac.zoom.png");
+
+    // Act
+    let result = client.upload(&path).await;
+
+    // Assert
+    assert!(result.is_err());
+}
@@ -63,6 +63,33 @@ impl ImdlCommand {
         serde_json::from_reader(reader).map_err(|e| json_error(e, "deserialize torrent"))
     }
 
+    /// Get a summary of a torrent file buffer.
+    pub async fn show_from_buffer(buffer: &[u8]) -> Result<TorrentSummary, Error> {
+        let mut child = Command::new(IMDL)
+            .arg("torrent")
+            .arg("show")
+            .arg("--json")
+            .arg("-")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| command_error(e, "execute read torrent", IMDL))?;
+        let mut stdin = child.stdin.take().expect("stdin should be available");
+        stdin
+            .write_all(buffer)
+            .await
+            .map_err(|e| command_error(e, "writing buffer to read torrent", IMDL))?;
+        drop(stdin);
+        let output = child
+            .wait_with_output()
+            .await
+            .map_err(|e| command_error(e, "get output of read torrent", IMDL))?;
+        let output = OutputHandler::execute(output, "read torrent", "IMDL")?;
+        let reader = output.stdout.reader();
+        serde_json::from_reader(reader).map_err(|e| json_error(e, "deserialize torrent"))
+    }
+
     /// Verify files match the torrent metadata.
     pub async fn verify(
         torrent_file: &Path,
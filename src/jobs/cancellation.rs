@@ -0,0 +1,68 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use rogue_logging::Error;
+use tokio::sync::RwLock;
+use tokio::task::spawn_blocking;
+
+use crate::errors::{error, task_error};
+
+/// Cancel the work a [`Job`](crate::jobs::Job) runs on blocking threads.
+///
+/// Dropping the future of a job does not stop work on a blocking thread so without this a
+/// timed out job could continue to write its partial output while it's removed or while
+/// the job is retried.
+///
+/// Blocking work started by [`Cancellation::spawn_blocking`] holds a read lock until it
+/// finishes so [`Cancellation::cancel`] can wait for it.
+#[derive(Clone, Default)]
+pub struct Cancellation {
+    cancelled: Arc<AtomicBool>,
+    running: Arc<RwLock<()>>,
+}
+
+impl Cancellation {
+    /// Has the job been cancelled?
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Return an error if the job has been cancelled.
+    pub fn check(&self, action: &str) -> Result<(), Error> {
+        if self.is_cancelled() {
+            Err(error(action, "Cancelled".to_owned()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Run `work` on a blocking thread.
+    ///
+    /// `work` should call [`Cancellation::check`] before each expensive step.
+    pub async fn spawn_blocking<T, F>(&self, action: &str, work: F) -> Result<T, Error>
+    where
+        F: FnOnce(&Cancellation) -> Result<T, Error> + Send + 'static,
+        T: Send + 'static,
+    {
+        let guard = self
+            .running
+            .clone()
+            .try_read_owned()
+            .map_err(|_| error(action, "Cancelled".to_owned()))?;
+        self.check(action)?;
+        let cancellation = self.clone();
+        spawn_blocking(move || {
+            let _guard = guard;
+            work(&cancellation)
+        })
+        .await
+        .map_err(|e| task_error(e, action))?
+    }
+
+    /// Cancel the job and wait until its blocking work has finished.
+    pub async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        let _lock = self.running.write().await;
+    }
+}
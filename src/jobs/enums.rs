@@ -5,6 +5,7 @@ pub enum Status {
     Created,
     Queued,
     Started,
+    Retrying,
     Completed,
 }
 
@@ -15,6 +16,7 @@ impl Display for Status {
             Status::Created => write!(formatter, "Created"),
             Status::Queued => write!(formatter, "Queued"),
             Status::Started => write!(formatter, "Started"),
+            Status::Retrying => write!(formatter, "Retrying"),
             Status::Completed => write!(formatter, "Completed"),
         }
     }
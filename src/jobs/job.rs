@@ -1,6 +1,8 @@
+use crate::jobs::{Cancellation, JobCost, JobKind};
 use crate::spectrogram::SpectrogramJob;
 use crate::transcode::{AdditionalJob, TranscodeJob};
 use rogue_logging::Error;
+use std::path::PathBuf;
 
 /// A job is a stand-alone object that contains all the information needed to perform
 /// a piece of work.
@@ -13,6 +15,7 @@ use rogue_logging::Error;
 /// by a [`JobRunner`].
 ///
 /// In theory, they could produce a result but the implement here is `Result<()>`.
+#[derive(Clone)]
 pub enum Job {
     Additional(AdditionalJob),
     Spectrogram(SpectrogramJob),
@@ -35,12 +38,48 @@ impl Job {
         }
     }
 
+    /// Get the kind of the wrapped command.
+    #[must_use]
+    pub fn get_kind(&self) -> JobKind {
+        match self {
+            Job::Additional(_) => JobKind::Additional,
+            Job::Spectrogram(_) => JobKind::Spectrogram,
+            Job::Transcode(_) => JobKind::Transcode,
+        }
+    }
+
+    /// Get the resources the wrapped command uses.
+    ///
+    /// A transcode pipes a decode process to an encode process but decoding FLAC takes a
+    /// small fraction of the CPU time of encoding so it's charged as one CPU.
+    /// Resizing an image decodes and encodes on one CPU and reads and writes a file so it's
+    /// charged one CPU and one IO permit.
+    #[must_use]
+    pub fn get_cost(&self) -> JobCost {
+        match self {
+            Job::Additional(_) => JobCost { cpu: 1, io: 1 },
+            Job::Spectrogram(_) | Job::Transcode(_) => JobCost { cpu: 1, io: 0 },
+        }
+    }
+
+    /// Get the path of the file the wrapped command writes.
+    #[must_use]
+    pub fn get_output_path(&self) -> PathBuf {
+        match self {
+            Job::Additional(job) => job.resize.output.clone(),
+            Job::Spectrogram(job) => job.output_path.clone(),
+            Job::Transcode(job) => job.variant.get_output(),
+        }
+    }
+
     /// Execute the wrapped command.
-    pub async fn execute(self) -> Result<(), Error> {
+    ///
+    /// Blocking work of the command is stopped by `cancellation`.
+    pub async fn execute(self, cancellation: &Cancellation) -> Result<(), Error> {
         match self {
-            Job::Additional(job) => job.execute().await,
-            Job::Spectrogram(job) => job.execute().await,
-            Job::Transcode(job) => job.execute().await,
+            Job::Additional(job) => job.execute(cancellation).await,
+            Job::Spectrogram(job) => job.execute(cancellation).await,
+            Job::Transcode(job) => job.execute(cancellation).await,
         }
     }
 }
@@ -0,0 +1,16 @@
+/// Kind of [`Job`](crate::jobs::Job) used to apply a separate concurrency limit.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum JobKind {
+    Additional,
+    Spectrogram,
+    Transcode,
+}
+
+/// Resources a [`Job`](crate::jobs::Job) uses while it runs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct JobCost {
+    /// Number of CPU permits, typically one for each CPU bound process
+    pub cpu: u32,
+    /// Number of IO permits
+    pub io: u32,
+}
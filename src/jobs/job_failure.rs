@@ -0,0 +1,31 @@
+use rogue_logging::Error;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::error;
+
+/// A [`Job`](crate::jobs::Job) that failed, with the error it failed with.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct JobFailure {
+    /// ID of the job
+    pub id: String,
+    /// Error the job failed with
+    pub error: Error,
+}
+
+impl JobFailure {
+    /// Combine `failures` into a single [`Error`] listing each failed job.
+    #[must_use]
+    pub fn to_error(failures: &[JobFailure]) -> Error {
+        if let [failure] = failures {
+            return failure.error.clone();
+        }
+        let lines: Vec<String> = failures
+            .iter()
+            .map(|failure| format!("{}: {}", failure.id, failure.error))
+            .collect();
+        error(
+            "execute jobs",
+            format!("{} jobs failed\n{}", failures.len(), lines.join("\n")),
+        )
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::BTreeSet;
+use std::fs::{read_to_string, remove_file, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use log::trace;
+use rogue_logging::Error;
+
+use crate::errors::{io_error, json_error};
+
+/// Journal of the jobs of a source that have completed.
+///
+/// If a run is interrupted the journal is read by the next run so only the jobs that
+/// didn't complete are executed again.
+///
+/// Each line of the journal file is the JSON encoded output path of a completed job.
+/// Lines are appended and synced as each job completes so a crash loses at most the line
+/// being written, and an incomplete line is ignored when the journal is read.
+///
+/// Outputs are synced by [`rename_partial`](crate::jobs::rename_partial) before they're
+/// recorded so a recorded output is never lost to a crash.
+pub struct JobJournal {
+    path: PathBuf,
+    completed: Mutex<BTreeSet<PathBuf>>,
+}
+
+impl JobJournal {
+    /// Read the journal at `path`.
+    ///
+    /// If the file does not exist the journal is empty.
+    pub fn read(path: PathBuf) -> Result<Self, Error> {
+        let contents = match read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(io_error(e, "read job journal")),
+        };
+        let completed = contents
+            .lines()
+            .filter_map(|line| serde_json::from_str::<PathBuf>(line).ok())
+            .collect();
+        Ok(Self {
+            path,
+            completed: Mutex::new(completed),
+        })
+    }
+
+    /// Has the job writing to `output` completed?
+    ///
+    /// The output must still exist.
+    #[must_use]
+    pub fn is_completed(&self, output: &Path) -> bool {
+        self.completed
+            .lock()
+            .expect("journal should be available")
+            .contains(output)
+            && output.is_file()
+    }
+
+    /// Get the number of completed jobs.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.completed
+            .lock()
+            .expect("journal should be available")
+            .len()
+    }
+
+    /// Is the journal empty?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Record that the job writing to `output` has completed.
+    pub fn record(&self, output: &Path) -> Result<(), Error> {
+        let mut completed = self.completed.lock().expect("journal should be available");
+        let line = serde_json::to_string(output).map_err(|e| json_error(e, "encode journal"))?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| io_error(e, "open job journal"))?;
+        writeln!(file, "{line}").map_err(|e| io_error(e, "write job journal"))?;
+        file.sync_data()
+            .map_err(|e| io_error(e, "sync job journal"))?;
+        completed.insert(output.to_path_buf());
+        Ok(())
+    }
+
+    /// Remove the journal file once every job of the source has completed.
+    pub fn remove(&self) -> Result<(), Error> {
+        match remove_file(&self.path) {
+            Ok(()) => {
+                trace!("Removed job journal {}", self.path.display());
+                Ok(())
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(io_error(e, "remove job journal")),
+        }
+    }
+}
@@ -1,109 +1,247 @@
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::errors::task_error;
+use crate::errors::{task_error, TIMEOUT_DOMAIN};
 use crate::jobs::*;
+use crate::options::RunnerOptions;
+use colored::Colorize;
 use di::{injectable, Ref, RefMut};
+use humantime::format_duration;
+use log::warn;
 use rogue_logging::Error;
-use tokio::sync::Semaphore;
 use tokio::task::JoinSet;
+use tokio::time::{sleep, timeout};
 
 /// Execute a [Job] in parallel across a restricted number of threads.
 ///
-/// [Semaphore] is used to limit the number of commands that can be executed concurrently.
+/// [Scheduler] is used to limit the resources used by commands that are executed concurrently.
 /// [`JoinSet`] is used to execute commands in parallel, and collate the results.
 /// [Publisher] is updated by an
 /// [observer design pattern](https://refactoring.guru/design-patterns/observer) when the status
 /// of a [Job] changes.
+///
+/// Each job is limited to [`RunnerOptions::job_timeout`]. If a job fails, times out or is
+/// aborted its partial output is removed by [`PartialOutput`].
+///
+/// A failed job is retried according to the [`RetryPolicy`] of [`RunnerOptions`].
 pub struct JobRunner {
-    pub semaphore: Arc<Semaphore>,
-    pub set: RefMut<JoinSet<Result<(), Error>>>,
+    pub scheduler: Ref<Scheduler>,
+    pub set: RefMut<JoinSet<Result<(), JobFailure>>>,
     pub publisher: Ref<Publisher>,
+    pub options: Ref<RunnerOptions>,
 }
 
 #[injectable]
 impl JobRunner {
     /// Create a new [`JobRunner`].
     pub fn new(
-        semaphore: Arc<Semaphore>,
-        set: RefMut<JoinSet<Result<(), Error>>>,
+        scheduler: Ref<Scheduler>,
+        set: RefMut<JoinSet<Result<(), JobFailure>>>,
         publisher: Ref<Publisher>,
+        options: Ref<RunnerOptions>,
     ) -> Self {
         Self {
-            semaphore,
+            scheduler,
             set,
             publisher,
+            options,
         }
     }
 
     /// Add commands to be run when [execute] is called.
     pub fn add(&self, jobs: Vec<Job>) {
-        for job in jobs {
-            let id = job.get_id();
-            let semaphore = self.semaphore.clone();
-            let publisher = self.publisher.clone();
-            publisher.update(&id, Created);
-            let mut set = self.set.write().expect("join set to be writeable");
-            set.spawn(async move {
-                publisher.update(&id, Queued);
-                let _permit = semaphore
-                    .acquire()
-                    .await
-                    .expect("Semaphore should be available");
-                publisher.update(&id, Started);
-                job.execute().await?;
-                publisher.update(&id, Completed);
-                Ok(())
-            });
-        }
+        self.spawn(jobs, None, true);
+    }
+
+    /// Add commands to be run when [execute] is called.
+    ///
+    /// The output of each command is recorded in `journal` once it completes.
+    pub fn add_journaled(&self, jobs: Vec<Job>, journal: &Arc<JobJournal>) {
+        self.spawn(jobs, Some(journal), true);
     }
 
     /// Add commands to be run when [execute] is called.
-    pub fn add_without_publish(&self, jobs: Vec<Job>) {
+    ///
+    /// The output of each command is recorded in `journal` once it completes.
+    pub fn add_journaled_without_publish(&self, jobs: Vec<Job>, journal: &Arc<JobJournal>) {
+        self.spawn(jobs, Some(journal), false);
+    }
+
+    fn spawn(&self, jobs: Vec<Job>, journal: Option<&Arc<JobJournal>>, publish: bool) {
+        let job_timeout = self.options.get_job_timeout();
+        let retry = self.options.get_retry_policy();
         for job in jobs {
-            let semaphore = self.semaphore.clone();
+            let id = job.get_id();
+            let scheduler = self.scheduler.clone();
+            let publisher = publish.then(|| self.publisher.clone());
+            let journal = journal.cloned();
+            let retry = retry.clone();
+            if let Some(publisher) = &publisher {
+                publisher.update(&id, Created);
+            }
             let mut set = self.set.write().expect("join set to be writeable");
             set.spawn(async move {
-                let _permit = semaphore
-                    .acquire()
-                    .await
-                    .expect("Semaphore should be available");
-                job.execute().await?;
+                if let Some(publisher) = &publisher {
+                    publisher.update(&id, Queued);
+                }
+                let output = job.get_output_path();
+                let result =
+                    execute_job(job, &scheduler, job_timeout, &retry, publisher.as_deref()).await;
+                let result = match (result, journal) {
+                    (Ok(()), Some(journal)) => journal.record(&output),
+                    (result, _) => result,
+                };
+                result.map_err(|error| JobFailure {
+                    id: id.clone(),
+                    error,
+                })?;
+                if let Some(publisher) = &publisher {
+                    publisher.update(&id, Completed);
+                }
                 Ok(())
             });
         }
     }
 
+    /// Execute the added commands.
+    ///
+    /// Returns an [`Error`] if any of the commands failed.
     pub async fn execute(&self) -> Result<(), Error> {
-        self.execute_internal(true).await
+        into_result(self.execute_internal(true).await?)
     }
 
     pub async fn execute_without_publish(&self) -> Result<(), Error> {
-        self.execute_internal(false).await
+        into_result(self.execute_internal(false).await?)
+    }
+
+    /// Execute the added commands and return each command that failed.
+    ///
+    /// Unless [`RunnerOptions::collect_failures`] is set the remaining commands are stopped
+    /// after the first failure.
+    pub async fn execute_with_failures(&self) -> Result<Vec<JobFailure>, Error> {
+        self.execute_internal(true).await
     }
 
-    async fn execute_internal(&self, publish: bool) -> Result<(), Error> {
+    async fn execute_internal(&self, publish: bool) -> Result<Vec<JobFailure>, Error> {
         if publish {
             self.publisher.start("");
         }
+        let collect_failures = self
+            .options
+            .collect_failures
+            .expect("collect_failures should be set");
+        let mut failures = Vec::new();
         let mut set = self.set.write().expect("join set to be writeable");
         while let Some(result) = set.join_next().await {
-            let result = match result {
-                Ok(result) => result,
+            match result {
+                Ok(Ok(())) => {}
+                Ok(Err(failure)) => {
+                    failures.push(failure);
+                    if !collect_failures {
+                        set.shutdown().await;
+                    }
+                }
                 Err(e) => {
-                    set.abort_all();
-                    set.detach_all();
+                    set.shutdown().await;
                     return Err(task_error(e, "executing task"));
                 }
-            };
-            if let Err(e) = result {
-                set.abort_all();
-                set.detach_all();
-                return Err(e);
             }
         }
         if publish {
             self.publisher.finish("");
         }
+        Ok(failures)
+    }
+}
+
+/// Execute `job`, retrying if it fails with an error matching the [`RetryPolicy`].
+///
+/// The resources of the job are acquired from the `scheduler` for each attempt so they're
+/// available to other jobs while waiting to retry.
+///
+/// If a `publisher` is provided it's updated when each attempt starts and when the job
+/// is retried.
+async fn execute_job(
+    job: Job,
+    scheduler: &Scheduler,
+    job_timeout: Option<Duration>,
+    retry: &RetryPolicy,
+    publisher: Option<&Publisher>,
+) -> Result<(), Error> {
+    let id = job.get_id();
+    let kind = job.get_kind();
+    let cost = job.get_cost();
+    let mut job = Some(job);
+    let mut attempt: u8 = 1;
+    loop {
+        // Only clone if there could be another attempt
+        let current = if attempt < retry.attempts {
+            job.clone()
+        } else {
+            job.take()
+        }
+        .expect("job should be available");
+        let permits = scheduler.acquire(kind, cost).await;
+        if let Some(publisher) = publisher {
+            publisher.update(&id, Started);
+        }
+        let result = execute_attempt(current, job_timeout).await;
+        drop(permits);
+        let error = match result {
+            Ok(()) => return Ok(()),
+            Err(error) => error,
+        };
+        if !retry.should_retry(&error, attempt) {
+            return Err(error);
+        }
+        let delay = retry.get_delay(attempt);
+        warn!(
+            "{} {id} in {} after attempt {attempt} of {} failed: {error}",
+            "Retrying".bold(),
+            format_duration(delay),
+            retry.attempts
+        );
+        if let Some(publisher) = publisher {
+            publisher.update(&id, Retrying);
+        }
+        sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// Execute `job` once, stopping it if it takes longer than `job_timeout`.
+///
+/// The partial output of the job is removed unless it completes.
+///
+/// If the job times out its blocking work is cancelled and awaited before the partial
+/// output is removed so it can't write to the output of a retry.
+async fn execute_attempt(job: Job, job_timeout: Option<Duration>) -> Result<(), Error> {
+    let output = PartialOutput::new(get_partial_path(&job.get_output_path()));
+    let cancellation = Cancellation::default();
+    match job_timeout {
+        Some(duration) => {
+            let result = timeout(duration, job.execute(&cancellation)).await;
+            let Ok(result) = result else {
+                cancellation.cancel().await;
+                return Err(Error {
+                    action: "execute job".to_owned(),
+                    message: format!("Timed out after {}", format_duration(duration)),
+                    domain: Some(TIMEOUT_DOMAIN.to_owned()),
+                    ..Error::default()
+                });
+            };
+            result?;
+        }
+        None => job.execute(&cancellation).await?,
+    }
+    output.complete();
+    Ok(())
+}
+
+fn into_result(failures: Vec<JobFailure>) -> Result<(), Error> {
+    if failures.is_empty() {
         Ok(())
+    } else {
+        Err(JobFailure::to_error(&failures))
     }
 }
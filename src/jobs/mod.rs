@@ -1,16 +1,32 @@
+pub use cancellation::*;
 pub use enums::Status::*;
 pub use enums::*;
 pub use job::*;
+pub use job_cost::*;
+pub use job_failure::*;
+pub use job_journal::*;
 pub use job_runner::*;
+pub use partial_output::*;
 pub use publisher::*;
+pub use retry_policy::*;
+pub use scheduler::*;
 pub use subscriber::*;
 pub use subscriber_debug::*;
 pub use subscriber_progress_bar::*;
 
+pub(crate) mod cancellation;
 pub(crate) mod enums;
 pub(crate) mod job;
+pub(crate) mod job_cost;
+pub(crate) mod job_failure;
+pub(crate) mod job_journal;
 pub(crate) mod job_runner;
+pub(crate) mod partial_output;
 pub(crate) mod publisher;
+pub(crate) mod retry_policy;
+pub(crate) mod scheduler;
 pub(crate) mod subscriber;
 pub(crate) mod subscriber_debug;
 pub(crate) mod subscriber_progress_bar;
+#[cfg(test)]
+mod tests;
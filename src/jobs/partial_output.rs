@@ -0,0 +1,136 @@
+use std::fs::{remove_file, rename, File};
+use std::path::{Path, PathBuf};
+
+use colored::Colorize;
+use log::{trace, warn};
+use rogue_logging::Error;
+use tokio::fs::{copy, hard_link};
+
+use crate::errors::io_error;
+
+/// Infix of the file name a job writes to before it's renamed.
+const PARTIAL_INFIX: &str = "partial";
+
+/// Remove the partial output of a [`Job`](crate::jobs::Job) unless it completed.
+///
+/// Jobs write to the path from [`get_partial_path`] and rename it once complete so the
+/// output path only ever contains a complete file.
+///
+/// The partial output is removed when the guard is dropped so a job that fails, times out
+/// or is aborted by Ctrl-C doesn't leave a partially written file behind.
+pub struct PartialOutput {
+    path: Option<PathBuf>,
+}
+
+impl PartialOutput {
+    /// Create a guard for `path`.
+    ///
+    /// A file left at `path` by a previous run that was killed is removed.
+    #[must_use]
+    pub fn new(path: PathBuf) -> Self {
+        remove(&path);
+        Self { path: Some(path) }
+    }
+
+    /// Mark the output as complete so it is kept.
+    pub fn complete(mut self) {
+        self.path = None;
+    }
+}
+
+impl Drop for PartialOutput {
+    fn drop(&mut self) {
+        if let Some(path) = self.path.take() {
+            remove(&path);
+        }
+    }
+}
+
+/// Get the path a job writes to before it's renamed to `path`.
+///
+/// The extension is kept so encoders can infer the format, for example
+/// `01 Track.flac` is written to `01 Track.partial.flac`.
+#[must_use]
+pub fn get_partial_path(path: &Path) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|x| x.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let file_name = match path.extension() {
+        Some(extension) => format!("{stem}.{PARTIAL_INFIX}.{}", extension.to_string_lossy()),
+        None => format!("{stem}.{PARTIAL_INFIX}"),
+    };
+    path.with_file_name(file_name)
+}
+
+/// Rename the partial output of `path` to `path`.
+///
+/// The rename is atomic so `path` is either the previous file or the complete output.
+///
+/// The partial output is synced before the rename and the directory after it so once this
+/// returns the complete output survives a crash and can be recorded in a
+/// [`JobJournal`](crate::jobs::JobJournal).
+pub fn rename_partial(path: &Path) -> Result<(), Error> {
+    let partial_path = get_partial_path(path);
+    File::open(&partial_path)
+        .and_then(|file| file.sync_all())
+        .map_err(|e| io_error(e, "sync partial output"))?;
+    rename(partial_path, path).map_err(|e| io_error(e, "rename partial output"))?;
+    if let Some(dir) = path.parent() {
+        sync_dir(dir)?;
+    }
+    Ok(())
+}
+
+/// Sync the entries of `dir` so a rename within it survives a crash.
+#[cfg(not(target_os = "windows"))]
+fn sync_dir(dir: &Path) -> Result<(), Error> {
+    File::open(dir)
+        .and_then(|file| file.sync_all())
+        .map_err(|e| io_error(e, "sync output directory"))
+}
+
+/// Directories can't be opened to sync on Windows where renames are journaled by NTFS.
+#[cfg(target_os = "windows")]
+#[allow(clippy::unnecessary_wraps)]
+fn sync_dir(_dir: &Path) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Hard link or copy `from` to `to` via its partial path.
+///
+/// Any existing file at `to` is replaced so an interrupted run can be resumed.
+///
+/// Returns the verb to log.
+pub async fn hard_link_or_copy(
+    from: &Path,
+    to: &Path,
+    is_hard_link: bool,
+) -> Result<&'static str, Error> {
+    let partial_path = get_partial_path(to);
+    let partial = PartialOutput::new(partial_path.clone());
+    let verb = if is_hard_link {
+        hard_link(from, &partial_path)
+            .await
+            .map_err(|e| io_error(e, "hard link additional file"))?;
+        "Hard Linked"
+    } else {
+        copy(from, &partial_path)
+            .await
+            .map_err(|e| io_error(e, "copy additional file"))?;
+        "Copied"
+    };
+    rename_partial(to)?;
+    partial.complete();
+    Ok(verb)
+}
+
+fn remove(path: &Path) {
+    if !path.exists() {
+        return;
+    }
+    match remove_file(path) {
+        Ok(()) => trace!("{} partial output {}", "Removed".bold(), path.display()),
+        Err(e) => warn!("Failed to remove partial output {}: {e}", path.display()),
+    }
+}
@@ -33,6 +33,15 @@ impl Publisher {
     }
 }
 
+#[cfg(test)]
+impl Publisher {
+    /// Create a [`Publisher`] that notifies `subscribers`.
+    #[must_use]
+    pub fn with_subscribers(subscribers: Vec<Ref<dyn Subscriber + Send + Sync>>) -> Self {
+        Self { subscribers }
+    }
+}
+
 impl Subscriber for Publisher {
     /// Called when a new scope is started.
     fn start(&self, scope_id: &str) {
@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use rogue_logging::Error;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{EXIT_DOMAIN, IO_DOMAIN, SIGNAL_DOMAIN, TIMEOUT_DOMAIN};
+
+/// Class of error a failed [`Job`](crate::jobs::Job) can be retried for.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum RetryClass {
+    /// A process was terminated by a signal, for example after running out of memory.
+    Signal,
+    /// A process exited with a non-zero exit code.
+    Exit,
+    /// A file system error.
+    Io,
+    /// The job exceeded the job timeout.
+    Timeout,
+}
+
+impl RetryClass {
+    /// Get the class of `error`.
+    ///
+    /// Returns `None` if the error is not of a class that can be retried.
+    #[must_use]
+    pub fn from_error(error: &Error) -> Option<Self> {
+        match error.domain.as_deref()? {
+            SIGNAL_DOMAIN => Some(RetryClass::Signal),
+            EXIT_DOMAIN => Some(RetryClass::Exit),
+            IO_DOMAIN => Some(RetryClass::Io),
+            TIMEOUT_DOMAIN => Some(RetryClass::Timeout),
+            _ => None,
+        }
+    }
+}
+
+/// When and how often a failed [`Job`](crate::jobs::Job) is retried.
+#[derive(Clone, Debug, Default)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts including the first
+    pub attempts: u8,
+    /// Delay before the first retry which is doubled for each subsequent retry
+    pub backoff: Duration,
+    /// Classes of error to retry
+    pub classes: Vec<RetryClass>,
+}
+
+impl RetryPolicy {
+    /// Should a job that failed with `error` on `attempt` be retried?
+    ///
+    /// `attempt` starts at `1`.
+    #[must_use]
+    pub fn should_retry(&self, error: &Error, attempt: u8) -> bool {
+        attempt < self.attempts
+            && RetryClass::from_error(error).is_some_and(|class| self.classes.contains(&class))
+    }
+
+    /// Get the delay before retrying a job that failed on `attempt`.
+    #[must_use]
+    pub fn get_delay(&self, attempt: u8) -> Duration {
+        let factor = 2_u32.saturating_pow(u32::from(attempt.saturating_sub(1)));
+        self.backoff.saturating_mul(factor)
+    }
+}
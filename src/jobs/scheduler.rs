@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use di::{injectable, Ref};
+use rogue_logging::Error;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::task::spawn_blocking;
+
+use crate::errors::task_error;
+use crate::jobs::{JobCost, JobKind};
+use crate::options::RunnerOptions;
+
+/// Limit the resources used by concurrent jobs.
+///
+/// Each [`Job`](crate::jobs::Job) acquires permits for its [`JobCost`] from a shared CPU
+/// [Semaphore] and IO [Semaphore], and a permit from the [Semaphore] of its [`JobKind`] if
+/// that kind is limited.
+///
+/// Permits are always acquired in the same order (kind, IO, CPU) so jobs can't deadlock.
+pub struct Scheduler {
+    cpu: Arc<Semaphore>,
+    cpu_total: u32,
+    io: Arc<Semaphore>,
+    io_total: u32,
+    kinds: BTreeMap<JobKind, Arc<Semaphore>>,
+}
+
+/// Permits held by a running job which are released when dropped.
+pub struct Permits {
+    _permits: Vec<OwnedSemaphorePermit>,
+}
+
+#[injectable]
+impl Scheduler {
+    /// Create a new [`Scheduler`].
+    ///
+    /// `cpu` is shared so the number of permits in use can be reported.
+    pub fn new(options: Ref<RunnerOptions>, cpu: Arc<Semaphore>) -> Self {
+        let cpu_total = u32::from(options.cpus.expect("cpus should be set"));
+        let io_limit = options.io_limit.expect("io_limit should be set");
+        let limits = [
+            (JobKind::Additional, options.additional_limit),
+            (JobKind::Spectrogram, options.spectrogram_limit),
+            (JobKind::Transcode, options.transcode_limit),
+        ];
+        let kinds = limits
+            .into_iter()
+            .filter_map(|(kind, limit)| Some((kind, Arc::new(Semaphore::new(usize::from(limit?))))))
+            .collect();
+        Self {
+            cpu,
+            cpu_total,
+            io: Arc::new(Semaphore::new(usize::from(io_limit))),
+            io_total: u32::from(io_limit),
+            kinds,
+        }
+    }
+
+    /// Wait until the resources of a job of `kind` and `cost` are available.
+    ///
+    /// A cost greater than the total is limited to the total so the job can still run.
+    pub async fn acquire(&self, kind: JobKind, cost: JobCost) -> Permits {
+        let mut permits = Vec::new();
+        if let Some(semaphore) = self.kinds.get(&kind) {
+            permits.push(acquire(semaphore, 1).await);
+        }
+        if cost.io > 0 {
+            permits.push(acquire(&self.io, cost.io.min(self.io_total)).await);
+        }
+        if cost.cpu > 0 {
+            permits.push(acquire(&self.cpu, cost.cpu.min(self.cpu_total)).await);
+        }
+        Permits { _permits: permits }
+    }
+
+    /// Wait until an IO permit is available for work outside of a job,
+    /// such as hard linking or copying files.
+    pub async fn acquire_io(&self) -> Permits {
+        Permits {
+            _permits: vec![acquire(&self.io, 1).await],
+        }
+    }
+
+    /// Wait until a CPU permit is available then run CPU bound work outside of a job,
+    /// such as verification or loudness analysis, on a blocking thread.
+    pub async fn execute_blocking<T, F>(&self, action: &str, work: F) -> Result<T, Error>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let _permit = acquire(&self.cpu, 1).await;
+        spawn_blocking(work)
+            .await
+            .map_err(|e| task_error(e, action))
+    }
+}
+
+async fn acquire(semaphore: &Arc<Semaphore>, count: u32) -> OwnedSemaphorePermit {
+    semaphore
+        .clone()
+        .acquire_many_owned(count)
+        .await
+        .expect("Semaphore should be available")
+}
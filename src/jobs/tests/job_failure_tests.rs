@@ -0,0 +1,42 @@
+use crate::errors::error;
+use crate::jobs::JobFailure;
+
+#[test]
+fn job_failure_to_error_single() {
+    // Arrange
+    let failures = vec![JobFailure {
+        id: "Transcode 320  1".to_owned(),
+        error: error("execute job", "Timed out after 30m".to_owned()),
+    }];
+
+    // Act
+    let error = JobFailure::to_error(&failures);
+
+    // Assert
+    assert_eq!(error.action, "execute job");
+    assert_eq!(error.message, "Timed out after 30m");
+}
+
+#[test]
+fn job_failure_to_error_multiple() {
+    // Arrange
+    let failures = vec![
+        JobFailure {
+            id: "Transcode 320  1".to_owned(),
+            error: error("execute job", "Timed out after 30m".to_owned()),
+        },
+        JobFailure {
+            id: "Transcode 320  2".to_owned(),
+            error: error("verify MP3", "no MPEG audio frames were found".to_owned()),
+        },
+    ];
+
+    // Act
+    let error = JobFailure::to_error(&failures);
+
+    // Assert
+    assert_eq!(error.action, "execute jobs");
+    assert!(error.message.starts_with("2 jobs failed"));
+    assert!(error.message.contains("Transcode 320  1"));
+    assert!(error.message.contains("Transcode 320  2"));
+}
@@ -0,0 +1,74 @@
+use std::fs::{read_to_string, write, OpenOptions};
+use std::io::Write;
+
+use crate::jobs::JobJournal;
+use crate::testing::TempDirectory;
+
+#[test]
+fn job_journal_record_and_read() {
+    // Arrange
+    let dir = TempDirectory::create("job_journal_record_and_read");
+    let journal_path = dir.join("journal.jsonl");
+    let completed = dir.join("01 Track.flac");
+    let missing = dir.join("02 Track.flac");
+    write(&completed, b"complete").expect("should write file");
+    let journal = JobJournal::read(journal_path.clone()).expect("should read journal");
+
+    // Act
+    journal.record(&completed).expect("should record");
+    journal.record(&missing).expect("should record");
+    let journal = JobJournal::read(journal_path).expect("should read journal");
+
+    // Assert
+    assert_eq!(journal.len(), 2);
+    assert!(journal.is_completed(&completed));
+    assert!(!journal.is_completed(&missing));
+}
+
+#[test]
+fn job_journal_ignores_incomplete_line() {
+    // Arrange
+    let dir = TempDirectory::create("job_journal_ignores_incomplete_line");
+    let journal_path = dir.join("journal.jsonl");
+    let completed = dir.join("01 Track.flac");
+    write(&completed, b"complete").expect("should write file");
+    let journal = JobJournal::read(journal_path.clone()).expect("should read journal");
+    journal.record(&completed).expect("should record");
+    let mut file = OpenOptions::new()
+        .append(true)
+        .open(&journal_path)
+        .expect("should open journal");
+    write!(file, "\"{}/02 Tr", dir.display()).expect("should write journal");
+
+    // Act
+    let journal = JobJournal::read(journal_path.clone()).expect("should read journal");
+
+    // Assert
+    assert_eq!(journal.len(), 1);
+    assert!(journal.is_completed(&completed));
+    assert_eq!(
+        read_to_string(&journal_path)
+            .expect("should read journal")
+            .lines()
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn job_journal_remove() {
+    // Arrange
+    let dir = TempDirectory::create("job_journal_remove");
+    let journal_path = dir.join("journal.jsonl");
+    let journal = JobJournal::read(journal_path.clone()).expect("should read journal");
+    journal
+        .record(&dir.join("01 Track.flac"))
+        .expect("should record");
+
+    // Act
+    journal.remove().expect("should remove");
+
+    // Assert
+    assert!(!journal_path.exists());
+    assert!(journal.remove().is_ok());
+}
@@ -0,0 +1,195 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use image::{Rgb, RgbImage};
+use tokio::join;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tokio::time::sleep;
+
+use crate::errors::{IO_DOMAIN, TIMEOUT_DOMAIN};
+use crate::jobs::*;
+use crate::options::{Options, RunnerOptions};
+use crate::testing::TempDirectory;
+use crate::transcode::resize::Resize;
+use crate::transcode::AdditionalJob;
+
+/// A [`Subscriber`] that records each status update.
+#[derive(Default)]
+struct RecordingSubscriber {
+    updates: Mutex<Vec<String>>,
+}
+
+impl RecordingSubscriber {
+    fn get_updates(&self) -> Vec<String> {
+        self.updates
+            .lock()
+            .expect("updates should be readable")
+            .clone()
+    }
+}
+
+impl Subscriber for RecordingSubscriber {
+    fn start(&self, _scope_id: &str) {}
+
+    fn finish(&self, _scope_id: &str) {}
+
+    fn update(&self, job_id: &str, status: Status) {
+        self.updates
+            .lock()
+            .expect("updates should be writeable")
+            .push(format!("{job_id} {status}"));
+    }
+}
+
+fn create_runner(mut options: RunnerOptions) -> (JobRunner, Arc<RecordingSubscriber>) {
+    options.apply_defaults();
+    let options = Arc::new(options);
+    let cpus = usize::from(options.cpus.expect("cpus should be set"));
+    let scheduler = Scheduler::new(options.clone(), Arc::new(Semaphore::new(cpus)));
+    let subscriber = Arc::new(RecordingSubscriber::default());
+    let publisher = Publisher::with_subscribers(vec![subscriber.clone()]);
+    let runner = JobRunner::new(
+        Arc::new(scheduler),
+        Arc::new(RwLock::new(JoinSet::new())),
+        Arc::new(publisher),
+        options,
+    );
+    (runner, subscriber)
+}
+
+/// Create a job that resizes `input` to `output`.
+fn create_job(id: &str, input: PathBuf, output: PathBuf) -> Job {
+    Job::Additional(AdditionalJob {
+        id: id.to_owned(),
+        resize: Resize {
+            input,
+            output,
+            max_pixel_size: 100,
+            quality: 80,
+            max_file_size: None,
+        },
+    })
+}
+
+/// Create an image that is slow to decode and resize.
+fn create_large_image(path: &Path) {
+    RgbImage::from_fn(500, 500, |x, y| {
+        Rgb([x.to_le_bytes()[0], y.to_le_bytes()[0], 0])
+    })
+    .save(path)
+    .expect("should save image");
+}
+
+#[tokio::test]
+async fn job_runner_timeout_removes_partial_output() {
+    // Arrange
+    let dir = TempDirectory::create("job_runner_timeout");
+    let input = dir.join("large.png");
+    create_large_image(&input);
+    let output = dir.join("output.jpg");
+    let (runner, _) = create_runner(RunnerOptions {
+        job_timeout: Some("1ms".to_owned()),
+        retry_attempts: Some(1),
+        ..RunnerOptions::default()
+    });
+    runner.add(vec![create_job("Resize", input, output.clone())]);
+
+    // Act
+    let failures = runner
+        .execute_with_failures()
+        .await
+        .expect("runner should not error");
+    // The resize would finish in this time if it wasn't awaited
+    sleep(Duration::from_secs(1)).await;
+
+    // Assert
+    let failure = failures.first().expect("job should fail");
+    assert_eq!(failure.error.domain.as_deref(), Some(TIMEOUT_DOMAIN));
+    assert!(!output.exists());
+    assert!(!get_partial_path(&output).exists());
+}
+
+#[tokio::test]
+async fn job_runner_collect_failures() {
+    // Arrange
+    let dir = TempDirectory::create("job_runner_collect_failures");
+    let jobs = || {
+        (1..=3)
+            .map(|index| {
+                create_job(
+                    &format!("Resize {index}"),
+                    dir.join(format!("missing {index}.png")),
+                    dir.join(format!("output {index}.jpg")),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let options = RunnerOptions {
+        retry_attempts: Some(1),
+        ..RunnerOptions::default()
+    };
+    let (collecting, _) = create_runner(RunnerOptions {
+        collect_failures: Some(true),
+        ..options.clone()
+    });
+    let (stopping, _) = create_runner(options);
+    collecting.add(jobs());
+    stopping.add(jobs());
+
+    // Act
+    let collected = collecting
+        .execute_with_failures()
+        .await
+        .expect("runner should not error");
+    let stopped = stopping
+        .execute_with_failures()
+        .await
+        .expect("runner should not error");
+
+    // Assert
+    assert_eq!(collected.len(), 3);
+    assert!(collected
+        .iter()
+        .all(|failure| failure.error.domain.as_deref() == Some(IO_DOMAIN)));
+    assert_eq!(stopped.len(), 1);
+}
+
+#[tokio::test]
+async fn job_runner_retry_releases_resources() {
+    // Arrange
+    let dir = TempDirectory::create("job_runner_retry");
+    let input = dir.join("small.png");
+    RgbImage::new(10, 10)
+        .save(&input)
+        .expect("should save image");
+    let output = dir.join("succeeding.jpg");
+    let (runner, subscriber) = create_runner(RunnerOptions {
+        cpus: Some(1),
+        io_limit: Some(1),
+        retry_attempts: Some(2),
+        retry_backoff: Some("1s".to_owned()),
+        ..RunnerOptions::default()
+    });
+    runner.add(vec![
+        create_job("Failing", dir.join("missing.png"), dir.join("failing.jpg")),
+        create_job("Succeeding", input, output.clone()),
+    ]);
+
+    // Act
+    let (failures, is_completed_during_backoff) = join!(runner.execute_with_failures(), async {
+        sleep(Duration::from_millis(500)).await;
+        output.exists()
+    });
+
+    // Assert
+    let failures = failures.expect("runner should not error");
+    let updates = subscriber.get_updates();
+    let count = |update: &str| updates.iter().filter(|x| *x == update).count();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(count("Failing Started"), 2);
+    assert_eq!(count("Failing Retrying"), 1);
+    assert_eq!(count("Succeeding Completed"), 1);
+    assert!(is_completed_during_backoff);
+}
@@ -0,0 +1,6 @@
+mod job_failure_tests;
+mod job_journal_tests;
+mod job_runner_tests;
+mod partial_output_tests;
+mod retry_policy_tests;
+mod scheduler_tests;
@@ -0,0 +1,63 @@
+use std::fs::{read, write};
+use std::path::PathBuf;
+
+use crate::jobs::{get_partial_path, rename_partial, PartialOutput};
+use crate::testing::TempDirectory;
+
+#[test]
+fn partial_output_removes_incomplete() {
+    // Arrange
+    let dir = TempDirectory::create("partial_output_removes_incomplete");
+    let path = dir.join("01 Track.mp3");
+    let output = PartialOutput::new(path.clone());
+    write(&path, b"partial").expect("should write file");
+
+    // Act
+    drop(output);
+
+    // Assert
+    assert!(!path.exists());
+}
+
+#[test]
+fn partial_output_keeps_complete() {
+    // Arrange
+    let dir = TempDirectory::create("partial_output_keeps_complete");
+    let path = dir.join("01 Track.mp3");
+    let output = PartialOutput::new(path.clone());
+    write(&path, b"complete").expect("should write file");
+
+    // Act
+    output.complete();
+
+    // Assert
+    assert!(path.exists());
+}
+
+#[test]
+fn get_partial_path_keeps_extension() {
+    // Arrange
+    let path = PathBuf::from("/output/01 Track.flac");
+
+    // Act
+    let partial = get_partial_path(&path);
+
+    // Assert
+    assert_eq!(partial, PathBuf::from("/output/01 Track.partial.flac"));
+}
+
+#[test]
+fn rename_partial_replaces_output() {
+    // Arrange
+    let dir = TempDirectory::create("rename_partial_replaces_output");
+    let path = dir.join("cover.jpg");
+    write(&path, b"previous").expect("should write file");
+    write(get_partial_path(&path), b"complete").expect("should write file");
+
+    // Act
+    rename_partial(&path).expect("should rename");
+
+    // Assert
+    assert_eq!(read(&path).expect("should read file"), b"complete");
+    assert!(!get_partial_path(&path).exists());
+}
@@ -0,0 +1,66 @@
+use std::io::Error;
+use std::time::Duration;
+
+use crate::errors::{error, io_error, output_error, CommandError};
+use crate::jobs::{RetryClass, RetryPolicy};
+
+fn create_policy() -> RetryPolicy {
+    RetryPolicy {
+        attempts: 3,
+        backoff: Duration::from_secs(5),
+        classes: vec![RetryClass::Signal, RetryClass::Io],
+    }
+}
+
+fn create_command_error(exit_code: Option<i32>, exit_signal: Option<i32>) -> CommandError {
+    CommandError {
+        stderr: "lame: failed".to_owned(),
+        stdout: String::new(),
+        exit_code,
+        exit_signal,
+        exit_stopped_signal: None,
+    }
+}
+
+#[test]
+fn retry_class_from_error() {
+    // Arrange
+    let signal = output_error(create_command_error(None, Some(9)), "encode", "transcode");
+    let exit = output_error(create_command_error(Some(1), None), "encode", "transcode");
+    let io = io_error(Error::other("disk full"), "write file");
+    let other = error("verify MP3", "no MPEG audio frames were found".to_owned());
+
+    // Act
+    // Assert
+    assert_eq!(RetryClass::from_error(&signal), Some(RetryClass::Signal));
+    assert_eq!(RetryClass::from_error(&exit), Some(RetryClass::Exit));
+    assert_eq!(RetryClass::from_error(&io), Some(RetryClass::Io));
+    assert_eq!(RetryClass::from_error(&other), None);
+}
+
+#[test]
+fn retry_policy_should_retry() {
+    // Arrange
+    let policy = create_policy();
+    let signal = output_error(create_command_error(None, Some(9)), "encode", "transcode");
+    let exit = output_error(create_command_error(Some(1), None), "encode", "transcode");
+
+    // Act
+    // Assert
+    assert!(policy.should_retry(&signal, 1));
+    assert!(policy.should_retry(&signal, 2));
+    assert!(!policy.should_retry(&signal, 3));
+    assert!(!policy.should_retry(&exit, 1));
+}
+
+#[test]
+fn retry_policy_get_delay() {
+    // Arrange
+    let policy = create_policy();
+
+    // Act
+    // Assert
+    assert_eq!(policy.get_delay(1), Duration::from_secs(5));
+    assert_eq!(policy.get_delay(2), Duration::from_secs(10));
+    assert_eq!(policy.get_delay(3), Duration::from_secs(20));
+}
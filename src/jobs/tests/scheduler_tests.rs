@@ -0,0 +1,83 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Semaphore;
+use tokio::time::timeout;
+
+use crate::jobs::{JobCost, JobKind, Scheduler};
+use crate::options::RunnerOptions;
+
+fn create_scheduler(cpus: u16, transcode_limit: Option<u16>) -> (Scheduler, Arc<Semaphore>) {
+    let options = RunnerOptions {
+        cpus: Some(cpus),
+        io_limit: Some(1),
+        transcode_limit,
+        ..RunnerOptions::default()
+    };
+    let cpu = Arc::new(Semaphore::new(usize::from(cpus)));
+    (Scheduler::new(Arc::new(options), cpu.clone()), cpu)
+}
+
+#[tokio::test]
+async fn scheduler_acquire_weighted() {
+    // Arrange
+    let (scheduler, cpu) = create_scheduler(4, None);
+    let cost = JobCost { cpu: 2, io: 0 };
+
+    // Act
+    let first = scheduler.acquire(JobKind::Transcode, cost).await;
+    let second = scheduler.acquire(JobKind::Transcode, cost).await;
+    let third = timeout(
+        Duration::from_millis(50),
+        scheduler.acquire(JobKind::Transcode, cost),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(cpu.available_permits(), 0);
+    assert!(third.is_err());
+    drop(first);
+    drop(second);
+    assert_eq!(cpu.available_permits(), 4);
+}
+
+#[tokio::test]
+async fn scheduler_acquire_cost_above_total() {
+    // Arrange
+    let (scheduler, _) = create_scheduler(1, None);
+    let cost = JobCost { cpu: 2, io: 3 };
+
+    // Act
+    let permits = timeout(
+        Duration::from_millis(50),
+        scheduler.acquire(JobKind::Transcode, cost),
+    )
+    .await;
+
+    // Assert
+    assert!(permits.is_ok());
+}
+
+#[tokio::test]
+async fn scheduler_acquire_kind_limit() {
+    // Arrange
+    let (scheduler, _) = create_scheduler(4, Some(1));
+    let cost = JobCost { cpu: 1, io: 0 };
+
+    // Act
+    let _transcode = scheduler.acquire(JobKind::Transcode, cost).await;
+    let blocked = timeout(
+        Duration::from_millis(50),
+        scheduler.acquire(JobKind::Transcode, cost),
+    )
+    .await;
+    let spectrogram = timeout(
+        Duration::from_millis(50),
+        scheduler.acquire(JobKind::Spectrogram, cost),
+    )
+    .await;
+
+    // Assert
+    assert!(blocked.is_err());
+    assert!(spectrogram.is_ok());
+}
@@ -5,19 +5,23 @@ pub mod cli;
 mod db;
 mod dependencies;
 mod errors;
-mod eyed3;
 mod formats;
 mod fs;
 pub mod hosting;
+mod image_host;
 mod imdl;
 mod jobs;
+mod loudness;
+mod mp3;
 mod naming;
 mod options;
 mod queue;
+mod rip_log;
 mod source;
 mod spectrogram;
 #[cfg(test)]
 mod testing;
+mod torrent_client;
 mod transcode;
 mod upload;
 mod verify;
@@ -0,0 +1,134 @@
+use std::path::Path;
+
+use claxon::FlacReader;
+use ebur128::{EbuR128, Mode};
+use futures::future::join_all;
+use rogue_logging::Error;
+
+use crate::errors::{claxon_error, error};
+use crate::fs::FlacFile;
+use crate::jobs::Scheduler;
+use crate::loudness::TrackLoudness;
+
+/// Minimum absolute sample value considered full scale.
+const FULL_SCALE: f32 = 0.9999;
+
+/// Minimum number of consecutive full scale samples considered clipping.
+const MIN_CLIPPED_RUN: u64 = 3;
+
+/// Measure EBU R128 loudness, sample peak and clipping.
+pub struct LoudnessAnalyzer {
+    meter: EbuR128,
+    peak: f64,
+    clipped: u64,
+    runs: Vec<u64>,
+}
+
+impl LoudnessAnalyzer {
+    pub fn new(channels: u32, sample_rate: u32) -> Result<Self, Error> {
+        let meter = EbuR128::new(
+            channels,
+            sample_rate,
+            Mode::I | Mode::SAMPLE_PEAK | Mode::HISTOGRAM,
+        )
+        .map_err(|e| error("create loudness meter", e.to_string()))?;
+        Ok(Self {
+            meter,
+            peak: 0.0,
+            clipped: 0,
+            runs: vec![0; usize::try_from(channels).unwrap_or_default()],
+        })
+    }
+
+    /// Add samples with one slice per channel.
+    ///
+    /// Samples are scaled from `-1.0` to `1.0`.
+    pub fn add(&mut self, channels: &[&[f32]]) -> Result<(), Error> {
+        self.meter
+            .add_frames_planar_f32(channels)
+            .map_err(|e| error("measure loudness", e.to_string()))?;
+        for (samples, run) in channels.iter().zip(self.runs.iter_mut()) {
+            for sample in *samples {
+                if sample.abs() >= FULL_SCALE {
+                    *run += 1;
+                } else {
+                    if *run >= MIN_CLIPPED_RUN {
+                        self.clipped += *run;
+                    }
+                    *run = 0;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<TrackLoudness, Error> {
+        for run in &self.runs {
+            if *run >= MIN_CLIPPED_RUN {
+                self.clipped += *run;
+            }
+        }
+        let loudness = self
+            .meter
+            .loudness_global()
+            .map_err(|e| error("measure loudness", e.to_string()))?;
+        for channel in 0..self.meter.channels() {
+            let peak = self
+                .meter
+                .sample_peak(channel)
+                .map_err(|e| error("measure peak", e.to_string()))?;
+            self.peak = self.peak.max(peak);
+        }
+        Ok(TrackLoudness {
+            meter: self.meter,
+            loudness,
+            peak: self.peak,
+            clipped: self.clipped,
+        })
+    }
+}
+
+/// Measure the loudness of every channel of a FLAC file.
+#[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+pub fn analyze_flac(path: &Path) -> Result<TrackLoudness, Error> {
+    let mut reader = FlacReader::open(path).map_err(|e| claxon_error(e, "read FLAC"))?;
+    let info = reader.streaminfo();
+    let scale = 1.0 / f64::from(1_u32 << (info.bits_per_sample - 1));
+    let mut analyzer = LoudnessAnalyzer::new(info.channels, info.sample_rate)?;
+    let mut channels: Vec<Vec<f32>> = vec![Vec::new(); analyzer.runs.len()];
+    let mut blocks = reader.blocks();
+    let mut buffer = Vec::new();
+    while let Some(block) = blocks
+        .read_next_or_eof(buffer)
+        .map_err(|e| claxon_error(e, "decode FLAC"))?
+    {
+        for (index, samples) in channels.iter_mut().enumerate() {
+            samples.clear();
+            let channel = u32::try_from(index).unwrap_or_default();
+            samples.extend(
+                block
+                    .channel(channel)
+                    .iter()
+                    .map(|sample| (f64::from(*sample) * scale) as f32),
+            );
+        }
+        let slices: Vec<&[f32]> = channels.iter().map(Vec::as_slice).collect();
+        analyzer.add(&slices)?;
+        buffer = block.into_buffer();
+    }
+    analyzer.finish()
+}
+
+/// Measure the loudness of each FLAC file in parallel.
+///
+/// Each file is analyzed on a blocking thread, limited by the CPU budget of the [`Scheduler`].
+pub async fn analyze_flacs(
+    flacs: &[FlacFile],
+    scheduler: &Scheduler,
+) -> Result<Vec<TrackLoudness>, Error> {
+    let tasks = flacs.iter().map(|flac| {
+        let path = flac.path.clone();
+        scheduler.execute_blocking("analyze loudness", move || analyze_flac(&path))
+    });
+    join_all(tasks).await.into_iter().map(|result| result?).collect()
+}
@@ -0,0 +1,9 @@
+pub use loudness_analyzer::*;
+pub use replay_gain::*;
+pub use track_loudness::*;
+
+pub(crate) mod loudness_analyzer;
+pub(crate) mod replay_gain;
+#[cfg(test)]
+mod tests;
+pub(crate) mod track_loudness;
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+
+use ebur128::EbuR128;
+use lofty::config::{ParseOptions, WriteOptions};
+use lofty::flac::FlacFile as FlacTaggedFile;
+use lofty::id3::v2::{
+    ChannelInformation, ChannelType, Frame, Id3v2Tag, RelativeVolumeAdjustmentFrame,
+};
+use lofty::ogg::VorbisComments;
+use lofty::prelude::{AudioFile, ItemKey, TagExt};
+use lofty::tag::{Tag, TagType};
+use rogue_logging::Error;
+
+use crate::errors::{error, io_error};
+use crate::loudness::TrackLoudness;
+
+/// Largest gain that can be represented by an RVA2 frame.
+const MAX_GAIN: f64 = 63.99;
+
+/// `ReplayGain` 2.0 track and album gain.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReplayGain {
+    /// Track gain in dB
+    pub track_gain: f64,
+    /// Track sample peak from `0.0` to `1.0`
+    pub track_peak: f64,
+    /// Album gain in dB
+    pub album_gain: f64,
+    /// Album sample peak from `0.0` to `1.0`
+    pub album_peak: f64,
+}
+
+impl ReplayGain {
+    /// Calculate the [`ReplayGain`] of each track of an album.
+    ///
+    /// `reference` is the target loudness in LUFS.
+    pub fn from_tracks(tracks: &[TrackLoudness], reference: f64) -> Result<Vec<Self>, Error> {
+        let album_loudness = EbuR128::loudness_global_multiple(tracks.iter().map(|x| &x.meter))
+            .map_err(|e| error("measure album loudness", e.to_string()))?;
+        let album_gain = get_gain(album_loudness, reference);
+        let album_peak = tracks.iter().map(|x| x.peak).fold(0.0, f64::max);
+        Ok(tracks
+            .iter()
+            .map(|track| ReplayGain {
+                track_gain: get_gain(track.loudness, reference),
+                track_peak: track.peak,
+                album_gain,
+                album_peak,
+            })
+            .collect())
+    }
+
+    /// Get the `REPLAYGAIN_*` fields and values.
+    #[must_use]
+    pub fn get_fields(&self) -> Vec<(ItemKey, String)> {
+        vec![
+            (ItemKey::ReplayGainTrackGain, format_gain(self.track_gain)),
+            (ItemKey::ReplayGainTrackPeak, format_peak(self.track_peak)),
+            (ItemKey::ReplayGainAlbumGain, format_gain(self.album_gain)),
+            (ItemKey::ReplayGainAlbumPeak, format_peak(self.album_peak)),
+        ]
+    }
+
+    /// Add `REPLAYGAIN_*` Vorbis comments to a FLAC file.
+    pub fn save_to_flac(&self, path: &Path) -> Result<(), Error> {
+        let mut file = File::open(path).map_err(|e| io_error(e, "open FLAC"))?;
+        let mut flac = FlacTaggedFile::read_from(&mut file, ParseOptions::new())
+            .map_err(|e| error("read FLAC tags", e.to_string()))?;
+        drop(file);
+        if flac.vorbis_comments().is_none() {
+            flac.set_vorbis_comments(VorbisComments::default());
+        }
+        let comments = flac
+            .vorbis_comments_mut()
+            .expect("vorbis comments should be set");
+        for (key, value) in self.get_fields() {
+            let key = key
+                .map_key(TagType::VorbisComments, false)
+                .expect("ReplayGain key should map to a Vorbis comment");
+            comments.insert(key.to_owned(), value);
+        }
+        flac.save_to_path(path, WriteOptions::default())
+            .map_err(|e| error("write ReplayGain tags", e.to_string()))
+    }
+
+    /// Save `tags` to an MP3 file with `REPLAYGAIN_*` TXXX frames and RVA2 frames.
+    pub fn save_to_mp3(
+        &self,
+        mut tags: Tag,
+        path: &Path,
+        options: WriteOptions,
+    ) -> Result<(), Error> {
+        for (key, value) in self.get_fields() {
+            tags.insert_text(key, value);
+        }
+        let mut id3 = Id3v2Tag::from(tags);
+        id3.insert(create_rva2("track", self.track_gain));
+        id3.insert(create_rva2("album", self.album_gain));
+        id3.save_to_path(path, options)
+            .map_err(|e| error("write ReplayGain tags", e.to_string()))
+    }
+}
+
+fn get_gain(loudness: f64, reference: f64) -> f64 {
+    (reference - loudness).clamp(-MAX_GAIN, MAX_GAIN)
+}
+
+#[must_use]
+pub fn format_gain(gain: f64) -> String {
+    format!("{gain:.2} dB")
+}
+
+#[must_use]
+pub fn format_peak(peak: f64) -> String {
+    format!("{peak:.6}")
+}
+
+#[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+fn create_rva2(identification: &str, gain: f64) -> Frame<'static> {
+    let channel = ChannelInformation {
+        channel_type: ChannelType::MasterVolume,
+        volume_adjustment: (gain * 512.0).round() as i16,
+        bits_representing_peak: 0,
+        peak_volume: None,
+    };
+    let channels = HashMap::from([(ChannelType::MasterVolume, channel)]);
+    Frame::RelativeVolumeAdjustment(RelativeVolumeAdjustmentFrame::new(
+        identification.to_owned(),
+        channels,
+    ))
+}
@@ -0,0 +1,52 @@
+use std::f64::consts::PI;
+
+use crate::loudness::{LoudnessAnalyzer, TrackLoudness};
+
+const SAMPLE_RATE: u32 = 48000;
+
+/// Analyze a stereo 997 Hz sine wave clamped to full scale.
+#[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+pub(crate) fn analyze_sine(amplitude: f64, seconds: u32) -> TrackLoudness {
+    let samples: Vec<f32> = (0..SAMPLE_RATE * seconds)
+        .map(|index| {
+            let time = f64::from(index) / f64::from(SAMPLE_RATE);
+            (amplitude * (2.0 * PI * 997.0 * time).sin()).clamp(-1.0, 1.0) as f32
+        })
+        .collect();
+    let mut analyzer = LoudnessAnalyzer::new(2, SAMPLE_RATE).expect("analyzer should be created");
+    analyzer
+        .add(&[&samples, &samples])
+        .expect("samples should be added");
+    analyzer.finish().expect("loudness should be measured")
+}
+
+#[test]
+fn loudness_analyzer_reference_sine() {
+    // Arrange
+    let amplitude = 10.0_f64.powf(-23.0 / 20.0);
+
+    // Act
+    let track = analyze_sine(amplitude, 10);
+
+    // Assert
+    assert!(
+        (track.loudness + 23.0).abs() < 0.2,
+        "loudness was {} LUFS",
+        track.loudness
+    );
+    assert!((track.get_peak_db() + 23.0).abs() < 0.1);
+    assert_eq!(track.clipped, 0);
+}
+
+#[test]
+fn loudness_analyzer_clipping() {
+    // Arrange
+    let amplitude = 2.0;
+
+    // Act
+    let track = analyze_sine(amplitude, 1);
+
+    // Assert
+    assert!(track.clipped > 0);
+    assert!(track.get_peak_db().abs() < 0.01);
+}
@@ -0,0 +1,2 @@
+pub(crate) mod loudness_analyzer_tests;
+mod replay_gain_tests;
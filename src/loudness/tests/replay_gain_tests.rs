@@ -0,0 +1,37 @@
+use crate::loudness::tests::loudness_analyzer_tests::analyze_sine;
+use crate::loudness::{format_gain, format_peak, ReplayGain};
+
+#[test]
+fn replay_gain_from_tracks() {
+    // Arrange
+    let quiet = analyze_sine(10.0_f64.powf(-23.0 / 20.0), 10);
+    let loud = analyze_sine(10.0_f64.powf(-13.0 / 20.0), 10);
+    let loud_peak = loud.peak;
+
+    // Act
+    let gains = ReplayGain::from_tracks(&[quiet, loud], -18.0).expect("should calculate");
+
+    // Assert
+    let quiet = gains.first().expect("should have quiet track");
+    let loud = gains.get(1).expect("should have loud track");
+    assert!((quiet.track_gain - 5.0).abs() < 0.2);
+    assert!((loud.track_gain + 5.0).abs() < 0.2);
+    assert!((quiet.album_gain - loud.album_gain).abs() < 1e-9);
+    assert!(quiet.album_gain < 0.0 && quiet.album_gain > -5.0);
+    assert!((quiet.album_peak - loud_peak).abs() < 1e-9);
+}
+
+#[test]
+fn replay_gain_format() {
+    // Arrange
+    let gain = -6.534;
+    let peak = 0.988_831_2;
+
+    // Act
+    let gain = format_gain(gain);
+    let peak = format_peak(peak);
+
+    // Assert
+    assert_eq!(gain, "-6.53 dB");
+    assert_eq!(peak, "0.988831");
+}
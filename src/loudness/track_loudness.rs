@@ -0,0 +1,21 @@
+use ebur128::EbuR128;
+
+/// Loudness of a single track.
+pub struct TrackLoudness {
+    /// Meter retained so album loudness can be calculated
+    pub meter: EbuR128,
+    /// Integrated loudness in LUFS
+    pub loudness: f64,
+    /// Sample peak from `0.0` to `1.0`
+    pub peak: f64,
+    /// Number of samples in runs of consecutive full scale samples
+    pub clipped: u64,
+}
+
+impl TrackLoudness {
+    /// Sample peak in dBFS.
+    #[must_use]
+    pub fn get_peak_db(&self) -> f64 {
+        20.0 * self.peak.max(1e-15).log10()
+    }
+}
@@ -1,20 +1,35 @@
 use caesura::hosting::HostBuilder;
+use colored::Colorize;
+use log::warn;
 use std::process::ExitCode;
+use tokio::select;
+use tokio::signal::ctrl_c;
+
+/// Exit code of a process interrupted by `SIGINT`.
+const INTERRUPTED: u8 = 130;
 
 #[tokio::main]
 async fn main() -> ExitCode {
     let host = HostBuilder::new().build();
-    match host.execute().await {
-        Ok(status) => {
-            if status {
-                ExitCode::SUCCESS
-            } else {
+    select! {
+        result = host.execute() => match result {
+            Ok(status) => {
+                if status {
+                    ExitCode::SUCCESS
+                } else {
+                    ExitCode::FAILURE
+                }
+            }
+            Err(error) => {
+                error.log();
                 ExitCode::FAILURE
             }
-        }
-        Err(error) => {
-            error.log();
-            ExitCode::FAILURE
+        },
+        // Dropping the host aborts running jobs which kills their processes
+        // and removes their partial outputs.
+        _ = ctrl_c() => {
+            warn!("{} by Ctrl-C", "Interrupted".bold());
+            ExitCode::from(INTERRUPTED)
         }
     }
 }
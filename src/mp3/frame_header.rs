@@ -0,0 +1,162 @@
+use std::fmt::{Display, Formatter};
+
+/// Bitrates in kb/s of MPEG 1 Layer III indexed by the bitrate index.
+const MPEG1_BITRATES: [u32; 15] = [
+    0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+];
+
+/// Bitrates in kb/s of MPEG 2 and 2.5 Layer III indexed by the bitrate index.
+const MPEG2_BITRATES: [u32; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+
+/// Version of the MPEG audio standard.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MpegVersion {
+    V1,
+    V2,
+    V2_5,
+}
+
+/// Channel mode of an MPEG audio frame.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChannelMode {
+    Stereo,
+    JointStereo,
+    DualChannel,
+    Mono,
+}
+
+/// Header of an MPEG 1, 2 or 2.5 Layer III audio frame.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FrameHeader {
+    pub version: MpegVersion,
+    /// Bitrate in kb/s.
+    pub bitrate: u32,
+    /// Sample rate in Hz.
+    pub sample_rate: u32,
+    pub padding: bool,
+    /// Is the header followed by a 16 bit CRC?
+    pub protected: bool,
+    pub channel_mode: ChannelMode,
+}
+
+impl FrameHeader {
+    /// Parse the 4 byte header at the start of `bytes`.
+    ///
+    /// Returns `None` if `bytes` does not start with a valid Layer III header.
+    /// Free format bitrates are not supported.
+    #[must_use]
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        let [first, second, third, fourth] = *bytes.get(..4)? else {
+            return None;
+        };
+        if first != 0xFF || second & 0xE0 != 0xE0 {
+            return None;
+        }
+        let version = match (second >> 3) & 0b11 {
+            0b00 => MpegVersion::V2_5,
+            0b10 => MpegVersion::V2,
+            0b11 => MpegVersion::V1,
+            _ => return None,
+        };
+        let is_layer_3 = (second >> 1) & 0b11 == 0b01;
+        if !is_layer_3 {
+            return None;
+        }
+        let bitrates = if version == MpegVersion::V1 {
+            MPEG1_BITRATES
+        } else {
+            MPEG2_BITRATES
+        };
+        let bitrate = *bitrates.get(usize::from(third >> 4))?;
+        if bitrate == 0 {
+            return None;
+        }
+        let sample_rate = match ((third >> 2) & 0b11, version) {
+            (0, MpegVersion::V1) => 44100,
+            (1, MpegVersion::V1) => 48000,
+            (2, MpegVersion::V1) => 32000,
+            (0, MpegVersion::V2) => 22050,
+            (1, MpegVersion::V2) => 24000,
+            (2, MpegVersion::V2) => 16000,
+            (0, MpegVersion::V2_5) => 11025,
+            (1, MpegVersion::V2_5) => 12000,
+            (2, MpegVersion::V2_5) => 8000,
+            _ => return None,
+        };
+        let channel_mode = match fourth >> 6 {
+            0b00 => ChannelMode::Stereo,
+            0b01 => ChannelMode::JointStereo,
+            0b10 => ChannelMode::DualChannel,
+            _ => ChannelMode::Mono,
+        };
+        Some(Self {
+            version,
+            bitrate,
+            sample_rate,
+            padding: (third >> 1) & 1 == 1,
+            protected: second & 1 == 0,
+            channel_mode,
+        })
+    }
+
+    /// Get the length of the frame in bytes including the header.
+    #[must_use]
+    #[allow(clippy::integer_division)]
+    pub fn get_length(&self) -> usize {
+        let coefficient = if self.version == MpegVersion::V1 {
+            144
+        } else {
+            72
+        };
+        let length = coefficient * self.bitrate * 1000 / self.sample_rate + u32::from(self.padding);
+        usize::try_from(length).expect("frame length should fit in usize")
+    }
+
+    /// Get the number of samples per channel in the frame.
+    #[must_use]
+    pub fn get_samples(&self) -> u32 {
+        if self.version == MpegVersion::V1 {
+            1152
+        } else {
+            576
+        }
+    }
+
+    /// Get the offset of the Xing or Info header from the start of the frame.
+    #[must_use]
+    pub fn get_xing_offset(&self) -> usize {
+        let side_info = match (self.version, self.channel_mode) {
+            (MpegVersion::V1, ChannelMode::Mono) => 17,
+            (MpegVersion::V1, _) => 32,
+            (_, ChannelMode::Mono) => 9,
+            (_, _) => 17,
+        };
+        let crc = if self.protected { 2 } else { 0 };
+        4 + crc + side_info
+    }
+}
+
+impl Display for MpegVersion {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            MpegVersion::V1 => "MPEG1",
+            MpegVersion::V2 => "MPEG2",
+            MpegVersion::V2_5 => "MPEG2.5",
+        };
+        name.fmt(formatter)
+    }
+}
+
+impl Display for ChannelMode {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ChannelMode::Stereo => "Stereo",
+            ChannelMode::JointStereo => "Joint stereo",
+            ChannelMode::DualChannel => "Dual channel",
+            ChannelMode::Mono => "Mono",
+        };
+        name.fmt(formatter)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::mp3::read_u32;
+
+const ENCODER_LENGTH: usize = 9;
+const HEADER_LENGTH: usize = 36;
+
+/// LAME extension of the Xing or Info header.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LameHeader {
+    /// Encoder version, for example `LAME3.100`.
+    pub encoder: String,
+    pub revision: u8,
+    pub vbr_method: u8,
+    /// Lowpass filter in Hz.
+    pub lowpass: u32,
+    /// Bitrate in kb/s of ABR, the minimum bitrate of VBR or the bitrate of CBR.
+    pub bitrate: u8,
+    /// Samples of silence added at the start by the encoder.
+    pub encoder_delay: u16,
+    /// Samples of silence added at the end by the encoder.
+    pub padding: u16,
+    pub preset: u16,
+    /// Length of the audio in bytes.
+    pub music_length: u32,
+}
+
+impl LameHeader {
+    /// Parse the LAME extension at the start of `bytes`.
+    ///
+    /// Returns `None` if the encoder is not LAME.
+    #[must_use]
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        let bytes = bytes.get(..HEADER_LENGTH)?;
+        let encoder = bytes.get(..ENCODER_LENGTH)?;
+        if !encoder.starts_with(b"LAME") && !encoder.starts_with(b"L3.99") {
+            return None;
+        }
+        let encoder = String::from_utf8_lossy(encoder)
+            .trim_end_matches(|x: char| x == '\0' || x.is_whitespace())
+            .to_owned();
+        let revision_method = *bytes.get(9)?;
+        let &[first, second, third] = bytes.get(21..24)? else {
+            return None;
+        };
+        let delays = u32::from_be_bytes([0, first, second, third]);
+        let preset = u16::from_be_bytes([*bytes.get(26)?, *bytes.get(27)?]);
+        Some(Self {
+            encoder,
+            revision: revision_method >> 4,
+            vbr_method: revision_method & 0x0F,
+            lowpass: u32::from(*bytes.get(10)?) * 100,
+            bitrate: *bytes.get(20)?,
+            encoder_delay: u16::try_from(delays >> 12).ok()?,
+            padding: u16::try_from(delays & 0x0FFF).ok()?,
+            preset: preset & 0x07FF,
+            music_length: read_u32(bytes, 28)?,
+        })
+    }
+
+    #[must_use]
+    pub fn get_vbr_method_name(&self) -> &str {
+        match self.vbr_method {
+            1 => "Constant Bitrate",
+            2 => "Average Bitrate",
+            3 => "Variable Bitrate method1 (old/rh)",
+            4 => "Variable Bitrate method2 (mtrh)",
+            5 => "Variable Bitrate method3 (mt)",
+            6 => "Variable Bitrate method4",
+            8 => "Constant Bitrate (2 pass)",
+            9 => "Average Bitrate (2 pass)",
+            _ => "Unknown",
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::integer_division)]
+    pub fn get_preset_name(&self) -> Option<String> {
+        let name = match self.preset {
+            8..=320 => format!("{} kb/s", self.preset),
+            410..=500 if self.preset.is_multiple_of(10) => format!("V{}", (500 - self.preset) / 10),
+            1000 => "r3mix".to_owned(),
+            1001 => "standard".to_owned(),
+            1002 => "extreme".to_owned(),
+            1003 => "insane".to_owned(),
+            1004 => "standard/fast".to_owned(),
+            1005 => "extreme/fast".to_owned(),
+            1006 => "medium".to_owned(),
+            1007 => "medium/fast".to_owned(),
+            _ => return None,
+        };
+        Some(name)
+    }
+}
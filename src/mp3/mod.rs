@@ -0,0 +1,13 @@
+pub use frame_header::*;
+pub use lame_header::*;
+pub use mp3_details::*;
+pub use mp3_inspector::*;
+pub use xing_header::*;
+
+pub(crate) mod frame_header;
+pub(crate) mod lame_header;
+pub(crate) mod mp3_details;
+pub(crate) mod mp3_inspector;
+#[cfg(test)]
+mod tests;
+pub(crate) mod xing_header;
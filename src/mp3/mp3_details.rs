@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Write};
+use std::path::PathBuf;
+
+use crate::formats::TargetFormat;
+use crate::mp3::{FrameHeader, XingHeader};
+
+/// Details of an MP3 file reported by [`Mp3Inspector`](crate::mp3::Mp3Inspector).
+#[derive(Clone, Debug, Default)]
+pub struct Mp3Details {
+    pub path: PathBuf,
+    /// Header of the first audio frame.
+    pub header: Option<FrameHeader>,
+    /// Number of audio frames.
+    pub frames: u32,
+    /// Number of samples per channel.
+    pub samples: u64,
+    /// Number of audio frames of each bitrate in kb/s.
+    pub bitrates: BTreeMap<u32, u32>,
+    pub xing: Option<XingHeader>,
+    /// Version of the `ID3v2` tag, for example `2.4`.
+    pub id3_version: Option<String>,
+    /// Frame ids and values of the `ID3v2` tag.
+    pub tags: Vec<(String, String)>,
+    /// Descriptions of the embedded pictures.
+    pub pictures: Vec<String>,
+}
+
+impl Mp3Details {
+    /// Get the duration in seconds.
+    #[must_use]
+    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+    pub fn get_duration(&self) -> f64 {
+        let Some(header) = &self.header else {
+            return 0.0;
+        };
+        self.samples as f64 / f64::from(header.sample_rate)
+    }
+
+    /// Get the average bitrate in kb/s.
+    #[must_use]
+    #[allow(clippy::integer_division)]
+    pub fn get_average_bitrate(&self) -> u32 {
+        if self.frames == 0 {
+            return 0;
+        }
+        let total: u64 = self
+            .bitrates
+            .iter()
+            .map(|(bitrate, count)| u64::from(*bitrate) * u64::from(*count))
+            .sum();
+        u32::try_from(total / u64::from(self.frames)).unwrap_or(u32::MAX)
+    }
+
+    /// Check the audio frames are consistent with the `target` they were encoded for.
+    ///
+    /// Returns a description of each problem.
+    #[must_use]
+    pub fn verify(&self, target: TargetFormat) -> Vec<String> {
+        let mut issues = Vec::new();
+        if self.frames == 0 {
+            issues.push("no MPEG audio frames were found".to_owned());
+            return issues;
+        }
+        if target == TargetFormat::_320 {
+            let other = self
+                .bitrates
+                .iter()
+                .filter(|(bitrate, _)| **bitrate != 320)
+                .map(|(_, count)| count)
+                .sum::<u32>();
+            if other > 0 {
+                issues.push(format!("{other} frames are not 320 kb/s"));
+            }
+        }
+        issues
+    }
+}
+
+impl Display for Mp3Details {
+    #[allow(
+        clippy::absolute_paths,
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss,
+        clippy::integer_division
+    )]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+        let file_name = self
+            .path
+            .file_name()
+            .map(|x| x.to_string_lossy().to_string())
+            .unwrap_or_default();
+        writeln!(output, "{file_name}")?;
+        if let Some(header) = &self.header {
+            let seconds = self.get_duration().round() as u64;
+            writeln!(
+                output,
+                "Time: {}:{:02}\t{}, Layer III\t[ ~{} kb/s @ {} Hz - {} ]",
+                seconds / 60,
+                seconds % 60,
+                header.version,
+                self.get_average_bitrate(),
+                header.sample_rate,
+                header.channel_mode
+            )?;
+        }
+        if let Some(version) = &self.id3_version {
+            writeln!(output, "ID3 v{version}:")?;
+            for (key, value) in &self.tags {
+                writeln!(output, "  {key}: {value}")?;
+            }
+            for picture in &self.pictures {
+                writeln!(output, "  Picture: {picture}")?;
+            }
+        }
+        if let Some(xing) = &self.xing {
+            let name = if xing.is_vbr { "Xing" } else { "Info" };
+            writeln!(output, "{name} header:")?;
+            if let Some(frames) = xing.frames {
+                writeln!(output, "  {:<20}: {frames}", "Frames")?;
+            }
+            if let Some(bytes) = xing.bytes {
+                writeln!(output, "  {:<20}: {bytes}", "Bytes")?;
+            }
+            if let Some(quality) = xing.quality {
+                let setting = 100_u32.saturating_sub(quality);
+                writeln!(
+                    output,
+                    "  {:<20}: {quality} (-V {} -q {})",
+                    "Quality",
+                    setting / 10,
+                    setting % 10
+                )?;
+            }
+            if let Some(lame) = &xing.lame {
+                writeln!(output, "LAME header:")?;
+                writeln!(output, "  {:<20}: {}", "Encoder version", lame.encoder)?;
+                writeln!(output, "  {:<20}: {}", "LAME tag revision", lame.revision)?;
+                let method = lame.get_vbr_method_name();
+                writeln!(output, "  {:<20}: {method}", "VBR method")?;
+                writeln!(output, "  {:<20}: {} Hz", "Lowpass filter", lame.lowpass)?;
+                if let Some(preset) = lame.get_preset_name() {
+                    writeln!(output, "  {:<20}: {preset}", "Preset")?;
+                }
+                writeln!(output, "  {:<20}: {} kb/s", "Bitrate", lame.bitrate)?;
+                let delay = lame.encoder_delay;
+                writeln!(output, "  {:<20}: {delay} samples", "Encoder delay")?;
+                writeln!(output, "  {:<20}: {} samples", "Padding", lame.padding)?;
+                let length = lame.music_length;
+                writeln!(output, "  {:<20}: {length} bytes", "Music length")?;
+            }
+        }
+        writeln!(output, "Bitrate distribution:")?;
+        for (bitrate, count) in &self.bitrates {
+            let percent = f64::from(*count) * 100.0 / f64::from(self.frames.max(1));
+            writeln!(
+                output,
+                "  {bitrate:>3} kb/s: {count:>6} frames ({percent:>5.1}%)"
+            )?;
+        }
+        output.trim_end().fmt(formatter)
+    }
+}
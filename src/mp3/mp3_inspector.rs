@@ -0,0 +1,172 @@
+use std::fs::{read, File};
+use std::path::{Path, PathBuf};
+
+use lofty::config::ParseOptions;
+use lofty::file::AudioFile;
+use lofty::id3::v2::Id3v2Version;
+use lofty::mpeg::MpegFile;
+use lofty::tag::{ItemValue, Tag, TagType};
+use rogue_logging::Error;
+use tokio::task::spawn_blocking;
+
+use crate::errors::{error, io_error, task_error};
+use crate::formats::TargetFormat;
+use crate::fs::DirectoryReader;
+use crate::mp3::{FrameHeader, Mp3Details, XingHeader};
+
+const ID3V2_HEADER_LENGTH: usize = 10;
+const ID3V1_LENGTH: usize = 128;
+
+/// Inspect the frames and tags of MP3 files.
+pub struct Mp3Inspector;
+
+impl Mp3Inspector {
+    /// Inspect the frames and tags of an MP3 file.
+    pub fn execute(path: &Path) -> Result<Mp3Details, Error> {
+        let bytes = read(path).map_err(|e| io_error(e, "read MP3"))?;
+        let mut details = Self::inspect_frames(&bytes);
+        details.path = path.to_path_buf();
+        let mut file = File::open(path).map_err(|e| io_error(e, "open MP3"))?;
+        let mpeg = MpegFile::read_from(&mut file, ParseOptions::new())
+            .map_err(|e| error("read MP3 tags", e.to_string()))?;
+        if let Some(id3v2) = mpeg.id3v2() {
+            let version = match id3v2.original_version() {
+                Id3v2Version::V2 => "2.2",
+                Id3v2Version::V3 => "2.3",
+                Id3v2Version::V4 => "2.4",
+            };
+            details.id3_version = Some(version.to_owned());
+            let tag = Tag::from(id3v2.clone());
+            details.tags = tag
+                .items()
+                .map(|item| {
+                    let key = item
+                        .key()
+                        .map_key(TagType::Id3v2, true)
+                        .map_or_else(|| format!("{:?}", item.key()), ToOwned::to_owned);
+                    let value = match item.value() {
+                        ItemValue::Text(value) | ItemValue::Locator(value) => value.clone(),
+                        ItemValue::Binary(value) => format!("{} bytes", value.len()),
+                    };
+                    (key, value)
+                })
+                .collect();
+            details.pictures = tag
+                .pictures()
+                .iter()
+                .map(|picture| {
+                    let mime_type = picture
+                        .mime_type()
+                        .map(|x| x.as_str().to_owned())
+                        .unwrap_or_default();
+                    format!(
+                        "{:?} {mime_type} {} bytes",
+                        picture.pic_type(),
+                        picture.data().len()
+                    )
+                })
+                .collect();
+        }
+        Ok(details)
+    }
+
+    /// Inspect the audio frames of an MP3 file.
+    ///
+    /// `ID3v2` tags at the start and `ID3v1` tags at the end are skipped. If the first frame
+    /// contains a Xing or Info header it is not counted as an audio frame.
+    #[must_use]
+    pub fn inspect_frames(bytes: &[u8]) -> Mp3Details {
+        let mut details = Mp3Details::default();
+        let mut position = get_id3v2_length(bytes);
+        let end = get_audio_end(bytes);
+        let mut is_first = true;
+        while position + 4 <= end {
+            let Some(header) = bytes.get(position..end).and_then(FrameHeader::parse) else {
+                position += 1;
+                continue;
+            };
+            let length = header.get_length();
+            let Some(frame) = bytes.get(position..position + length) else {
+                position += 1;
+                continue;
+            };
+            position += length;
+            if is_first {
+                is_first = false;
+                if let Some(xing) = XingHeader::parse(frame, &header) {
+                    details.xing = Some(xing);
+                    continue;
+                }
+            }
+            details.header.get_or_insert(header);
+            details.frames += 1;
+            details.samples += u64::from(header.get_samples());
+            *details.bitrates.entry(header.bitrate).or_default() += 1;
+        }
+        details
+    }
+
+    /// Inspect and display each MP3 file in a directory.
+    pub async fn display_dir(dir: &Path) -> Result<String, Error> {
+        let dir = dir.to_path_buf();
+        spawn_blocking(move || {
+            let paths = DirectoryReader::new()
+                .with_extension("mp3")
+                .read(&dir)
+                .map_err(|e| io_error(e, "read transcode directory"))?;
+            let mut sections = Vec::new();
+            for path in paths {
+                sections.push(Self::execute(&path)?.to_string());
+            }
+            Ok(sections.join(&format!("\n{}\n", "-".repeat(79))))
+        })
+        .await
+        .map_err(|e| task_error(e, "inspect MP3 files"))?
+    }
+
+    /// Check the MP3 at `path` is consistent with the `target` it was encoded for.
+    pub fn verify(path: &PathBuf, target: TargetFormat) -> Result<(), Error> {
+        let bytes = read(path).map_err(|e| io_error(e, "read MP3"))?;
+        let issues = Self::inspect_frames(&bytes).verify(target);
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(error(
+                "verify MP3",
+                format!("{}: {}", path.display(), issues.join(", ")),
+            ))
+        }
+    }
+}
+
+/// Get the length of the `ID3v2` tag at the start of `bytes` including the header and footer.
+fn get_id3v2_length(bytes: &[u8]) -> usize {
+    let Some(header) = bytes.get(..ID3V2_HEADER_LENGTH) else {
+        return 0;
+    };
+    if !header.starts_with(b"ID3") {
+        return 0;
+    }
+    let size = header
+        .iter()
+        .skip(6)
+        .fold(0_usize, |size, byte| (size << 7) | usize::from(byte & 0x7F));
+    let footer = if header.get(5).is_some_and(|flags| flags & 0x10 != 0) {
+        ID3V2_HEADER_LENGTH
+    } else {
+        0
+    };
+    ID3V2_HEADER_LENGTH + size + footer
+}
+
+/// Get the position after the last audio frame, excluding an `ID3v1` tag.
+fn get_audio_end(bytes: &[u8]) -> usize {
+    let Some(start) = bytes.len().checked_sub(ID3V1_LENGTH) else {
+        return bytes.len();
+    };
+    if bytes.get(start..start + 3) == Some(b"TAG") {
+        start
+    } else {
+        bytes.len()
+    }
+}
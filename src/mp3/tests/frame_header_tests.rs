@@ -0,0 +1,57 @@
+use crate::mp3::{ChannelMode, FrameHeader, MpegVersion};
+
+#[test]
+fn frame_header_parse_320() {
+    // Arrange
+    let bytes = [0xFF, 0xFB, 0xE0, 0x44];
+
+    // Act
+    let header = FrameHeader::parse(&bytes).expect("should parse");
+
+    // Assert
+    assert_eq!(header.version, MpegVersion::V1);
+    assert_eq!(header.bitrate, 320);
+    assert_eq!(header.sample_rate, 44100);
+    assert_eq!(header.channel_mode, ChannelMode::JointStereo);
+    assert!(!header.padding);
+    assert!(!header.protected);
+    assert_eq!(header.get_length(), 1044);
+    assert_eq!(header.get_samples(), 1152);
+    assert_eq!(header.get_xing_offset(), 36);
+}
+
+#[test]
+fn frame_header_parse_padding() {
+    // Arrange
+    let bytes = [0xFF, 0xFB, 0x92, 0xC0];
+
+    // Act
+    let header = FrameHeader::parse(&bytes).expect("should parse");
+
+    // Assert
+    assert_eq!(header.bitrate, 128);
+    assert!(header.padding);
+    assert_eq!(header.channel_mode, ChannelMode::Mono);
+    assert_eq!(header.get_length(), 418);
+    assert_eq!(header.get_xing_offset(), 21);
+}
+
+#[test]
+fn frame_header_parse_invalid() {
+    // Arrange
+    let cases: [&[u8]; 5] = [
+        &[0xFF, 0xFB, 0xE0],
+        &[0x00, 0xFB, 0xE0, 0x44],
+        &[0xFF, 0xFD, 0xE0, 0x44],
+        &[0xFF, 0xFB, 0xF0, 0x44],
+        &[0xFF, 0xFB, 0xEC, 0x44],
+    ];
+
+    for bytes in cases {
+        // Act
+        let header = FrameHeader::parse(bytes);
+
+        // Assert
+        assert_eq!(header, None, "{bytes:02X?}");
+    }
+}
@@ -0,0 +1,3 @@
+mod frame_header_tests;
+pub(crate) mod mp3_inspector_tests;
+mod xing_header_tests;
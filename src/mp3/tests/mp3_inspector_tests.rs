@@ -0,0 +1,120 @@
+use crate::formats::TargetFormat;
+use crate::mp3::{FrameHeader, Mp3Details, Mp3Inspector};
+
+const HEADER_320: [u8; 4] = [0xFF, 0xFB, 0xE0, 0x44];
+const HEADER_128: [u8; 4] = [0xFF, 0xFB, 0x90, 0x44];
+
+/// Create an MPEG 1 320 kb/s audio frame of silence.
+pub(crate) fn create_frame() -> Vec<u8> {
+    create_frame_with_header(HEADER_320)
+}
+
+fn create_frame_with_header(header: [u8; 4]) -> Vec<u8> {
+    let length = FrameHeader::parse(&header)
+        .expect("should parse")
+        .get_length();
+    let mut frame = vec![0; length];
+    frame.splice(..4, header);
+    frame
+}
+
+/// Create an MPEG 1 320 kb/s frame containing a Xing and LAME header as written by `lame -V 2`.
+pub(crate) fn create_xing_frame(frames: u32) -> Vec<u8> {
+    let mut frame = create_frame();
+    let mut xing = Vec::new();
+    xing.extend_from_slice(b"Xing");
+    xing.extend_from_slice(&0x0F_u32.to_be_bytes());
+    xing.extend_from_slice(&frames.to_be_bytes());
+    xing.extend_from_slice(&(frames * 1044).to_be_bytes());
+    xing.extend_from_slice(&[0; 100]);
+    xing.extend_from_slice(&78_u32.to_be_bytes());
+    let mut lame = vec![0; 36];
+    lame.splice(..9, *b"LAME3.100");
+    if let Some(revision_method) = lame.get_mut(9) {
+        *revision_method = 0x04;
+    }
+    if let Some(lowpass) = lame.get_mut(10) {
+        *lowpass = 195;
+    }
+    let delays: u32 = (0x240 << 12) | 0x4EC;
+    lame.splice(21..24, delays.to_be_bytes().into_iter().skip(1));
+    lame.splice(26..28, 480_u16.to_be_bytes());
+    lame.splice(28..32, (frames * 1044).to_be_bytes());
+    xing.extend(lame);
+    frame.splice(36..36 + xing.len(), xing);
+    frame
+}
+
+#[test]
+fn mp3_inspector_inspect_frames() {
+    // Arrange
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(b"ID3\x04\x00\x00\x00\x00\x01\x00");
+    bytes.extend_from_slice(&[0xFF; 128]);
+    bytes.extend(create_xing_frame(3));
+    bytes.extend(create_frame());
+    bytes.extend_from_slice(&[0x00, 0xFF, 0x12]);
+    bytes.extend(create_frame());
+    bytes.extend(create_frame_with_header(HEADER_128));
+    bytes.extend_from_slice(b"TAG");
+    bytes.extend_from_slice(&[0xFF; 125]);
+
+    // Act
+    let details = Mp3Inspector::inspect_frames(&bytes);
+
+    // Assert
+    assert_eq!(details.frames, 3);
+    assert_eq!(details.samples, 3 * 1152);
+    assert_eq!(details.bitrates.get(&320), Some(&2));
+    assert_eq!(details.bitrates.get(&128), Some(&1));
+    assert_eq!(details.get_average_bitrate(), 256);
+    let xing = details.xing.as_ref().expect("should have Xing header");
+    assert_eq!(xing.frames, Some(3));
+    assert!(xing.lame.is_some());
+    assert_eq!(
+        details.verify(TargetFormat::_320),
+        vec!["1 frames are not 320 kb/s".to_owned()]
+    );
+    assert!(details.verify(TargetFormat::V0).is_empty());
+}
+
+#[test]
+fn mp3_inspector_inspect_frames_empty() {
+    // Arrange
+    let bytes = b"ID3\x04\x00\x00\x00\x00\x00\x00not an mp3".to_vec();
+
+    // Act
+    let details = Mp3Inspector::inspect_frames(&bytes);
+
+    // Assert
+    assert_eq!(details.frames, 0);
+    assert_eq!(details.header, None);
+    assert_eq!(details.verify(TargetFormat::V0).len(), 1);
+}
+
+#[test]
+fn mp3_details_display() {
+    // Arrange
+    let mut bytes = create_xing_frame(2);
+    bytes.extend(create_frame());
+    bytes.extend(create_frame());
+    let details = Mp3Details {
+        id3_version: Some("2.4".to_owned()),
+        tags: vec![("TIT2".to_owned(), "Title".to_owned())],
+        ..Mp3Inspector::inspect_frames(&bytes)
+    };
+
+    // Act
+    let output = details.to_string();
+
+    // Assert
+    assert!(
+        output.contains("Time: 0:00\tMPEG1, Layer III\t[ ~320 kb/s @ 44100 Hz - Joint stereo ]")
+    );
+    assert!(output.contains("ID3 v2.4:\n  TIT2: Title"));
+    assert!(output.contains("Xing header:"));
+    assert!(output.contains("Quality             : 78 (-V 2 -q 2)"));
+    assert!(output.contains("Encoder version     : LAME3.100"));
+    assert!(output.contains("Preset              : V2"));
+    assert!(output.contains("320 kb/s:      2 frames (100.0%)"));
+}
@@ -0,0 +1,44 @@
+use crate::mp3::tests::mp3_inspector_tests::{create_frame, create_xing_frame};
+use crate::mp3::{FrameHeader, XingHeader};
+
+#[test]
+fn xing_header_parse() {
+    // Arrange
+    let frame = create_xing_frame(1000);
+    let header = FrameHeader::parse(&frame).expect("should parse");
+
+    // Act
+    let xing = XingHeader::parse(&frame, &header).expect("should parse");
+
+    // Assert
+    assert!(xing.is_vbr);
+    assert_eq!(xing.frames, Some(1000));
+    assert_eq!(xing.bytes, Some(1_044_000));
+    assert_eq!(xing.quality, Some(78));
+    let lame = xing.lame.expect("should have LAME header");
+    assert_eq!(lame.encoder, "LAME3.100");
+    assert_eq!(lame.revision, 0);
+    assert_eq!(lame.vbr_method, 4);
+    assert_eq!(
+        lame.get_vbr_method_name(),
+        "Variable Bitrate method2 (mtrh)"
+    );
+    assert_eq!(lame.lowpass, 19500);
+    assert_eq!(lame.encoder_delay, 576);
+    assert_eq!(lame.padding, 1260);
+    assert_eq!(lame.get_preset_name(), Some("V2".to_owned()));
+    assert_eq!(lame.music_length, 1_044_000);
+}
+
+#[test]
+fn xing_header_parse_audio_frame() {
+    // Arrange
+    let frame = create_frame();
+    let header = FrameHeader::parse(&frame).expect("should parse");
+
+    // Act
+    let xing = XingHeader::parse(&frame, &header);
+
+    // Assert
+    assert_eq!(xing, None);
+}
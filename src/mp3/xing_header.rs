@@ -0,0 +1,72 @@
+use crate::mp3::{FrameHeader, LameHeader};
+
+const FRAMES_FLAG: u32 = 0x1;
+const BYTES_FLAG: u32 = 0x2;
+const TOC_FLAG: u32 = 0x4;
+const QUALITY_FLAG: u32 = 0x8;
+const TOC_LENGTH: usize = 100;
+
+/// Xing or Info header in the first frame of an MP3.
+///
+/// The `Xing` identifier is written for VBR and ABR files and `Info` for CBR files.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct XingHeader {
+    /// Was the header identified by `Xing` rather than `Info`?
+    pub is_vbr: bool,
+    /// Number of audio frames.
+    pub frames: Option<u32>,
+    /// Number of bytes of audio.
+    pub bytes: Option<u32>,
+    /// Quality indicator from 0 (best) to 100 (worst).
+    pub quality: Option<u32>,
+    pub lame: Option<LameHeader>,
+}
+
+impl XingHeader {
+    /// Parse the Xing or Info header of a `frame`.
+    ///
+    /// Returns `None` if the frame does not contain a header.
+    #[must_use]
+    pub fn parse(frame: &[u8], header: &FrameHeader) -> Option<Self> {
+        let start = header.get_xing_offset();
+        let is_vbr = match frame.get(start..start + 4)? {
+            b"Xing" => true,
+            b"Info" => false,
+            _ => return None,
+        };
+        let flags = read_u32(frame, start + 4)?;
+        let mut position = start + 8;
+        let mut read_optional = |flag: u32| -> Option<u32> {
+            if flags & flag == 0 {
+                return None;
+            }
+            let value = read_u32(frame, position);
+            position += 4;
+            value
+        };
+        let frames = read_optional(FRAMES_FLAG);
+        let bytes = read_optional(BYTES_FLAG);
+        if flags & TOC_FLAG != 0 {
+            position += TOC_LENGTH;
+        }
+        let quality = if flags & QUALITY_FLAG == 0 {
+            None
+        } else {
+            let quality = read_u32(frame, position);
+            position += 4;
+            quality
+        };
+        Some(Self {
+            is_vbr,
+            frames,
+            bytes,
+            quality,
+            lame: frame.get(position..).and_then(LameHeader::parse),
+        })
+    }
+}
+
+pub(crate) fn read_u32(bytes: &[u8], position: usize) -> Option<u32> {
+    let bytes: [u8; 4] = bytes.get(position..position + 4)?.try_into().ok()?;
+    Some(u32::from_be_bytes(bytes))
+}
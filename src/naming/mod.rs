@@ -1,17 +1,15 @@
 pub use humanize::*;
+pub use name_templates::*;
 pub use sanitizer::*;
 pub use shortener::*;
 pub use source_name::*;
-pub use spectrogram_name::*;
 pub use track_name::*;
-pub use transcode_name::*;
 
 pub(crate) mod humanize;
+pub(crate) mod name_templates;
 pub(crate) mod sanitizer;
 pub(crate) mod shortener;
 pub(crate) mod source_name;
-pub(crate) mod spectrogram_name;
 #[cfg(test)]
 mod tests;
 pub(crate) mod track_name;
-pub(crate) mod transcode_name;
@@ -0,0 +1,221 @@
+use crate::formats::TargetFormat;
+use crate::formats::TargetFormat::Flac;
+use crate::naming::Sanitizer;
+use crate::options::NamingOptions;
+use crate::source::Metadata;
+use log::warn;
+use minijinja::{Environment, UndefinedBehavior};
+use serde::Serialize;
+
+/// Default template of the source name.
+pub const DEFAULT_SOURCE_NAME_TEMPLATE: &str = "{{ artist }} - {{ album }}\
+{% if remaster_title %} ({{ remaster_title }}){% endif %} [{{ year }}]";
+
+/// Default template of the transcode directory name.
+pub const DEFAULT_TRANSCODE_NAME_TEMPLATE: &str = "{{ source_name }} [{{ media }} {{ target }}]";
+
+/// Default template of the spectrogram directory name.
+pub const DEFAULT_SPECTROGRAM_NAME_TEMPLATE: &str = "{{ source_name }} [{{ media }} SPECTROGRAMS]";
+
+/// Default template of the torrent file name, without the extension.
+pub const DEFAULT_TORRENT_NAME_TEMPLATE: &str = "{{ transcode_name }}";
+
+/// Templates for the names of sources, transcodes, spectrograms and torrents.
+///
+/// Templates use the [`minijinja`] syntax and have access to the [`Metadata`] fields `artist`,
+/// `album`, `remaster_title`, `year`, `original_year`, `media`, `label`, `catalogue_number` and
+/// `release_type` as well as:
+/// - `format`: `FLAC` or `MP3`
+/// - `bitrate`: `Lossless`, `320` or `V0 (VBR)`
+/// - `target`: `FLAC`, `320` or `V0`
+/// - `source_name`: the rendered source name
+/// - `transcode_name`: the rendered transcode name
+///
+/// Target specific values are empty for the source and spectrogram names.
+#[derive(Clone, Debug)]
+pub struct NameTemplates {
+    source: String,
+    transcode: String,
+    spectrogram: String,
+    torrent: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+struct NameContext {
+    artist: String,
+    album: String,
+    remaster_title: String,
+    year: u16,
+    original_year: u16,
+    media: String,
+    label: String,
+    catalogue_number: String,
+    release_type: String,
+    format: String,
+    bitrate: String,
+    target: String,
+    source_name: String,
+    transcode_name: String,
+}
+
+impl Default for NameTemplates {
+    fn default() -> Self {
+        Self {
+            source: DEFAULT_SOURCE_NAME_TEMPLATE.to_owned(),
+            transcode: DEFAULT_TRANSCODE_NAME_TEMPLATE.to_owned(),
+            spectrogram: DEFAULT_SPECTROGRAM_NAME_TEMPLATE.to_owned(),
+            torrent: DEFAULT_TORRENT_NAME_TEMPLATE.to_owned(),
+        }
+    }
+}
+
+impl NameTemplates {
+    #[must_use]
+    pub fn new(options: &NamingOptions) -> Self {
+        Self {
+            source: options
+                .source_name_template
+                .clone()
+                .expect("source_name_template should be set"),
+            transcode: options
+                .transcode_name_template
+                .clone()
+                .expect("transcode_name_template should be set"),
+            spectrogram: options
+                .spectrogram_name_template
+                .clone()
+                .expect("spectrogram_name_template should be set"),
+            torrent: options
+                .torrent_name_template
+                .clone()
+                .expect("torrent_name_template should be set"),
+        }
+    }
+
+    #[must_use]
+    pub fn get_source_name(&self, metadata: &Metadata) -> String {
+        let context = NameContext::new(metadata, None);
+        render_or_default(&self.source, DEFAULT_SOURCE_NAME_TEMPLATE, &context)
+    }
+
+    #[must_use]
+    pub fn get_transcode_name(&self, metadata: &Metadata, target: TargetFormat) -> String {
+        let mut context = NameContext::new(metadata, Some(target));
+        context.source_name = self.get_source_name(metadata);
+        render_or_default(&self.transcode, DEFAULT_TRANSCODE_NAME_TEMPLATE, &context)
+    }
+
+    #[must_use]
+    pub fn get_spectrogram_name(&self, metadata: &Metadata) -> String {
+        let mut context = NameContext::new(metadata, None);
+        context.source_name = self.get_source_name(metadata);
+        render_or_default(
+            &self.spectrogram,
+            DEFAULT_SPECTROGRAM_NAME_TEMPLATE,
+            &context,
+        )
+    }
+
+    /// Get the name of the torrent file without the extension.
+    ///
+    /// `transcode_name` is the name of the transcode directory which may have been shortened.
+    #[must_use]
+    pub fn get_torrent_name(
+        &self,
+        metadata: &Metadata,
+        target: TargetFormat,
+        transcode_name: &str,
+    ) -> String {
+        let mut context = NameContext::new(metadata, Some(target));
+        context.source_name = self.get_source_name(metadata);
+        transcode_name.clone_into(&mut context.transcode_name);
+        render_or_default(&self.torrent, DEFAULT_TORRENT_NAME_TEMPLATE, &context)
+    }
+
+    /// Check that a template can be rendered to a valid name.
+    ///
+    /// The template is rendered with both example and empty [`Metadata`] so a template that
+    /// relies only on optional fields is rejected.
+    pub fn validate(template: &str) -> Result<(), String> {
+        let example = NameContext {
+            artist: "Artist".to_owned(),
+            album: "Album".to_owned(),
+            remaster_title: "Remaster".to_owned(),
+            year: 2000,
+            original_year: 1990,
+            media: "CD".to_owned(),
+            label: "Label".to_owned(),
+            catalogue_number: "CAT-001".to_owned(),
+            release_type: "Album".to_owned(),
+            format: "FLAC".to_owned(),
+            bitrate: "Lossless".to_owned(),
+            target: "FLAC".to_owned(),
+            source_name: "Artist - Album (Remaster) [2000]".to_owned(),
+            transcode_name: "Artist - Album (Remaster) [2000] [CD FLAC]".to_owned(),
+        };
+        let empty = NameContext {
+            format: example.format.clone(),
+            bitrate: example.bitrate.clone(),
+            target: example.target.clone(),
+            source_name: example.source_name.clone(),
+            transcode_name: example.transcode_name.clone(),
+            ..NameContext::default()
+        };
+        for context in [example, empty] {
+            let name = render(template, &context).map_err(|e| e.to_string())?;
+            if !is_valid_name(&name) {
+                return Err(format!("renders an invalid name: \"{name}\""));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl NameContext {
+    fn new(metadata: &Metadata, target: Option<TargetFormat>) -> Self {
+        let mut context = Self {
+            artist: metadata.artist.clone(),
+            album: metadata.album.clone(),
+            remaster_title: metadata.remaster_title.clone(),
+            year: metadata.year,
+            original_year: metadata.original_year,
+            media: metadata.media.clone(),
+            label: metadata.label.clone(),
+            catalogue_number: metadata.catalogue_number.clone(),
+            release_type: metadata.release_type.to_string(),
+            ..Self::default()
+        };
+        if let Some(target) = target {
+            let format = if target == Flac { "FLAC" } else { "MP3" };
+            format.clone_into(&mut context.format);
+            target.get_bitrate().clone_into(&mut context.bitrate);
+            target.get_name().clone_into(&mut context.target);
+        }
+        context
+    }
+}
+
+fn render(template: &str, context: &NameContext) -> Result<String, minijinja::Error> {
+    let mut environment = Environment::new();
+    environment.set_undefined_behavior(UndefinedBehavior::Strict);
+    let name = environment.render_str(template, context)?;
+    Ok(Sanitizer::execute(name).trim().to_owned())
+}
+
+fn render_or_default(template: &str, default: &str, context: &NameContext) -> String {
+    match render(template, context) {
+        Ok(name) if is_valid_name(&name) => name,
+        Ok(name) => {
+            warn!("Name template rendered an invalid name so the default was used: \"{name}\"");
+            render(default, context).expect("default template should render")
+        }
+        Err(error) => {
+            warn!("Name template could not be rendered so the default was used: {error}");
+            render(default, context).expect("default template should render")
+        }
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name != "." && name != ".." && !name.contains(['/', '\\'])
+}
@@ -6,6 +6,8 @@ use log::info;
 use regex::Regex;
 use rogue_logging::Colors;
 
+const ELLIPSIS: char = '…';
+
 pub struct Shortener;
 
 impl Shortener {
@@ -22,6 +24,25 @@ impl Shortener {
         }
     }
 
+    /// Truncate `input` so it is at most `max_length` bytes, ending with an ellipsis.
+    ///
+    /// Trailing whitespace and punctuation before the ellipsis is removed.
+    ///
+    /// Returns `None` if `input` is already short enough.
+    #[must_use]
+    pub fn truncate(input: &str, max_length: usize) -> Option<String> {
+        if input.len() <= max_length {
+            return None;
+        }
+        let mut end = max_length.saturating_sub(ELLIPSIS.len_utf8());
+        while !input.is_char_boundary(end) {
+            end -= 1;
+        }
+        let truncated = input[..end]
+            .trim_end_matches(|x: char| x.is_whitespace() || x == '-' || x == '.' || x == ',');
+        Some(format!("{truncated}{ELLIPSIS}"))
+    }
+
     pub fn suggest_track_name(flac: &FlacFile) {
         if let Some(file_name) = TrackName::get(flac) {
             let difference = flac.file_name.len() - file_name.len();
@@ -1,3 +1,4 @@
 mod humanize_tests;
+mod name_templates_tests;
 mod sanitizer_tests;
 mod shortener_tests;
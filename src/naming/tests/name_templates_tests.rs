@@ -0,0 +1,137 @@
+use crate::formats::TargetFormat;
+use crate::naming::NameTemplates;
+use crate::options::{NamingOptions, Options};
+use crate::source::Metadata;
+
+#[test]
+fn get_transcode_name_default() {
+    // Arrange
+    let templates = NameTemplates::default();
+
+    // Act
+    let with_remaster = templates.get_transcode_name(&example(), TargetFormat::V0);
+    let without_remaster = templates.get_transcode_name(
+        &Metadata {
+            remaster_title: String::new(),
+            ..example()
+        },
+        TargetFormat::Flac,
+    );
+
+    // Assert
+    assert_eq!(
+        with_remaster,
+        "Artist - Album (Deluxe Edition) [2012] [WEB V0]"
+    );
+    assert_eq!(without_remaster, "Artist - Album [2012] [WEB FLAC]");
+}
+
+#[test]
+fn get_transcode_name_custom() {
+    // Arrange
+    let options = NamingOptions {
+        transcode_name_template: Some(
+            "{{ artist }} - {{ album }} {{ catalogue_number }} [{{ format }} {{ bitrate }}]"
+                .to_owned(),
+        ),
+        ..default_options()
+    };
+    let templates = NameTemplates::new(&options);
+
+    // Act
+    let result = templates.get_transcode_name(&example(), TargetFormat::_320);
+
+    // Assert
+    assert_eq!(result, "Artist - Album CAT-001 [MP3 320]");
+}
+
+#[test]
+fn get_torrent_name_custom() {
+    // Arrange
+    let options = NamingOptions {
+        torrent_name_template: Some("{{ transcode_name }} ({{ label }})".to_owned()),
+        ..default_options()
+    };
+    let templates = NameTemplates::new(&options);
+
+    // Act
+    let result = templates.get_torrent_name(&example(), TargetFormat::Flac, "Shortened");
+
+    // Assert
+    assert_eq!(result, "Shortened (Label)");
+}
+
+#[test]
+fn get_source_name_sanitized() {
+    // Arrange
+    let options = NamingOptions {
+        source_name_template: Some("{{ artist }}: {{ album }} / {{ year }}".to_owned()),
+        ..default_options()
+    };
+    let templates = NameTemplates::new(&options);
+
+    // Act
+    let result = templates.get_source_name(&example());
+
+    // Assert
+    assert_eq!(result, "Artist Album - 2012");
+}
+
+#[test]
+fn validate_default() {
+    // Arrange
+    let options = default_options();
+
+    // Act
+    let results = [
+        options.source_name_template,
+        options.transcode_name_template,
+        options.spectrogram_name_template,
+        options.torrent_name_template,
+    ]
+    .map(|template| NameTemplates::validate(&template.expect("template should be set")));
+
+    // Assert
+    for result in results {
+        assert_eq!(result, Ok(()));
+    }
+}
+
+#[test]
+fn validate_invalid() {
+    // Arrange
+    let templates = [
+        "{{ artist",
+        "{{ unknown }}",
+        "{{ label }}",
+        "{% if false %}{{ artist }}{% endif %}",
+        "..",
+    ];
+
+    // Act
+    let results = templates.map(NameTemplates::validate);
+
+    // Assert
+    for result in results {
+        assert!(result.is_err());
+    }
+}
+
+fn default_options() -> NamingOptions {
+    let mut options = NamingOptions::default();
+    options.apply_defaults();
+    options
+}
+
+fn example() -> Metadata {
+    Metadata {
+        artist: "Artist".to_owned(),
+        album: "Album".to_owned(),
+        remaster_title: "Deluxe Edition".to_owned(),
+        year: 2012,
+        media: "WEB".to_owned(),
+        label: "Label".to_owned(),
+        catalogue_number: "CAT-001".to_owned(),
+        ..Metadata::default()
+    }
+}
@@ -10,6 +10,7 @@ fn get_shortened() {
         remaster_title: "Remaster Title".to_owned(),
         year: 1234,
         media: "Vinyl".to_owned(),
+        ..Metadata::default()
     };
 
     // Act
@@ -20,3 +21,43 @@ fn get_shortened() {
     let name_after = SourceName::get(&result.expect("Should have value"));
     assert!(name_after.len() < name_before.len());
 }
+
+#[test]
+fn truncate_short() {
+    // Arrange
+    let input = "01 Title";
+
+    // Act
+    let result = Shortener::truncate(input, 20);
+
+    // Assert
+    assert_eq!(result, None);
+}
+
+#[test]
+fn truncate_long() {
+    // Arrange
+    let input = "01 This is a Very Long Title - With a Subtitle";
+
+    // Act
+    let result = Shortener::truncate(input, 32);
+
+    // Assert
+    let result = result.expect("Should have value");
+    assert_eq!(result, "01 This is a Very Long Title…");
+    assert!(result.len() <= 32);
+}
+
+#[test]
+fn truncate_multibyte() {
+    // Arrange
+    let input = "01 Ééééééééééééééééé";
+
+    // Act
+    let result = Shortener::truncate(input, 12);
+
+    // Assert
+    let result = result.expect("Should have value");
+    assert_eq!(result, "01 Ééé…");
+    assert!(result.len() <= 12);
+}
@@ -57,6 +57,15 @@ pub struct BatchOptions {
     /// Default: `null`
     #[arg(long)]
     pub wait_before_upload: Option<String>,
+
+    /// Number of sources to verify ahead of the source being transcoded.
+    ///
+    /// Verification, including API calls and hash checks, of the next sources
+    /// overlaps with the current transcode.
+    ///
+    /// Default: `1`
+    #[arg(long)]
+    pub verify_ahead: Option<usize>,
 }
 
 #[injectable]
@@ -109,6 +118,9 @@ impl Options for BatchOptions {
             self.wait_before_upload
                 .clone_from(&alternative.wait_before_upload);
         }
+        if self.verify_ahead.is_none() {
+            self.verify_ahead = alternative.verify_ahead;
+        }
     }
 
     fn apply_defaults(&mut self) {
@@ -130,6 +142,9 @@ impl Options for BatchOptions {
         if self.no_limit.is_none() {
             self.no_limit = Some(false);
         }
+        if self.verify_ahead.is_none() {
+            self.verify_ahead = Some(1);
+        }
     }
 
     #[must_use]
@@ -143,6 +158,9 @@ impl Options for BatchOptions {
                 ));
             }
         }
+        if self.verify_ahead == Some(0) {
+            errors.push(OptionRule::IsZero("Verify Ahead".to_owned()));
+        }
         if self.upload == Some(true) && self.transcode != Some(true) {
             errors.push(OptionRule::Dependent(
                 "Upload".to_owned(),
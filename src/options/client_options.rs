@@ -0,0 +1,178 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use clap::Args;
+use di::{injectable, Ref};
+use serde::{Deserialize, Serialize};
+
+use crate::cli::ArgumentsParser;
+use crate::cli::CommandArguments::*;
+use crate::cli::QueueCommandArguments::Add;
+use crate::options::{OptionRule, Options, OptionsProvider, UrlInvalidSuffix, UrlNotHttp};
+use crate::torrent_client::TorrentClientKind;
+
+/// Options for adding uploaded transcodes to a torrent client
+#[derive(Args, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClientOptions {
+    /// Torrent client to add uploaded transcodes to.
+    ///
+    /// Once uploaded the `.torrent` file is added to the client with the
+    /// transcode directory as the save path.
+    ///
+    /// Default: Not set
+    #[arg(long, value_enum)]
+    pub client: Option<TorrentClientKind>,
+
+    /// URL of the torrent client web interface.
+    ///
+    /// Examples: `http://localhost:8080`, `http://localhost:9091`, `http://localhost:8112`
+    ///
+    /// Default: Not set
+    #[arg(long)]
+    pub client_url: Option<String>,
+
+    /// Username for the torrent client.
+    ///
+    /// Deluge only requires a password so this is ignored.
+    ///
+    /// Default: Not set
+    #[arg(long)]
+    pub client_username: Option<String>,
+
+    /// Password for the torrent client.
+    ///
+    /// Default: Not set
+    #[arg(long)]
+    pub client_password: Option<String>,
+
+    /// Category or label to assign to torrents added to the client.
+    ///
+    /// Default: Not set
+    #[arg(long)]
+    pub client_category: Option<String>,
+
+    /// Maximum duration to wait for the client to report a 100% hash check.
+    ///
+    /// The duration is a string that can be parsed such as `500ms`, `5m`, `1h30m15s`.
+    ///
+    /// Default: `5m`
+    #[arg(long)]
+    pub client_timeout: Option<String>,
+}
+
+#[injectable]
+impl ClientOptions {
+    fn new(provider: Ref<OptionsProvider>) -> Self {
+        provider.get()
+    }
+
+    #[must_use]
+    pub fn get_timeout(&self) -> Option<Duration> {
+        let timeout = self.client_timeout.clone()?;
+        humantime::parse_duration(timeout.as_str()).ok()
+    }
+}
+
+impl Options for ClientOptions {
+    fn get_name() -> String {
+        "Client Options".to_owned()
+    }
+
+    fn merge(&mut self, alternative: &Self) {
+        if self.client.is_none() {
+            self.client = alternative.client;
+        }
+        if self.client_url.is_none() {
+            self.client_url.clone_from(&alternative.client_url);
+        }
+        if self.client_username.is_none() {
+            self.client_username
+                .clone_from(&alternative.client_username);
+        }
+        if self.client_password.is_none() {
+            self.client_password
+                .clone_from(&alternative.client_password);
+        }
+        if self.client_category.is_none() {
+            self.client_category
+                .clone_from(&alternative.client_category);
+        }
+        if self.client_timeout.is_none() {
+            self.client_timeout.clone_from(&alternative.client_timeout);
+        }
+    }
+
+    fn apply_defaults(&mut self) {
+        if self.client_timeout.is_none() {
+            self.client_timeout = Some("5m".to_owned());
+        }
+    }
+
+    fn validate(&self) -> bool {
+        let mut errors: Vec<OptionRule> = Vec::new();
+        if self.client.is_some() {
+            if let Some(client_url) = &self.client_url {
+                if !client_url.starts_with("https://") && !client_url.starts_with("http://") {
+                    errors.push(UrlNotHttp("Client URL".to_owned(), client_url.clone()));
+                }
+                if client_url.ends_with('/') {
+                    errors.push(UrlInvalidSuffix(
+                        "Client URL".to_owned(),
+                        client_url.clone(),
+                    ));
+                }
+            } else {
+                errors.push(OptionRule::Dependent(
+                    "Client".to_owned(),
+                    "Client URL".to_owned(),
+                ));
+            }
+        }
+        if let Some(client_timeout) = &self.client_timeout {
+            if self.get_timeout().is_none() {
+                errors.push(OptionRule::DurationInvalid(
+                    "Client Timeout".to_owned(),
+                    client_timeout.clone(),
+                ));
+            }
+        }
+        OptionRule::show(&errors);
+        errors.is_empty()
+    }
+
+    #[allow(clippy::manual_let_else)]
+    fn from_args() -> Option<Self> {
+        let options = match ArgumentsParser::get() {
+            Some(
+                Batch { client, .. }
+                | Upload { client, .. }
+                | Queue {
+                    command: Add { client, .. },
+                },
+            ) => client,
+            _ => return None,
+        };
+        Some(options)
+    }
+
+    #[allow(clippy::absolute_paths)]
+    fn from_json(json: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(json)
+    }
+
+    fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+}
+
+impl Display for ClientOptions {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let output = if let Ok(yaml) = serde_yaml::to_string(self) {
+            yaml
+        } else {
+            format!("{self:?}")
+        };
+        output.fmt(formatter)
+    }
+}
@@ -11,10 +11,15 @@ use std::collections::BTreeMap;
 pub struct ConfigCommand {
     batch_options: Ref<BatchOptions>,
     cache_options: Ref<CacheOptions>,
+    client_options: Ref<ClientOptions>,
     file_options: Ref<FileOptions>,
+    image_host_options: Ref<ImageHostOptions>,
+    loudness_options: Ref<LoudnessOptions>,
+    naming_options: Ref<NamingOptions>,
     runner_options: Ref<RunnerOptions>,
     shared_options: Ref<SharedOptions>,
     spectrogram_options: Ref<SpectrogramOptions>,
+    tag_options: Ref<TagOptions>,
     target_options: Ref<TargetOptions>,
     upload_options: Ref<UploadOptions>,
     verify_options: Ref<VerifyOptions>,
@@ -35,10 +40,15 @@ impl ConfigCommand {
         let options = [
             serde_json::to_value(&*self.batch_options)?,
             serde_json::to_value(&*self.cache_options)?,
+            serde_json::to_value(&*self.client_options)?,
             serde_json::to_value(&*self.file_options)?,
+            serde_json::to_value(&*self.image_host_options)?,
+            serde_json::to_value(&*self.loudness_options)?,
+            serde_json::to_value(&*self.naming_options)?,
             serde_json::to_value(&*self.runner_options)?,
             serde_json::to_value(&*self.shared_options)?,
             serde_json::to_value(&*self.spectrogram_options)?,
+            serde_json::to_value(&*self.tag_options)?,
             serde_json::to_value(&*self.target_options)?,
             serde_json::to_value(&*self.upload_options)?,
             serde_json::to_value(&*self.verify_options)?,
@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::cli::ArgumentsParser;
 use crate::cli::CommandArguments::*;
-use crate::options::{Options, OptionsProvider};
+use crate::fs::{AdditionalFilePolicy, FileAction, FileRule};
+use crate::options::{GlobInvalid, OptionRule, Options, OptionsProvider};
+use crate::transcode::CoverArtPolicy;
 
 /// Options for including additional files during [`TranscodeCommand`]
 #[derive(Args, Clone, Debug, Default, Deserialize, Serialize)]
@@ -54,6 +56,70 @@ pub struct FileOptions {
     /// Only applied if the image is greater than `max_file_size`.
     #[arg(long, default_value = None, action = ArgAction::SetTrue)]
     pub no_png_to_jpg: Option<bool>,
+
+    /// Should the jpg quality be lowered until images are under `max_file_size`?
+    ///
+    /// Default: `false`
+    ///
+    /// Quality is lowered in steps of 5% from `jpg_quality` to a minimum of 30%.
+    ///
+    /// Only applied if the image is greater than `max_file_size`.
+    #[arg(long, default_value = None, action = ArgAction::SetTrue)]
+    pub iterative_compression: Option<bool>,
+
+    /// How should cover art embedded in transcodes be handled?
+    ///
+    /// `embed` uses `cover`, `folder` or `front` in the source directory.
+    ///
+    /// Embedded art is considered oversize if it is greater than `max_file_size`.
+    ///
+    /// Default: `keep`
+    #[arg(long, value_enum)]
+    pub cover_art: Option<CoverArtPolicy>,
+
+    /// Maximum size in pixels for embedded cover art.
+    ///
+    /// Default: `1000`
+    ///
+    /// Only applied if `cover_art` is `embed`.
+    #[arg(long)]
+    pub cover_art_max_pixel_size: Option<u32>,
+
+    /// Glob patterns of additional files to include.
+    ///
+    /// Patterns are matched against the path relative to the source directory.
+    ///
+    /// Default: `**/*`
+    #[arg(long)]
+    pub additional_include: Option<Vec<String>>,
+
+    /// Glob patterns of additional files to exclude.
+    ///
+    /// Patterns are matched against the path relative to the source directory.
+    ///
+    /// Default: None
+    #[arg(long)]
+    pub additional_exclude: Option<Vec<String>>,
+
+    /// Maximum depth of subdirectories to search for additional files.
+    ///
+    /// Default: `1`
+    #[arg(long)]
+    pub additional_max_depth: Option<usize>,
+
+    /// Action to apply to additional files by extension.
+    ///
+    /// Written as `<extension>=<action>` with an optional `@<target>,<target>` suffix
+    /// to only apply the rule to some target formats. The first matching rule wins.
+    /// Files without a matching rule are excluded.
+    ///
+    /// Actions: `copy`, `hard-link`, `compress` or `exclude`
+    ///
+    /// Default: `jpg=compress`, `jpeg=compress` and `png=compress`
+    ///
+    /// Example: `--additional-rule pdf=copy --additional-rule log=copy@flac`
+    #[arg(long = "additional-rule")]
+    pub additional_rules: Option<Vec<FileRule>>,
 }
 
 #[injectable]
@@ -78,6 +144,15 @@ impl Options for FileOptions {
         if self.no_png_to_jpg.is_none() {
             self.no_png_to_jpg = alternative.no_png_to_jpg;
         }
+        if self.iterative_compression.is_none() {
+            self.iterative_compression = alternative.iterative_compression;
+        }
+        if self.cover_art.is_none() {
+            self.cover_art = alternative.cover_art;
+        }
+        if self.cover_art_max_pixel_size.is_none() {
+            self.cover_art_max_pixel_size = alternative.cover_art_max_pixel_size;
+        }
         if self.max_file_size.is_none() {
             self.max_file_size = alternative.max_file_size;
         }
@@ -87,6 +162,21 @@ impl Options for FileOptions {
         if self.jpg_quality.is_none() {
             self.jpg_quality = alternative.jpg_quality;
         }
+        if self.additional_include.is_none() {
+            self.additional_include
+                .clone_from(&alternative.additional_include);
+        }
+        if self.additional_exclude.is_none() {
+            self.additional_exclude
+                .clone_from(&alternative.additional_exclude);
+        }
+        if self.additional_max_depth.is_none() {
+            self.additional_max_depth = alternative.additional_max_depth;
+        }
+        if self.additional_rules.is_none() {
+            self.additional_rules
+                .clone_from(&alternative.additional_rules);
+        }
     }
 
     fn apply_defaults(&mut self) {
@@ -99,6 +189,15 @@ impl Options for FileOptions {
         if self.no_png_to_jpg.is_none() {
             self.no_png_to_jpg = Some(false);
         }
+        if self.iterative_compression.is_none() {
+            self.iterative_compression = Some(false);
+        }
+        if self.cover_art.is_none() {
+            self.cover_art = Some(CoverArtPolicy::default());
+        }
+        if self.cover_art_max_pixel_size.is_none() {
+            self.cover_art_max_pixel_size = Some(1000);
+        }
         if self.max_file_size.is_none() {
             self.max_file_size = Some(750_000);
         }
@@ -108,11 +207,45 @@ impl Options for FileOptions {
         if self.jpg_quality.is_none() {
             self.jpg_quality = Some(80);
         }
+        if self.additional_include.is_none() {
+            self.additional_include = Some(vec!["**/*".to_owned()]);
+        }
+        if self.additional_exclude.is_none() {
+            self.additional_exclude = Some(Vec::new());
+        }
+        if self.additional_max_depth.is_none() {
+            self.additional_max_depth = Some(1);
+        }
+        if self.additional_rules.is_none() {
+            self.additional_rules = Some(
+                ["jpg", "jpeg", "png"]
+                    .into_iter()
+                    .map(|extension| FileRule {
+                        extension: extension.to_owned(),
+                        action: FileAction::Compress,
+                        targets: None,
+                    })
+                    .collect(),
+            );
+        }
     }
 
     #[must_use]
     fn validate(&self) -> bool {
-        true
+        let mut errors: Vec<OptionRule> = Vec::new();
+        let patterns = [
+            ("Additional include", &self.additional_include),
+            ("Additional exclude", &self.additional_exclude),
+        ];
+        for (name, patterns) in patterns {
+            for pattern in patterns.iter().flatten() {
+                if AdditionalFilePolicy::get_glob(pattern).is_err() {
+                    errors.push(GlobInvalid(name.to_owned(), pattern.clone()));
+                }
+            }
+        }
+        OptionRule::show(&errors);
+        errors.is_empty()
     }
 
     #[must_use]
@@ -130,6 +263,9 @@ impl Options for FileOptions {
         if options.no_png_to_jpg == Some(false) {
             options.no_png_to_jpg = None;
         }
+        if options.iterative_compression == Some(false) {
+            options.iterative_compression = None;
+        }
         Some(options)
     }
 
@@ -0,0 +1,140 @@
+use std::fmt::{Display, Formatter};
+
+use clap::Args;
+use di::{injectable, Ref};
+use serde::{Deserialize, Serialize};
+
+use crate::cli::ArgumentsParser;
+use crate::cli::CommandArguments::*;
+use crate::image_host::ImageHostKind;
+use crate::options::{OptionRule, Options, OptionsProvider, UrlInvalidSuffix, UrlNotHttp};
+
+/// Options for uploading spectrograms to an image host
+#[allow(clippy::struct_field_names)]
+#[derive(Args, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ImageHostOptions {
+    /// Image host to upload spectrograms to.
+    ///
+    /// Once uploaded the spectrograms are linked in the upload description.
+    ///
+    /// Default: Not set
+    #[arg(long, value_enum)]
+    pub image_host: Option<ImageHostKind>,
+
+    /// URL of the image host.
+    ///
+    /// For `ptpimg` this is the base URL. For `generic` this is the upload endpoint
+    /// which must accept a multipart `file` and `api_key` and respond with the image URL.
+    ///
+    /// Default: `https://ptpimg.me` for `ptpimg`, otherwise not set
+    #[arg(long)]
+    pub image_host_url: Option<String>,
+
+    /// API key for the image host.
+    ///
+    /// Default: Not set
+    #[arg(long)]
+    pub image_host_api_key: Option<String>,
+}
+
+#[injectable]
+impl ImageHostOptions {
+    fn new(provider: Ref<OptionsProvider>) -> Self {
+        provider.get()
+    }
+}
+
+impl Options for ImageHostOptions {
+    fn get_name() -> String {
+        "Image Host Options".to_owned()
+    }
+
+    fn merge(&mut self, alternative: &Self) {
+        if self.image_host.is_none() {
+            self.image_host = alternative.image_host;
+        }
+        if self.image_host_url.is_none() {
+            self.image_host_url.clone_from(&alternative.image_host_url);
+        }
+        if self.image_host_api_key.is_none() {
+            self.image_host_api_key
+                .clone_from(&alternative.image_host_api_key);
+        }
+    }
+
+    fn apply_defaults(&mut self) {
+        if self.image_host_url.is_none() {
+            self.image_host_url = self
+                .image_host
+                .and_then(|kind| kind.get_default_url().map(ToOwned::to_owned));
+        }
+    }
+
+    fn validate(&self) -> bool {
+        let mut errors: Vec<OptionRule> = Vec::new();
+        if self.image_host.is_some() {
+            if let Some(image_host_url) = &self.image_host_url {
+                if !image_host_url.starts_with("https://") && !image_host_url.starts_with("http://")
+                {
+                    errors.push(UrlNotHttp(
+                        "Image Host URL".to_owned(),
+                        image_host_url.clone(),
+                    ));
+                }
+                if image_host_url.ends_with('/') {
+                    errors.push(UrlInvalidSuffix(
+                        "Image Host URL".to_owned(),
+                        image_host_url.clone(),
+                    ));
+                }
+            } else {
+                errors.push(OptionRule::Dependent(
+                    "Image Host".to_owned(),
+                    "Image Host URL".to_owned(),
+                ));
+            }
+            if self.image_host_api_key.is_none() {
+                errors.push(OptionRule::Dependent(
+                    "Image Host".to_owned(),
+                    "Image Host API Key".to_owned(),
+                ));
+            }
+        }
+        OptionRule::show(&errors);
+        errors.is_empty()
+    }
+
+    #[allow(clippy::manual_let_else)]
+    fn from_args() -> Option<Self> {
+        let options = match ArgumentsParser::get() {
+            Some(
+                Batch { image_host, .. }
+                | Spectrogram { image_host, .. }
+                | Upload { image_host, .. },
+            ) => image_host,
+            _ => return None,
+        };
+        Some(options)
+    }
+
+    #[allow(clippy::absolute_paths)]
+    fn from_json(json: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(json)
+    }
+
+    fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+}
+
+impl Display for ImageHostOptions {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let output = if let Ok(yaml) = serde_yaml::to_string(self) {
+            yaml
+        } else {
+            format!("{self:?}")
+        };
+        output.fmt(formatter)
+    }
+}
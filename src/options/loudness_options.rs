@@ -0,0 +1,136 @@
+use std::fmt::{Display, Formatter};
+
+use clap::{ArgAction, Args};
+use di::{injectable, Ref};
+use serde::{Deserialize, Serialize};
+
+use crate::cli::ArgumentsParser;
+use crate::cli::CommandArguments::*;
+use crate::options::{OptionRule, Options, OptionsProvider, OutOfRange};
+
+/// Lowest replay gain reference loudness in LUFS.
+const MIN_REFERENCE: f64 = -70.0;
+
+/// Highest replay gain reference loudness in LUFS.
+const MAX_REFERENCE: f64 = 0.0;
+
+/// Options for loudness analysis and replay gain
+#[derive(Args, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LoudnessOptions {
+    /// Should replay gain tags be added to the transcodes?
+    ///
+    /// Track and album gain are calculated from the EBU R128 loudness of the source FLACs.
+    ///
+    /// FLAC transcodes have `REPLAYGAIN_*` Vorbis comments and MP3 transcodes have
+    /// `REPLAYGAIN_*` TXXX and RVA2 frames.
+    ///
+    /// Default: `false`
+    #[arg(long, default_value = None, action = ArgAction::SetTrue)]
+    pub replay_gain: Option<bool>,
+
+    /// Reference loudness in LUFS that replay gain adjusts to.
+    ///
+    /// Must be between `-70.0` and `0.0`.
+    ///
+    /// Default: `-18.0`
+    #[arg(long)]
+    pub replay_gain_reference: Option<f64>,
+
+    /// Should the loudness of each FLAC be analyzed when verifying?
+    ///
+    /// Clipping and abnormally low peak levels are reported as warnings.
+    ///
+    /// Default: `false`
+    #[arg(long, default_value = None, action = ArgAction::SetTrue)]
+    pub loudness_check: Option<bool>,
+}
+
+#[injectable]
+impl LoudnessOptions {
+    fn new(provider: Ref<OptionsProvider>) -> Self {
+        provider.get()
+    }
+}
+
+impl Options for LoudnessOptions {
+    fn get_name() -> String {
+        "Loudness Options".to_owned()
+    }
+
+    fn merge(&mut self, alternative: &Self) {
+        if self.replay_gain.is_none() {
+            self.replay_gain = alternative.replay_gain;
+        }
+        if self.replay_gain_reference.is_none() {
+            self.replay_gain_reference = alternative.replay_gain_reference;
+        }
+        if self.loudness_check.is_none() {
+            self.loudness_check = alternative.loudness_check;
+        }
+    }
+
+    fn apply_defaults(&mut self) {
+        if self.replay_gain.is_none() {
+            self.replay_gain = Some(false);
+        }
+        if self.replay_gain_reference.is_none() {
+            self.replay_gain_reference = Some(-18.0);
+        }
+        if self.loudness_check.is_none() {
+            self.loudness_check = Some(false);
+        }
+    }
+
+    fn validate(&self) -> bool {
+        let mut errors: Vec<OptionRule> = Vec::new();
+        if let Some(reference) = self.replay_gain_reference {
+            if !(MIN_REFERENCE..=MAX_REFERENCE).contains(&reference) {
+                errors.push(OutOfRange(
+                    "Replay Gain Reference".to_owned(),
+                    reference.to_string(),
+                    format!("between {MIN_REFERENCE} and {MAX_REFERENCE} LUFS"),
+                ));
+            }
+        }
+        OptionRule::show(&errors);
+        errors.is_empty()
+    }
+
+    #[allow(clippy::manual_let_else)]
+    fn from_args() -> Option<Self> {
+        let mut options = match ArgumentsParser::get() {
+            Some(Batch { loudness, .. } | Transcode { loudness, .. } | Verify { loudness, .. }) => {
+                loudness
+            }
+            _ => return None,
+        };
+        if options.replay_gain == Some(false) {
+            options.replay_gain = None;
+        }
+        if options.loudness_check == Some(false) {
+            options.loudness_check = None;
+        }
+        Some(options)
+    }
+
+    #[allow(clippy::absolute_paths)]
+    fn from_json(json: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(json)
+    }
+
+    fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+}
+
+impl Display for LoudnessOptions {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let output = if let Ok(yaml) = serde_yaml::to_string(self) {
+            yaml
+        } else {
+            format!("{self:?}")
+        };
+        output.fmt(formatter)
+    }
+}
@@ -1,6 +1,10 @@
 pub use batch_options::*;
 pub use cache_options::*;
+pub use client_options::*;
 pub use file_options::*;
+pub use image_host_options::*;
+pub use loudness_options::*;
+pub use naming_options::*;
 pub use options_provider::*;
 pub use options_trait::*;
 pub use queue_add_args::*;
@@ -10,14 +14,19 @@ pub use runner_options::*;
 pub use shared_options::*;
 pub use source_arg::*;
 pub use spectrogram_options::*;
+pub use tag_options::*;
 pub use target_options::*;
 pub use upload_options::*;
 pub use verify_options::*;
 
 pub(crate) mod batch_options;
 pub(crate) mod cache_options;
+pub(crate) mod client_options;
 pub(crate) mod config_command;
 pub(crate) mod file_options;
+pub(crate) mod image_host_options;
+pub(crate) mod loudness_options;
+pub(crate) mod naming_options;
 pub(crate) mod options_provider;
 pub(crate) mod options_trait;
 pub(crate) mod queue_add_args;
@@ -26,6 +35,7 @@ pub(crate) mod runner_options;
 pub(crate) mod shared_options;
 pub(crate) mod source_arg;
 pub(crate) mod spectrogram_options;
+pub(crate) mod tag_options;
 pub(crate) mod target_options;
 #[cfg(test)]
 mod tests;
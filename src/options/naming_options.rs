@@ -0,0 +1,155 @@
+use std::fmt::{Display, Formatter};
+
+use clap::Args;
+use di::{injectable, Ref};
+use serde::{Deserialize, Serialize};
+
+use crate::cli::ArgumentsParser;
+use crate::cli::CommandArguments::*;
+use crate::naming::{
+    NameTemplates, DEFAULT_SOURCE_NAME_TEMPLATE, DEFAULT_SPECTROGRAM_NAME_TEMPLATE,
+    DEFAULT_TORRENT_NAME_TEMPLATE, DEFAULT_TRANSCODE_NAME_TEMPLATE,
+};
+use crate::options::{OptionRule, Options, OptionsProvider, TemplateInvalid};
+
+/// Options for the names of transcode and spectrogram directories and torrent files
+///
+/// Templates use the `minijinja` syntax with the placeholders `artist`, `album`,
+/// `remaster_title`, `year`, `original_year`, `media`, `label`, `catalogue_number` and
+/// `release_type`.
+///
+/// Transcode and torrent templates additionally have `format` (`FLAC` or `MP3`),
+/// `bitrate` (`Lossless`, `320` or `V0 (VBR)`) and `target` (`FLAC`, `320` or `V0`).
+#[allow(clippy::struct_field_names)]
+#[derive(Args, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NamingOptions {
+    /// Template of the source name.
+    ///
+    /// Default: `{{ artist }} - {{ album }}{% if remaster_title %} ({{ remaster_title }}){% endif %} [{{ year }}]`
+    #[arg(long)]
+    pub source_name_template: Option<String>,
+
+    /// Template of the transcode directory name.
+    ///
+    /// `source_name` is the rendered source name.
+    ///
+    /// Default: `{{ source_name }} [{{ media }} {{ target }}]`
+    #[arg(long)]
+    pub transcode_name_template: Option<String>,
+
+    /// Template of the spectrogram directory name.
+    ///
+    /// `source_name` is the rendered source name.
+    ///
+    /// Default: `{{ source_name }} [{{ media }} SPECTROGRAMS]`
+    #[arg(long)]
+    pub spectrogram_name_template: Option<String>,
+
+    /// Template of the torrent file name without the extension.
+    ///
+    /// `transcode_name` is the name of the transcode directory.
+    ///
+    /// Default: `{{ transcode_name }}`
+    #[arg(long)]
+    pub torrent_name_template: Option<String>,
+}
+
+#[injectable]
+impl NamingOptions {
+    fn new(provider: Ref<OptionsProvider>) -> Self {
+        provider.get()
+    }
+}
+
+impl Options for NamingOptions {
+    fn get_name() -> String {
+        "Naming Options".to_owned()
+    }
+
+    fn merge(&mut self, alternative: &Self) {
+        if self.source_name_template.is_none() {
+            self.source_name_template
+                .clone_from(&alternative.source_name_template);
+        }
+        if self.transcode_name_template.is_none() {
+            self.transcode_name_template
+                .clone_from(&alternative.transcode_name_template);
+        }
+        if self.spectrogram_name_template.is_none() {
+            self.spectrogram_name_template
+                .clone_from(&alternative.spectrogram_name_template);
+        }
+        if self.torrent_name_template.is_none() {
+            self.torrent_name_template
+                .clone_from(&alternative.torrent_name_template);
+        }
+    }
+
+    fn apply_defaults(&mut self) {
+        if self.source_name_template.is_none() {
+            self.source_name_template = Some(DEFAULT_SOURCE_NAME_TEMPLATE.to_owned());
+        }
+        if self.transcode_name_template.is_none() {
+            self.transcode_name_template = Some(DEFAULT_TRANSCODE_NAME_TEMPLATE.to_owned());
+        }
+        if self.spectrogram_name_template.is_none() {
+            self.spectrogram_name_template = Some(DEFAULT_SPECTROGRAM_NAME_TEMPLATE.to_owned());
+        }
+        if self.torrent_name_template.is_none() {
+            self.torrent_name_template = Some(DEFAULT_TORRENT_NAME_TEMPLATE.to_owned());
+        }
+    }
+
+    fn validate(&self) -> bool {
+        let mut errors: Vec<OptionRule> = Vec::new();
+        let templates = [
+            ("Source name template", &self.source_name_template),
+            ("Transcode name template", &self.transcode_name_template),
+            ("Spectrogram name template", &self.spectrogram_name_template),
+            ("Torrent name template", &self.torrent_name_template),
+        ];
+        for (name, template) in templates {
+            if let Some(template) = template {
+                if let Err(reason) = NameTemplates::validate(template) {
+                    errors.push(TemplateInvalid(name.to_owned(), reason));
+                }
+            }
+        }
+        OptionRule::show(&errors);
+        errors.is_empty()
+    }
+
+    fn from_args() -> Option<Self> {
+        match ArgumentsParser::get() {
+            Some(
+                Batch { naming, .. }
+                | Spectrogram { naming, .. }
+                | Transcode { naming, .. }
+                | Upload { naming, .. }
+                | Verify { naming, .. },
+            ) => Some(naming),
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::absolute_paths)]
+    fn from_json(json: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(json)
+    }
+
+    fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+}
+
+impl Display for NamingOptions {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let output = if let Ok(yaml) = serde_yaml::to_string(self) {
+            yaml
+        } else {
+            format!("{self:?}")
+        };
+        output.fmt(formatter)
+    }
+}
@@ -4,7 +4,7 @@ use std::path::PathBuf;
 use crate::cli::CommandArguments::Queue;
 use crate::cli::{ArgumentsParser, QueueCommandArguments};
 use crate::options::{DoesNotExist, NotSet, OptionRule, Options, OptionsProvider};
-use clap::Args;
+use clap::{ArgAction, Args};
 use di::{injectable, Ref};
 use serde::{Deserialize, Serialize};
 use QueueCommandArguments::Add;
@@ -19,6 +19,14 @@ pub struct QueueAddArgs {
     /// Examples: `./torrents`, `/path/to/torrents`, `./queue.yml`
     #[arg(value_name = "PATH")]
     pub queue_add_path: Option<PathBuf>,
+
+    /// Should completed torrents be read from the torrent client instead of a path?
+    ///
+    /// Requires `client` and `client_url` to be set.
+    ///
+    /// Default: `false`
+    #[arg(long, default_value = None, action = ArgAction::SetTrue)]
+    pub from_client: Option<bool>,
 }
 
 #[injectable]
@@ -37,9 +45,16 @@ impl Options for QueueAddArgs {
         if self.queue_add_path.is_none() {
             self.queue_add_path.clone_from(&alternative.queue_add_path);
         }
+        if self.from_client.is_none() {
+            self.from_client = alternative.from_client;
+        }
     }
 
-    fn apply_defaults(&mut self) {}
+    fn apply_defaults(&mut self) {
+        if self.from_client.is_none() {
+            self.from_client = Some(false);
+        }
+    }
 
     #[must_use]
     fn validate(&self) -> bool {
@@ -51,7 +66,7 @@ impl Options for QueueAddArgs {
                     path.to_string_lossy().to_string(),
                 ));
             }
-        } else {
+        } else if self.from_client != Some(true) {
             errors.push(NotSet("Queue add path".to_owned()));
         }
         OptionRule::show(&errors);
@@ -64,7 +79,13 @@ impl Options for QueueAddArgs {
             Some(Queue {
                 command: Add { args, .. },
                 ..
-            }) => Some(args),
+            }) => {
+                let mut options = args;
+                if options.from_client == Some(false) {
+                    options.from_client = None;
+                }
+                Some(options)
+            }
             _ => None,
         }
     }
@@ -9,10 +9,17 @@ pub enum OptionRule {
     NotSet(String),
     Dependent(String, String),
     IsEmpty(String),
+    IsZero(String),
+    OutOfRange(String, String, String),
     UrlNotHttp(String, String),
     UrlInvalidSuffix(String, String),
     DoesNotExist(String, String),
+    Duplicate(String, String),
+    PathSeparator(String, String),
     DurationInvalid(String, String),
+    GlobInvalid(String, String),
+    RegexInvalid(String, String),
+    TemplateInvalid(String, String),
 }
 
 impl Display for OptionRule {
@@ -23,6 +30,8 @@ impl Display for OptionRule {
             Dependent(this, that) => format!("{this} requires {that} to be set"),
             NotSet(name) => format!("{name} is not set"),
             IsEmpty(name) => format!("{name} must have at least one value"),
+            IsZero(name) => format!("{name} must be greater than zero"),
+            OutOfRange(name, value, range) => format!("{name} must be {range}: {value}"),
             UrlNotHttp(name, value) => {
                 format!("{name} must start with https:// or http://: {value}")
             }
@@ -30,7 +39,14 @@ impl Display for OptionRule {
                 format!("{name} must not end with /: {value}")
             }
             DoesNotExist(name, value) => format!("{name} does not exist: {value}"),
+            Duplicate(name, value) => format!("{name} must be unique: {value}"),
+            PathSeparator(name, value) => {
+                format!("{name} must not contain / or \\: {value}")
+            }
             DurationInvalid(name, value) => format!("{name} could not be parsed: {value}"),
+            GlobInvalid(name, value) => format!("{name} is not a valid glob: {value}"),
+            RegexInvalid(name, value) => format!("{name} is not a valid regex: {value}"),
+            TemplateInvalid(name, reason) => format!("{name} is not a valid template: {reason}"),
         };
         output.fmt(formatter)
     }
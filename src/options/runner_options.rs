@@ -1,12 +1,14 @@
 use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
 use crate::cli::ArgumentsParser;
 use crate::cli::CommandArguments::*;
-use clap::Args;
+use clap::{ArgAction, Args};
 use di::{injectable, Ref};
 use serde::{Deserialize, Serialize};
 
-use crate::options::{Options, OptionsProvider};
+use crate::jobs::{RetryClass, RetryPolicy};
+use crate::options::{DurationInvalid, IsZero, OptionRule, Options, OptionsProvider};
 
 /// Options for [`JobRunner`]
 #[derive(Args, Clone, Debug, Default, Deserialize, Serialize)]
@@ -16,6 +18,84 @@ pub struct RunnerOptions {
     /// Default: Total number of CPUs
     #[arg(long)]
     pub cpus: Option<u16>,
+
+    /// Number of concurrent IO bound operations such as resizing images,
+    /// hard linking and copying files.
+    ///
+    /// Lower this to avoid thrashing spinning disks.
+    ///
+    /// Default: `4`
+    #[arg(long)]
+    pub io_limit: Option<u16>,
+
+    /// Maximum number of concurrent transcode jobs.
+    ///
+    /// Each transcode uses a CPU for the decode and a CPU for the encode process.
+    ///
+    /// Default: Not set
+    #[arg(long)]
+    pub transcode_limit: Option<u16>,
+
+    /// Maximum number of concurrent spectrogram jobs.
+    ///
+    /// Default: Not set
+    #[arg(long)]
+    pub spectrogram_limit: Option<u16>,
+
+    /// Maximum number of concurrent additional file jobs.
+    ///
+    /// Default: Not set
+    #[arg(long)]
+    pub additional_limit: Option<u16>,
+
+    /// Maximum duration of a single job.
+    ///
+    /// A job that takes longer is stopped, its processes are killed and its output is removed.
+    ///
+    /// The duration is a string that can be parsed such as `500ms`, `5m`, `1h30m15s`.
+    ///
+    /// Default: `30m`
+    #[arg(long)]
+    pub job_timeout: Option<String>,
+
+    /// Should the remaining jobs continue after a job fails?
+    ///
+    /// If set every failed job is reported, otherwise the remaining jobs are stopped
+    /// after the first failure.
+    ///
+    /// Default: `false`
+    #[arg(long, default_value = None, action = ArgAction::SetTrue)]
+    pub collect_failures: Option<bool>,
+
+    /// Maximum number of attempts for each job.
+    ///
+    /// A job that fails with one of the `retry_on` errors is retried until it
+    /// succeeds or this number of attempts is reached.
+    ///
+    /// Default: `3`
+    #[arg(long)]
+    pub retry_attempts: Option<u8>,
+
+    /// Delay before retrying a failed job.
+    ///
+    /// The delay is doubled for each subsequent retry.
+    ///
+    /// The duration is a string that can be parsed such as `500ms`, `5m`, `1h30m15s`.
+    ///
+    /// Default: `5s`
+    #[arg(long)]
+    pub retry_backoff: Option<String>,
+
+    /// Classes of error to retry a failed job for.
+    ///
+    /// - `signal`: a process was terminated by a signal, for example after running out of memory
+    /// - `exit`: a process exited with a non-zero exit code
+    /// - `io`: a file system error
+    /// - `timeout`: the job exceeded `job_timeout`
+    ///
+    /// Default: `signal` and `io`
+    #[arg(long, value_enum)]
+    pub retry_on: Option<Vec<RetryClass>>,
 }
 
 #[injectable]
@@ -23,6 +103,25 @@ impl RunnerOptions {
     fn new(provider: Ref<OptionsProvider>) -> Self {
         provider.get()
     }
+
+    #[must_use]
+    pub fn get_job_timeout(&self) -> Option<Duration> {
+        let job_timeout = self.job_timeout.clone()?;
+        humantime::parse_duration(job_timeout.as_str()).ok()
+    }
+
+    #[must_use]
+    pub fn get_retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            attempts: self.retry_attempts.expect("retry_attempts should be set"),
+            backoff: self
+                .retry_backoff
+                .as_deref()
+                .and_then(|x| humantime::parse_duration(x).ok())
+                .expect("retry_backoff should be set"),
+            classes: self.retry_on.clone().expect("retry_on should be set"),
+        }
+    }
 }
 
 impl Options for RunnerOptions {
@@ -34,6 +133,33 @@ impl Options for RunnerOptions {
         if self.cpus.is_none() {
             self.cpus.clone_from(&alternative.cpus);
         }
+        if self.io_limit.is_none() {
+            self.io_limit = alternative.io_limit;
+        }
+        if self.transcode_limit.is_none() {
+            self.transcode_limit = alternative.transcode_limit;
+        }
+        if self.spectrogram_limit.is_none() {
+            self.spectrogram_limit = alternative.spectrogram_limit;
+        }
+        if self.additional_limit.is_none() {
+            self.additional_limit = alternative.additional_limit;
+        }
+        if self.job_timeout.is_none() {
+            self.job_timeout.clone_from(&alternative.job_timeout);
+        }
+        if self.collect_failures.is_none() {
+            self.collect_failures = alternative.collect_failures;
+        }
+        if self.retry_attempts.is_none() {
+            self.retry_attempts = alternative.retry_attempts;
+        }
+        if self.retry_backoff.is_none() {
+            self.retry_backoff.clone_from(&alternative.retry_backoff);
+        }
+        if self.retry_on.is_none() {
+            self.retry_on.clone_from(&alternative.retry_on);
+        }
     }
 
     #[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
@@ -41,11 +167,60 @@ impl Options for RunnerOptions {
         if self.cpus.is_none() {
             self.cpus = Some(num_cpus::get() as u16);
         }
+        if self.io_limit.is_none() {
+            self.io_limit = Some(4);
+        }
+        if self.job_timeout.is_none() {
+            self.job_timeout = Some("30m".to_owned());
+        }
+        if self.collect_failures.is_none() {
+            self.collect_failures = Some(false);
+        }
+        if self.retry_attempts.is_none() {
+            self.retry_attempts = Some(3);
+        }
+        if self.retry_backoff.is_none() {
+            self.retry_backoff = Some("5s".to_owned());
+        }
+        if self.retry_on.is_none() {
+            self.retry_on = Some(vec![RetryClass::Signal, RetryClass::Io]);
+        }
     }
 
     #[must_use]
     fn validate(&self) -> bool {
-        true
+        let mut errors: Vec<OptionRule> = Vec::new();
+        let counts = [
+            ("CPUs", self.cpus),
+            ("IO Limit", self.io_limit),
+            ("Transcode Limit", self.transcode_limit),
+            ("Spectrogram Limit", self.spectrogram_limit),
+            ("Additional Limit", self.additional_limit),
+            ("Retry Attempts", self.retry_attempts.map(u16::from)),
+        ];
+        for (name, count) in counts {
+            if count == Some(0) {
+                errors.push(IsZero(name.to_owned()));
+            }
+        }
+        if let Some(job_timeout) = &self.job_timeout {
+            if self.get_job_timeout().is_none() {
+                errors.push(DurationInvalid(
+                    "Job Timeout".to_owned(),
+                    job_timeout.clone(),
+                ));
+            }
+        }
+        if let Some(retry_backoff) = &self.retry_backoff {
+            if humantime::parse_duration(retry_backoff).is_err() {
+                errors.push(DurationInvalid(
+                    "Retry Backoff".to_owned(),
+                    retry_backoff.clone(),
+                ));
+            }
+        }
+        OptionRule::show(&errors);
+        errors.is_empty()
     }
 
     fn from_args() -> Option<Self> {
@@ -1,21 +1,79 @@
+use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
 
 use crate::cli::ArgumentsParser;
 use crate::cli::CommandArguments::{Batch, Spectrogram};
-use crate::options::{IsEmpty, OptionRule, Options, OptionsProvider};
-use crate::spectrogram::Size;
+use crate::options::{
+    DoesNotExist, Duplicate, DurationInvalid, IsEmpty, OptionRule, Options, OptionsProvider,
+    PathSeparator,
+};
+use crate::spectrogram::{SpectrogramProfile, SpectrogramStart, FULL_PROFILE, ZOOM_PROFILE};
 use clap::Args;
 use di::{injectable, Ref};
 use serde::{Deserialize, Serialize};
 
 /// Options for [`SpectrogramCommand`]
+#[allow(clippy::struct_field_names)]
 #[derive(Args, Clone, Debug, Default, Deserialize, Serialize)]
 pub struct SpectrogramOptions {
-    /// Sizes of spectrograms to generate.
+    /// Names of the spectrogram profiles to generate.
+    ///
+    /// The built-in profiles are `full` and `zoom`. Additional profiles can be
+    /// defined with `spectrogram_profiles`.
     ///
     /// Default: `full` and `zoom`
     #[arg(long)]
-    pub spectrogram_size: Option<Vec<Size>>,
+    pub spectrogram_size: Option<Vec<String>>,
+
+    /// Custom spectrogram profiles.
+    ///
+    /// Each profile has a `name`, `width` and `height` in pixels, and optionally a
+    /// `dynamic_range`, `window_size`, `channels` (`remix` or `separate`), `start`
+    /// and `duration`.
+    ///
+    /// A profile with the name of a built-in profile replaces it.
+    ///
+    /// Only configurable in the config file.
+    ///
+    /// Default: Not set
+    #[arg(skip)]
+    pub spectrogram_profiles: Option<Vec<SpectrogramProfile>>,
+
+    /// Number of samples in each DFT window.
+    ///
+    /// Larger windows improve frequency resolution at the cost of time resolution.
+    ///
+    /// Default: Not set, the window is sized to the image height as sox does.
+    #[arg(long)]
+    pub spectrogram_window_size: Option<usize>,
+
+    /// Dynamic range of the spectrogram in dB.
+    ///
+    /// Equivalent to the sox `-z` option.
+    ///
+    /// Default: `120`
+    #[arg(long)]
+    pub spectrogram_dynamic_range: Option<u32>,
+
+    /// Offset from the start of the track of the zoomed spectrogram.
+    ///
+    /// The duration is a string that can be parsed such as `500ms`, `5m`, `1h30m15s`.
+    ///
+    /// Set to `random` for a random offset within the track.
+    ///
+    /// If the track is shorter the offset is moved earlier.
+    ///
+    /// Default: `1m`
+    #[arg(long)]
+    pub spectrogram_zoom_start: Option<String>,
+
+    /// Duration of the zoomed spectrogram.
+    ///
+    /// The duration is a string that can be parsed such as `500ms`, `5m`, `1h30m15s`.
+    ///
+    /// Default: `2s`
+    #[arg(long)]
+    pub spectrogram_zoom_duration: Option<String>,
 }
 
 #[injectable]
@@ -23,6 +81,57 @@ impl SpectrogramOptions {
     fn new(provider: Ref<OptionsProvider>) -> Self {
         provider.get()
     }
+
+    /// Get the built-in and custom profiles.
+    #[must_use]
+    pub fn get_profiles(&self) -> Vec<SpectrogramProfile> {
+        let mut profiles = vec![
+            SpectrogramProfile {
+                name: FULL_PROFILE.to_owned(),
+                width: 3000,
+                height: 513,
+                ..SpectrogramProfile::default()
+            },
+            SpectrogramProfile {
+                name: ZOOM_PROFILE.to_owned(),
+                width: 500,
+                height: 1025,
+                start: self.spectrogram_zoom_start.clone(),
+                duration: self.spectrogram_zoom_duration.clone(),
+                ..SpectrogramProfile::default()
+            },
+        ];
+        for custom in self.spectrogram_profiles.iter().flatten() {
+            if let Some(profile) = profiles.iter_mut().find(|x| x.name == custom.name) {
+                profile.clone_from(custom);
+            } else {
+                profiles.push(custom.clone());
+            }
+        }
+        profiles
+    }
+
+    /// Get the profiles selected by `spectrogram_size` in order.
+    #[must_use]
+    pub fn get_selected_profiles(&self) -> Vec<SpectrogramProfile> {
+        let profiles = self.get_profiles();
+        self.spectrogram_size
+            .iter()
+            .flatten()
+            .filter_map(|name| profiles.iter().find(|x| &x.name == name).cloned())
+            .collect()
+    }
+
+    /// Get the name of the selected profile to include in the contact sheet.
+    ///
+    /// The widest profile covering the whole track is preferred.
+    #[must_use]
+    pub fn get_contact_sheet_profile(&self) -> Option<String> {
+        self.get_selected_profiles()
+            .into_iter()
+            .max_by_key(|x| (x.start.is_none() && x.duration.is_none(), x.width))
+            .map(|x| x.name)
+    }
 }
 
 impl Options for SpectrogramOptions {
@@ -35,11 +144,38 @@ impl Options for SpectrogramOptions {
             self.spectrogram_size
                 .clone_from(&alternative.spectrogram_size);
         }
+        if self.spectrogram_profiles.is_none() {
+            self.spectrogram_profiles
+                .clone_from(&alternative.spectrogram_profiles);
+        }
+        if self.spectrogram_window_size.is_none() {
+            self.spectrogram_window_size = alternative.spectrogram_window_size;
+        }
+        if self.spectrogram_dynamic_range.is_none() {
+            self.spectrogram_dynamic_range = alternative.spectrogram_dynamic_range;
+        }
+        if self.spectrogram_zoom_start.is_none() {
+            self.spectrogram_zoom_start
+                .clone_from(&alternative.spectrogram_zoom_start);
+        }
+        if self.spectrogram_zoom_duration.is_none() {
+            self.spectrogram_zoom_duration
+                .clone_from(&alternative.spectrogram_zoom_duration);
+        }
     }
 
     fn apply_defaults(&mut self) {
         if self.spectrogram_size.is_none() {
-            self.spectrogram_size = Some(vec![Size::Full, Size::Zoom]);
+            self.spectrogram_size = Some(vec![FULL_PROFILE.to_owned(), ZOOM_PROFILE.to_owned()]);
+        }
+        if self.spectrogram_dynamic_range.is_none() {
+            self.spectrogram_dynamic_range = Some(120);
+        }
+        if self.spectrogram_zoom_start.is_none() {
+            self.spectrogram_zoom_start = Some("1m".to_owned());
+        }
+        if self.spectrogram_zoom_duration.is_none() {
+            self.spectrogram_zoom_duration = Some("2s".to_owned());
         }
     }
 
@@ -50,6 +186,61 @@ impl Options for SpectrogramOptions {
         if size.is_none() || size.is_some_and(Vec::is_empty) {
             errors.push(IsEmpty("Spectrogram Size".to_owned()));
         }
+        let profiles = self.get_profiles();
+        for name in size.into_iter().flatten() {
+            if !profiles.iter().any(|x| &x.name == name) {
+                errors.push(DoesNotExist("Spectrogram Profile".to_owned(), name.clone()));
+            }
+        }
+        let mut names = HashSet::new();
+        for profile in self.spectrogram_profiles.iter().flatten() {
+            if profile.name.is_empty() {
+                errors.push(IsEmpty("Spectrogram Profile Name".to_owned()));
+            } else if profile.name.contains(['/', '\\']) {
+                errors.push(PathSeparator(
+                    "Spectrogram Profile Name".to_owned(),
+                    profile.name.clone(),
+                ));
+            }
+            if !names.insert(profile.name.as_str()) {
+                errors.push(Duplicate(
+                    "Spectrogram Profile Name".to_owned(),
+                    profile.name.clone(),
+                ));
+            }
+            if let Some(start) = &profile.start {
+                if profile.get_start().is_none() {
+                    errors.push(DurationInvalid(
+                        format!("Spectrogram Profile {} Start", profile.name),
+                        start.clone(),
+                    ));
+                }
+            }
+            if let Some(duration) = &profile.duration {
+                if profile.get_duration().is_err() {
+                    errors.push(DurationInvalid(
+                        format!("Spectrogram Profile {} Duration", profile.name),
+                        duration.clone(),
+                    ));
+                }
+            }
+        }
+        if let Some(zoom_start) = &self.spectrogram_zoom_start {
+            if SpectrogramStart::parse(zoom_start).is_none() {
+                errors.push(DurationInvalid(
+                    "Spectrogram Zoom Start".to_owned(),
+                    zoom_start.clone(),
+                ));
+            }
+        }
+        if let Some(zoom_duration) = &self.spectrogram_zoom_duration {
+            if humantime::parse_duration(zoom_duration).is_err() {
+                errors.push(DurationInvalid(
+                    "Spectrogram Zoom Duration".to_owned(),
+                    zoom_duration.clone(),
+                ));
+            }
+        }
         OptionRule::show(&errors);
         errors.is_empty()
     }
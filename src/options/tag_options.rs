@@ -0,0 +1,222 @@
+use std::fmt::{Display, Formatter};
+
+use clap::{ArgAction, Args};
+use di::{injectable, Ref};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::ArgumentsParser;
+use crate::cli::CommandArguments::*;
+use crate::fs::{Id3Version, DEFAULT_TAG_JUNK};
+use crate::options::{OptionRule, Options, OptionsProvider, RegexInvalid};
+
+/// Options for the tags of transcodes
+#[derive(Args, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TagOptions {
+    /// Vorbis comment fields to keep.
+    ///
+    /// If set, all other fields are removed.
+    ///
+    /// Default: None
+    ///
+    /// Example: `--tag-include ARTIST --tag-include TITLE`
+    #[arg(long)]
+    pub tag_include: Option<Vec<String>>,
+
+    /// Vorbis comment fields to remove.
+    ///
+    /// Default: None
+    ///
+    /// Example: `--tag-exclude COMMENT --tag-exclude ENCODER`
+    #[arg(long)]
+    pub tag_exclude: Option<Vec<String>>,
+
+    /// Should comments of ripper or encoder junk be removed?
+    ///
+    /// Comments matching any of the `tag_junk` patterns are removed.
+    ///
+    /// Default: `false`
+    #[arg(long, default_value = None, action = ArgAction::SetTrue)]
+    pub strip_tag_junk: Option<bool>,
+
+    /// Regex patterns of ripper or encoder junk to remove from comments.
+    ///
+    /// Only applied if `strip_tag_junk` is set.
+    ///
+    /// Default: patterns matching comments that credit EAC, XLD, `dBpoweramp`,
+    /// `CUERipper`, `foobar2000` or an encoder.
+    #[arg(long)]
+    pub tag_junk: Option<Vec<String>>,
+
+    /// Should leading and trailing whitespace be trimmed from tag values?
+    ///
+    /// Default: `false`
+    #[arg(long, default_value = None, action = ArgAction::SetTrue)]
+    pub tag_trim: Option<bool>,
+
+    /// Separators to split multi-valued artist fields by.
+    ///
+    /// Applies to the `ARTIST` and `ALBUMARTIST` fields.
+    ///
+    /// Default: None
+    ///
+    /// Example: `--artist-separator "; "`
+    #[arg(long = "artist-separator")]
+    pub artist_separators: Option<Vec<String>>,
+
+    /// Should the disc number be derived from `CD1` style directories?
+    ///
+    /// Only applied if the disc number is not set.
+    ///
+    /// Default: `false`
+    #[arg(long, default_value = None, action = ArgAction::SetTrue)]
+    pub disc_from_directory: Option<bool>,
+
+    /// Should missing tags be supplemented in the transcodes?
+    ///
+    /// Missing `artist`, `album`, `title`, `composer` and `track_number` tags are inferred
+    /// from the API metadata, the torrent file list and file names such as `01 - Title.flac`.
+    ///
+    /// The source files are never modified.
+    ///
+    /// Default: `false`
+    #[arg(long, default_value = None, action = ArgAction::SetTrue)]
+    pub fix_tags: Option<bool>,
+
+    /// ID3 version of MP3 transcodes.
+    ///
+    /// Default: `v2.4`
+    #[arg(long, value_enum)]
+    pub id3_version: Option<Id3Version>,
+}
+
+#[injectable]
+impl TagOptions {
+    fn new(provider: Ref<OptionsProvider>) -> Self {
+        provider.get()
+    }
+}
+
+impl Options for TagOptions {
+    fn get_name() -> String {
+        "Tag Options".to_owned()
+    }
+
+    fn merge(&mut self, alternative: &Self) {
+        if self.tag_include.is_none() {
+            self.tag_include.clone_from(&alternative.tag_include);
+        }
+        if self.tag_exclude.is_none() {
+            self.tag_exclude.clone_from(&alternative.tag_exclude);
+        }
+        if self.strip_tag_junk.is_none() {
+            self.strip_tag_junk = alternative.strip_tag_junk;
+        }
+        if self.tag_junk.is_none() {
+            self.tag_junk.clone_from(&alternative.tag_junk);
+        }
+        if self.tag_trim.is_none() {
+            self.tag_trim = alternative.tag_trim;
+        }
+        if self.artist_separators.is_none() {
+            self.artist_separators
+                .clone_from(&alternative.artist_separators);
+        }
+        if self.disc_from_directory.is_none() {
+            self.disc_from_directory = alternative.disc_from_directory;
+        }
+        if self.fix_tags.is_none() {
+            self.fix_tags = alternative.fix_tags;
+        }
+        if self.id3_version.is_none() {
+            self.id3_version = alternative.id3_version;
+        }
+    }
+
+    fn apply_defaults(&mut self) {
+        if self.tag_exclude.is_none() {
+            self.tag_exclude = Some(Vec::new());
+        }
+        if self.strip_tag_junk.is_none() {
+            self.strip_tag_junk = Some(false);
+        }
+        if self.tag_junk.is_none() {
+            self.tag_junk = Some(
+                DEFAULT_TAG_JUNK
+                    .iter()
+                    .map(|pattern| (*pattern).to_owned())
+                    .collect(),
+            );
+        }
+        if self.tag_trim.is_none() {
+            self.tag_trim = Some(false);
+        }
+        if self.artist_separators.is_none() {
+            self.artist_separators = Some(Vec::new());
+        }
+        if self.disc_from_directory.is_none() {
+            self.disc_from_directory = Some(false);
+        }
+        if self.fix_tags.is_none() {
+            self.fix_tags = Some(false);
+        }
+        if self.id3_version.is_none() {
+            self.id3_version = Some(Id3Version::default());
+        }
+    }
+
+    fn validate(&self) -> bool {
+        let mut errors: Vec<OptionRule> = Vec::new();
+        for pattern in self.tag_junk.iter().flatten() {
+            if Regex::new(pattern).is_err() {
+                errors.push(RegexInvalid("Tag junk".to_owned(), pattern.clone()));
+            }
+        }
+        OptionRule::show(&errors);
+        errors.is_empty()
+    }
+
+    #[allow(clippy::manual_let_else)]
+    fn from_args() -> Option<Self> {
+        let mut options = match ArgumentsParser::get() {
+            Some(
+                Batch { tag, .. } | Transcode { tag, .. } | Upload { tag, .. } | Verify { tag, .. },
+            ) => tag,
+            _ => return None,
+        };
+        if options.strip_tag_junk == Some(false) {
+            options.strip_tag_junk = None;
+        }
+        if options.tag_trim == Some(false) {
+            options.tag_trim = None;
+        }
+        if options.disc_from_directory == Some(false) {
+            options.disc_from_directory = None;
+        }
+        if options.fix_tags == Some(false) {
+            options.fix_tags = None;
+        }
+        Some(options)
+    }
+
+    #[allow(clippy::absolute_paths)]
+    fn from_json(json: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(json)
+    }
+
+    fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+}
+
+impl Display for TagOptions {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let output = if let Ok(yaml) = serde_yaml::to_string(self) {
+            yaml
+        } else {
+            format!("{self:?}")
+        };
+        output.fmt(formatter)
+    }
+}
@@ -25,6 +25,17 @@ pub struct TargetOptions {
     /// Default: `false`
     #[arg(long, default_value = None, action = ArgAction::SetTrue)]
     pub allow_existing: Option<bool>,
+
+    /// Shorten transcode paths that would exceed the maximum path length.
+    ///
+    /// The file name is replaced with one derived from the track number and title tags, the
+    /// parenthetical suffix of the album is removed and if necessary file names are truncated.
+    ///
+    /// The source is not modified.
+    ///
+    /// Default: `false`
+    #[arg(long, default_value = None, action = ArgAction::SetTrue)]
+    pub shorten_paths: Option<bool>,
 }
 
 #[injectable]
@@ -46,6 +57,9 @@ impl Options for TargetOptions {
         if self.allow_existing.is_none() {
             self.allow_existing = alternative.allow_existing;
         }
+        if self.shorten_paths.is_none() {
+            self.shorten_paths = alternative.shorten_paths;
+        }
     }
 
     fn apply_defaults(&mut self) {
@@ -59,6 +73,9 @@ impl Options for TargetOptions {
         if self.allow_existing.is_none() {
             self.allow_existing = Some(false);
         }
+        if self.shorten_paths.is_none() {
+            self.shorten_paths = Some(false);
+        }
     }
 
     #[must_use]
@@ -90,6 +107,9 @@ impl Options for TargetOptions {
         if options.allow_existing == Some(false) {
             options.allow_existing = None;
         }
+        if options.shorten_paths == Some(false) {
+            options.shorten_paths = None;
+        }
         Some(options)
     }
     #[allow(clippy::absolute_paths)]
@@ -15,6 +15,19 @@ async fn batch_options_validate() {
     assert!(batch_options.validate());
 }
 
+#[tokio::test]
+async fn client_options_validate() {
+    // Arrange
+    Logger::force_init(PKG_NAME.to_owned());
+    let provider = OptionsProvider::new();
+
+    // Act
+    let client_options = provider.get::<ClientOptions>();
+
+    // Assert
+    assert!(client_options.validate());
+}
+
 #[tokio::test]
 async fn file_options_validate() {
     // Arrange
@@ -28,6 +41,48 @@ async fn file_options_validate() {
     assert!(file_options.validate());
 }
 
+#[tokio::test]
+async fn image_host_options_validate() {
+    // Arrange
+    Logger::force_init(PKG_NAME.to_owned());
+    let provider = OptionsProvider::new();
+
+    // Act
+    let image_host_options = provider.get::<ImageHostOptions>();
+
+    // Assert
+    assert!(image_host_options.validate());
+}
+
+#[tokio::test]
+async fn loudness_options_validate() {
+    // Arrange
+    Logger::force_init(PKG_NAME.to_owned());
+    let provider = OptionsProvider::new();
+
+    // Act
+    let loudness_options = provider.get::<LoudnessOptions>();
+
+    // Assert
+    assert!(loudness_options.validate());
+}
+
+#[tokio::test]
+async fn loudness_options_validate_reference() {
+    // Arrange
+    Logger::force_init(PKG_NAME.to_owned());
+    let options = [-18.0, 6.0, -100.0, f64::NAN].map(|reference| LoudnessOptions {
+        replay_gain_reference: Some(reference),
+        ..LoudnessOptions::default()
+    });
+
+    // Act
+    let results = options.map(|options| options.validate());
+
+    // Assert
+    assert_eq!(results, [true, false, false, false]);
+}
+
 #[tokio::test]
 async fn runner_options_validate() {
     // Arrange
@@ -54,6 +109,32 @@ async fn spectrogram_options_validate() {
     assert!(spectrogram_options.validate());
 }
 
+#[tokio::test]
+async fn naming_options_validate() {
+    // Arrange
+    Logger::force_init(PKG_NAME.to_owned());
+    let provider = OptionsProvider::new();
+
+    // Act
+    let naming_options = provider.get::<NamingOptions>();
+
+    // Assert
+    assert!(naming_options.validate());
+}
+
+#[tokio::test]
+async fn tag_options_validate() {
+    // Arrange
+    Logger::force_init(PKG_NAME.to_owned());
+    let provider = OptionsProvider::new();
+
+    // Act
+    let tag_options = provider.get::<TagOptions>();
+
+    // Assert
+    assert!(tag_options.validate());
+}
+
 #[tokio::test]
 async fn target_options_validate() {
     // Arrange
@@ -1,4 +1,5 @@
 use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
 use std::path::PathBuf;
 
 use clap::{ArgAction, Args};
@@ -7,7 +8,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::cli::ArgumentsParser;
 use crate::cli::CommandArguments::*;
-use crate::options::{Options, OptionsProvider};
+use crate::options::{DoesNotExist, OptionRule, Options, OptionsProvider, TemplateInvalid};
+use crate::upload::Description;
 
 /// Options for including additional files during [`TranscodeCommand`]
 #[derive(Args, Clone, Debug, Default, Deserialize, Serialize)]
@@ -43,6 +45,21 @@ pub struct UploadOptions {
     /// Default: `false`
     #[arg(long, default_value = None, action = ArgAction::SetTrue)]
     pub dry_run: Option<bool>,
+
+    /// Path to a template file of the release description.
+    ///
+    /// Templates use the `minijinja` syntax with access to `app`, `source`, `metadata`,
+    /// `target`, `command`, `details`, `spectrograms`, `tracks` and `tags_supplemented`.
+    ///
+    /// Default: Not set, the built-in description is used
+    #[arg(long)]
+    pub description_template: Option<PathBuf>,
+
+    /// Should the release description be printed instead of uploading?
+    ///
+    /// Default: `false`
+    #[arg(long, default_value = None, action = ArgAction::SetTrue)]
+    pub preview_description: Option<bool>,
 }
 
 #[injectable]
@@ -71,6 +88,13 @@ impl Options for UploadOptions {
         if self.dry_run.is_none() {
             self.dry_run = alternative.dry_run;
         }
+        if self.description_template.is_none() {
+            self.description_template
+                .clone_from(&alternative.description_template);
+        }
+        if self.preview_description.is_none() {
+            self.preview_description = alternative.preview_description;
+        }
     }
 
     fn apply_defaults(&mut self) {
@@ -83,11 +107,29 @@ impl Options for UploadOptions {
         if self.dry_run.is_none() {
             self.dry_run = Some(false);
         }
+        if self.preview_description.is_none() {
+            self.preview_description = Some(false);
+        }
     }
 
     #[must_use]
     fn validate(&self) -> bool {
-        true
+        let mut errors: Vec<OptionRule> = Vec::new();
+        if let Some(path) = &self.description_template {
+            match read_to_string(path) {
+                Ok(template) => {
+                    if let Err(reason) = Description::validate(&template) {
+                        errors.push(TemplateInvalid("Description template".to_owned(), reason));
+                    }
+                }
+                Err(_) => errors.push(DoesNotExist(
+                    "Description template".to_owned(),
+                    path.to_string_lossy().to_string(),
+                )),
+            }
+        }
+        OptionRule::show(&errors);
+        errors.is_empty()
     }
 
     #[must_use]
@@ -108,6 +150,9 @@ impl Options for UploadOptions {
         if options.dry_run == Some(false) {
             options.dry_run = None;
         }
+        if options.preview_description == Some(false) {
+            options.preview_description = None;
+        }
         Some(options)
     }
 
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::cli::ArgumentsParser;
 use crate::cli::CommandArguments::*;
 use crate::options::{Options, OptionsProvider};
+use crate::verify::CheckPolicy;
 
 /// Options for [`VerifyCommand`]
 #[derive(Args, Clone, Debug, Default, Deserialize, Serialize)]
@@ -24,6 +25,23 @@ pub struct VerifyOptions {
     /// Default: None
     #[arg(long)]
     pub exclude_tags: Option<Vec<String>>,
+
+    /// How should sources with a spectrum typical of a lossy source be handled?
+    ///
+    /// Each FLAC is analyzed for a brick-wall cutoff typical of an MP3 or AAC encoder.
+    ///
+    /// Default: `warn`
+    #[arg(long, value_enum)]
+    pub lossy_check: Option<CheckPolicy>,
+
+    /// How should high resolution sources that were padded or upsampled be handled?
+    ///
+    /// Each 24-bit or high sample rate FLAC is analyzed for low-order bits that are never
+    /// used and for an empty spectrum above the Nyquist frequency of a lower sample rate.
+    ///
+    /// Default: `warn`
+    #[arg(long, value_enum)]
+    pub resolution_check: Option<CheckPolicy>,
 }
 
 #[injectable]
@@ -45,6 +63,12 @@ impl Options for VerifyOptions {
         if self.exclude_tags.is_none() {
             self.exclude_tags.clone_from(&alternative.exclude_tags);
         }
+        if self.lossy_check.is_none() {
+            self.lossy_check = alternative.lossy_check;
+        }
+        if self.resolution_check.is_none() {
+            self.resolution_check = alternative.resolution_check;
+        }
     }
 
     fn apply_defaults(&mut self) {
@@ -54,6 +78,12 @@ impl Options for VerifyOptions {
         if self.exclude_tags.is_none() {
             self.exclude_tags = Some(Vec::new());
         }
+        if self.lossy_check.is_none() {
+            self.lossy_check = Some(CheckPolicy::default());
+        }
+        if self.resolution_check.is_none() {
+            self.resolution_check = Some(CheckPolicy::default());
+        }
     }
 
     #[must_use]
@@ -0,0 +1,14 @@
+use crate::torrent_client::TorrentClientKind;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Reference to a source torrent in a torrent client.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ClientReference {
+    /// Torrent client the source was read from
+    pub client: TorrentClientKind,
+    /// Directory the client reported as the save path of the source.
+    ///
+    /// This is used as the content directory instead of searching `content`.
+    pub save_path: PathBuf,
+}
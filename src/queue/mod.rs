@@ -1,3 +1,4 @@
+pub use client_reference::*;
 pub use queue::*;
 pub use queue_add_command::*;
 pub use queue_item::*;
@@ -6,6 +7,7 @@ pub use queue_status::*;
 pub use queue_summary::*;
 pub use timestamp::*;
 
+pub(crate) mod client_reference;
 pub(crate) mod queue;
 pub(crate) mod queue_add_command;
 pub(crate) mod queue_item;
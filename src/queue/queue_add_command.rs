@@ -1,24 +1,28 @@
 use crate::db::Hash;
 use crate::errors::{error, io_error, yaml_error};
 use crate::fs::DirectoryReader;
-use crate::options::{CacheOptions, Options, QueueAddArgs, SharedOptions};
-use crate::queue::{Queue, QueueItem, QueueStatus};
+use crate::imdl::ImdlCommand;
+use crate::options::{CacheOptions, ClientOptions, Options, QueueAddArgs, SharedOptions};
+use crate::queue::{ClientReference, Queue, QueueItem, QueueStatus};
+use crate::torrent_client::TorrentClientFactory;
 use colored::Colorize;
 use di::{injectable, Ref, RefMut};
-use log::{info, trace};
+use log::{debug, info, trace, warn};
 use rogue_logging::Error;
 use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::PathBuf;
 
-/// Add a directory of `.torrent` files to the queue
+/// Add a directory of `.torrent` files, or the completed torrents of a torrent client, to the queue
 #[injectable]
 pub struct QueueAddCommand {
     shared_options: Ref<SharedOptions>,
     cache_options: Ref<CacheOptions>,
+    client_options: Ref<ClientOptions>,
     args: Ref<QueueAddArgs>,
     queue: RefMut<Queue>,
+    client_factory: Ref<TorrentClientFactory>,
 }
 
 impl QueueAddCommand {
@@ -26,15 +30,20 @@ impl QueueAddCommand {
         if !self.shared_options.validate()
             || !self.cache_options.validate()
             || !self.args.validate()
+            || !self.client_options.validate()
         {
             return Ok(false);
         }
-        let path = self
-            .args
-            .queue_add_path
-            .clone()
-            .expect("source should be set");
-        let status = self.execute(path).await?;
+        let status = if self.args.from_client == Some(true) {
+            self.execute_client().await?
+        } else {
+            let path = self
+                .args
+                .queue_add_path
+                .clone()
+                .expect("source should be set");
+            self.execute(path).await?
+        };
         info!("{} {} items to the queue", "Added".bold(), status.added);
         trace!(
             "{} {} items already in the queue",
@@ -95,6 +104,86 @@ impl QueueAddCommand {
         })
     }
 
+    async fn execute_client(&mut self) -> Result<QueueStatus, Error> {
+        let Some(mut client) = self.client_factory.create() else {
+            return Err(error(
+                "add to queue from client",
+                "Client and Client URL must be set".to_owned(),
+            ));
+        };
+        let kind = client.get_kind();
+        let indexer = self
+            .shared_options
+            .indexer
+            .clone()
+            .expect("indexer should be set");
+        let mut queue = self.queue.write().expect("queue should be writeable");
+        let existing = queue.get_all().await?;
+        let torrents = client.get_completed().await?;
+        let found = torrents.len();
+        trace!("{} {found} completed torrents in {kind}", "Found".bold());
+        let torrents: Vec<_> = torrents
+            .into_iter()
+            .filter(|torrent| {
+                Hash::<20>::from_string(&torrent.hash)
+                    .map_or(true, |hash| !existing.contains_key(&hash))
+            })
+            .collect();
+        info!(
+            "{} {} new torrents in {kind}",
+            "Found".bold(),
+            torrents.len()
+        );
+        if torrents.len() > 250 {
+            info!("This may take a while");
+        }
+        let mut items = BTreeMap::new();
+        for torrent in torrents {
+            let buffer = match client.get_torrent_file(&torrent).await {
+                Ok(buffer) => buffer,
+                Err(error) => {
+                    warn!(
+                        "{} to read torrent: {}\n{error}",
+                        "Failed".bold(),
+                        torrent.name
+                    );
+                    continue;
+                }
+            };
+            let summary = match ImdlCommand::show_from_buffer(&buffer).await {
+                Ok(summary) => summary,
+                Err(error) => {
+                    warn!(
+                        "{} to read torrent: {}\n{error}",
+                        "Failed".bold(),
+                        torrent.name
+                    );
+                    continue;
+                }
+            };
+            if !summary.is_source_equal(&indexer) {
+                debug!(
+                    "{} {} as the source is not {indexer}",
+                    "Skipping".bold(),
+                    torrent.name
+                );
+                continue;
+            }
+            let reference = ClientReference {
+                client: kind,
+                save_path: torrent.save_path,
+            };
+            let item = QueueItem::from_client(reference, summary);
+            items.insert(item.hash, item);
+        }
+        let added = queue.set_many(items, false).await?;
+        Ok(QueueStatus {
+            success: true,
+            added,
+            excluded: found - added,
+        })
+    }
+
     async fn execute_file(&mut self, path: PathBuf) -> Result<QueueStatus, Error> {
         trace!("Reading queue file: {}", path.display());
         let file = File::open(path).map_err(|e| io_error(e, "open chunk file"))?;
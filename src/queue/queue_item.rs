@@ -1,5 +1,6 @@
 use crate::db::Hash;
 use crate::imdl::TorrentSummary;
+use crate::queue::ClientReference;
 use crate::source::get_torrent_id_from_torrent_url;
 use crate::spectrogram::SpectrogramStatus;
 use crate::transcode::TranscodeStatus;
@@ -14,7 +15,13 @@ pub struct QueueItem {
     /// Source name
     pub name: String,
     /// Torrent file path
+    ///
+    /// Empty if the source was read from a torrent client.
+    #[serde(default, skip_serializing_if = "is_empty_path")]
     pub path: PathBuf,
+    /// Torrent client the source was read from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client: Option<ClientReference>,
     /// Source info hash
     pub hash: Hash<20>,
     /// Source indexer
@@ -54,6 +61,20 @@ impl QueueItem {
             ..Self::default()
         }
     }
+
+    /// Create a new [`QueueItem`] from a [`TorrentSummary`] of a torrent in a torrent client
+    #[must_use]
+    pub fn from_client(client: ClientReference, torrent: TorrentSummary) -> Self {
+        Self {
+            client: Some(client),
+            ..Self::from_torrent(PathBuf::new(), torrent)
+        }
+    }
+}
+
+#[allow(clippy::ptr_arg)]
+fn is_empty_path(path: &PathBuf) -> bool {
+    path.as_os_str().is_empty()
 }
 
 impl Display for QueueItem {
@@ -68,7 +68,11 @@ impl QueueListCommand {
                 continue;
             };
             info!("{}: {item}", format!("{index:pad$}").bold());
-            debug!("{}", item.path.display());
+            if let Some(client) = &item.client {
+                debug!("{} {}", client.client, client.save_path.display());
+            } else {
+                debug!("{}", item.path.display());
+            }
             debug!("{hash}");
             if let Some(id) = item.id {
                 debug!("{id}");
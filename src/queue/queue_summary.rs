@@ -32,4 +32,6 @@ pub struct QueueSummary {
     pub upload_success_true: usize,
     /// Failed uploads count
     pub upload_success_false: usize,
+    /// Uploaded but not added to the torrent client count
+    pub upload_not_seeding: usize,
 }
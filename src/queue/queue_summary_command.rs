@@ -68,6 +68,13 @@ impl QueueSummaryCommand {
                 Some(UploadStatus { success: true, .. }) => summary.upload_success_true += 1,
                 Some(UploadStatus { success: false, .. }) => summary.upload_success_false += 1,
             };
+            if let Some(UploadStatus {
+                not_seeding: Some(_),
+                ..
+            }) = item.upload
+            {
+                summary.upload_not_seeding += 1;
+            }
         }
         Ok(summary)
     }
@@ -1,6 +1,7 @@
 use super::super::*;
 use crate::db::Hash;
 use crate::imdl::TorrentSummary;
+use crate::torrent_client::TorrentClientKind;
 use std::path::PathBuf;
 
 #[test]
@@ -88,3 +89,29 @@ fn from_torrent_with_invalid_comment() {
     // Assert
     assert!(result.id.is_none());
 }
+
+#[test]
+fn from_client_replaces_path() {
+    // Arrange
+    let reference = ClientReference {
+        client: TorrentClientKind::QBittorrent,
+        save_path: PathBuf::from("/srv/content"),
+    };
+    let torrent = TorrentSummary {
+        name: "Example Torrent".to_owned(),
+        info_hash: "abcdef1234567890abcdef1234567890abcdef12".to_owned(),
+        source: Some("ABC".to_owned()),
+        comment: Some("https://example.com/torrents.php?torrentid=12345".to_owned()),
+        ..TorrentSummary::default()
+    };
+
+    // Act
+    let result = QueueItem::from_client(reference.clone(), torrent);
+    let yaml = serde_yaml::to_string(&result).expect("should serialize");
+
+    // Assert
+    assert_eq!(result.client, Some(reference));
+    assert_eq!(result.id, Some(12345));
+    assert!(!yaml.contains("path: ''"));
+    assert!(yaml.contains("save_path: /srv/content"));
+}
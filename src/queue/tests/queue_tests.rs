@@ -60,6 +60,7 @@ async fn queue_get_unprocessed() -> Result<(), Error> {
                 completed: TimeStamp::now(),
                 formats: None,
                 error: None,
+                failures: None,
             }),
             ..QueueItem::default()
         })
@@ -75,6 +76,7 @@ async fn queue_get_unprocessed() -> Result<(), Error> {
                 completed: TimeStamp::now(),
                 formats: None,
                 error: None,
+                failures: None,
             }),
             ..QueueItem::default()
         })
@@ -90,12 +92,14 @@ async fn queue_get_unprocessed() -> Result<(), Error> {
                 completed: TimeStamp::now(),
                 formats: None,
                 error: None,
+                failures: None,
             }),
             upload: Some(UploadStatus {
                 success: true,
                 completed: TimeStamp::now(),
                 formats: None,
                 errors: None,
+                not_seeding: None,
             }),
             ..QueueItem::default()
         })
@@ -111,12 +115,14 @@ async fn queue_get_unprocessed() -> Result<(), Error> {
                 completed: TimeStamp::now(),
                 formats: None,
                 error: None,
+                failures: None,
             }),
             upload: Some(UploadStatus {
                 success: false,
                 completed: TimeStamp::now(),
                 formats: None,
                 errors: None,
+                not_seeding: None,
             }),
             ..QueueItem::default()
         })
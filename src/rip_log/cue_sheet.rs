@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use rogue_logging::Error;
+
+use crate::rip_log::read_text;
+
+/// A cue sheet describing the layout of a CD.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CueSheet {
+    /// Number of `FILE` entries
+    pub files: usize,
+    /// Number of `TRACK` entries with the `AUDIO` data type
+    pub audio_tracks: usize,
+}
+
+impl CueSheet {
+    /// Read and parse a cue sheet.
+    pub fn read(path: &Path) -> Result<CueSheet, Error> {
+        let text = read_text(path)?;
+        Ok(CueSheet::parse(&text))
+    }
+
+    /// Parse a cue sheet.
+    #[must_use]
+    pub fn parse(text: &str) -> CueSheet {
+        let mut cue = CueSheet::default();
+        for line in text.lines() {
+            let mut words = line.split_whitespace();
+            match words.next() {
+                Some("FILE") => cue.files += 1,
+                Some("TRACK") if words.nth(1) == Some("AUDIO") => cue.audio_tracks += 1,
+                _ => {}
+            }
+        }
+        cue
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt::Write;
+
+use crate::rip_log::Rijndael;
+
+/// Key used by EAC to sign logs.
+const EAC_KEY: [u8; 32] = [
+    0x93, 0x78, 0x71, 0x6c, 0xf1, 0x3e, 0x42, 0x65, 0xae, 0x55, 0x33, 0x8e, 0x94, 0x0b, 0x37, 0x61,
+    0x84, 0xda, 0x38, 0x9e, 0x50, 0x64, 0x77, 0x26, 0xb3, 0x5f, 0x6f, 0x34, 0x1e, 0xe3, 0xef, 0xd9,
+];
+
+const BLOCK_SIZE: usize = 32;
+
+/// Marker preceding the checksum at the end of an EAC log.
+pub const EAC_CHECKSUM_MARKER: &str = "==== Log checksum";
+
+/// Calculate the checksum EAC appends to a log.
+///
+/// `text` is the content of the log before the checksum line.
+///
+/// Line breaks and byte order marks are ignored. The remaining text is encoded as UTF-16
+/// and encrypted with Rijndael-256 in CBC mode with a zero IV. The checksum is the final
+/// cipher block as upper case hex.
+#[must_use]
+pub fn get_eac_checksum(text: &str) -> String {
+    let plaintext: Vec<u8> = text
+        .chars()
+        .filter(|char| !matches!(char, '\r' | '\n' | '\u{feff}' | '\u{fffe}'))
+        .collect::<String>()
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect();
+    let cipher = Rijndael::new(&EAC_KEY, BLOCK_SIZE);
+    let mut signature = [0_u8; BLOCK_SIZE];
+    for chunk in plaintext.chunks(BLOCK_SIZE) {
+        for (byte, value) in signature.iter_mut().zip(chunk) {
+            *byte ^= value;
+        }
+        cipher.encrypt(&mut signature);
+    }
+    signature.iter().fold(String::new(), |mut output, byte| {
+        let _ = write!(output, "{byte:02X}");
+        output
+    })
+}
+
+/// Split an EAC log into the signed text and the checksum.
+///
+/// Returns `None` if the log does not have a checksum.
+#[must_use]
+pub fn split_eac_checksum(text: &str) -> Option<(&str, &str)> {
+    let index = text.rfind(EAC_CHECKSUM_MARKER)?;
+    let (signed, checksum) = text.split_at(index);
+    let checksum = checksum
+        .get(EAC_CHECKSUM_MARKER.len()..)?
+        .split_whitespace()
+        .next()?;
+    Some((signed, checksum))
+}
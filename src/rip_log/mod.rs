@@ -0,0 +1,15 @@
+pub use cue_sheet::*;
+pub use eac_checksum::*;
+pub use rijndael::*;
+pub use rip_log::*;
+pub use ripper::*;
+pub use text_helpers::*;
+
+pub(crate) mod cue_sheet;
+pub(crate) mod eac_checksum;
+pub(crate) mod rijndael;
+pub(crate) mod rip_log;
+pub(crate) mod ripper;
+#[cfg(test)]
+mod tests;
+pub(crate) mod text_helpers;
@@ -0,0 +1,166 @@
+/// Rijndael block cipher with a 256-bit key.
+///
+/// Unlike AES the block size may be 128 or 256 bits.
+pub struct Rijndael {
+    /// Number of 32-bit columns in a block
+    columns: usize,
+    round_keys: Vec<[u8; 4]>,
+    sbox: [u8; 256],
+}
+
+const KEY_WORDS: usize = 8;
+
+const ROUNDS: usize = 14;
+
+impl Rijndael {
+    /// Create a [`Rijndael`] cipher.
+    ///
+    /// `block_size` is in bytes and must be `16` or `32`.
+    #[must_use]
+    #[allow(clippy::integer_division)]
+    pub fn new(key: &[u8; 32], block_size: usize) -> Self {
+        assert!(block_size == 16 || block_size == 32, "invalid block size");
+        let columns = block_size / 4;
+        let sbox = get_sbox();
+        Self {
+            columns,
+            round_keys: expand_key(key, columns, &sbox),
+            sbox,
+        }
+    }
+
+    /// Encrypt a single block in place.
+    pub fn encrypt(&self, block: &mut [u8]) {
+        assert_eq!(block.len(), self.columns * 4, "invalid block length");
+        self.add_round_key(block, 0);
+        for round in 1..ROUNDS {
+            self.sub_bytes(block);
+            self.shift_rows(block);
+            mix_columns(block);
+            self.add_round_key(block, round);
+        }
+        self.sub_bytes(block);
+        self.shift_rows(block);
+        self.add_round_key(block, ROUNDS);
+    }
+
+    #[allow(clippy::indexing_slicing)]
+    fn sub_bytes(&self, block: &mut [u8]) {
+        for byte in block {
+            *byte = self.sbox[usize::from(*byte)];
+        }
+    }
+
+    fn add_round_key(&self, block: &mut [u8], round: usize) {
+        let keys = self
+            .round_keys
+            .iter()
+            .skip(round * self.columns)
+            .flat_map(|word| word.iter());
+        for (byte, key) in block.iter_mut().zip(keys) {
+            *byte ^= key;
+        }
+    }
+
+    fn shift_rows(&self, block: &mut [u8]) {
+        let offsets: [usize; 4] = if self.columns == 8 {
+            [0, 1, 3, 4]
+        } else {
+            [0, 1, 2, 3]
+        };
+        let state = block.to_vec();
+        for (row, offset) in offsets.iter().enumerate() {
+            for column in 0..self.columns {
+                let source = row + 4 * ((column + offset) % self.columns);
+                if let (Some(target), Some(value)) =
+                    (block.get_mut(row + 4 * column), state.get(source))
+                {
+                    *target = *value;
+                }
+            }
+        }
+    }
+}
+
+#[allow(clippy::indexing_slicing)]
+fn expand_key(key: &[u8; 32], columns: usize, sbox: &[u8; 256]) -> Vec<[u8; 4]> {
+    let total = columns * (ROUNDS + 1);
+    let mut words: Vec<[u8; 4]> = key
+        .chunks(4)
+        .map(|chunk| [chunk[0], chunk[1], chunk[2], chunk[3]])
+        .collect();
+    let mut round_constant: u8 = 1;
+    for index in KEY_WORDS..total {
+        let mut word = words[index - 1];
+        if index % KEY_WORDS == 0 {
+            word.rotate_left(1);
+            for byte in &mut word {
+                *byte = sbox[usize::from(*byte)];
+            }
+            word[0] ^= round_constant;
+            round_constant = multiply(round_constant, 2);
+        } else if index % KEY_WORDS == 4 {
+            for byte in &mut word {
+                *byte = sbox[usize::from(*byte)];
+            }
+        }
+        let previous = words[index - KEY_WORDS];
+        words.push([
+            word[0] ^ previous[0],
+            word[1] ^ previous[1],
+            word[2] ^ previous[2],
+            word[3] ^ previous[3],
+        ]);
+    }
+    words
+}
+
+#[allow(clippy::indexing_slicing)]
+fn mix_columns(block: &mut [u8]) {
+    for column in block.chunks_mut(4) {
+        let [a, b, c, d] = [column[0], column[1], column[2], column[3]];
+        column[0] = multiply(a, 2) ^ multiply(b, 3) ^ c ^ d;
+        column[1] = a ^ multiply(b, 2) ^ multiply(c, 3) ^ d;
+        column[2] = a ^ b ^ multiply(c, 2) ^ multiply(d, 3);
+        column[3] = multiply(a, 3) ^ b ^ c ^ multiply(d, 2);
+    }
+}
+
+/// Multiply in the Rijndael finite field.
+fn multiply(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= 0x1B;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+/// Rijndael S-box computed from the multiplicative inverse and affine transformation.
+fn get_sbox() -> [u8; 256] {
+    let mut sbox = [0; 256];
+    for (byte, value) in (0..=u8::MAX).zip(sbox.iter_mut()) {
+        // a^254 is the multiplicative inverse of a, and 0 maps to 0
+        let mut inverse = 0;
+        if byte != 0 {
+            inverse = 1;
+            for _ in 0..254 {
+                inverse = multiply(inverse, byte);
+            }
+        }
+        *value = inverse
+            ^ inverse.rotate_left(1)
+            ^ inverse.rotate_left(2)
+            ^ inverse.rotate_left(3)
+            ^ inverse.rotate_left(4)
+            ^ 0x63;
+    }
+    sbox
+}
@@ -0,0 +1,166 @@
+use std::path::Path;
+
+use regex::Regex;
+use rogue_logging::Error;
+
+use crate::rip_log::*;
+
+/// Marker of the signature at the end of an XLD log.
+const XLD_SIGNATURE_MARKER: &str = "-----BEGIN XLD SIGNATURE-----";
+
+/// Validity of the checksum appended to a rip log.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LogChecksum {
+    /// The log does not have a checksum
+    Missing,
+    /// The checksum matches the content of the log
+    Valid,
+    /// The checksum does not match so the log has been edited
+    Invalid,
+    /// The log has a checksum but it can't be validated
+    Unverified,
+}
+
+/// A single track of a [`RipLog`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RipLogTrack {
+    pub number: u32,
+    /// CRC of the test pass
+    pub test_crc: Option<String>,
+    /// CRC of the copy pass
+    pub copy_crc: Option<String>,
+    /// `AccurateRip` result
+    ///
+    /// `None` if the track was not present in the `AccurateRip` database.
+    pub accurate: Option<bool>,
+}
+
+impl RipLogTrack {
+    /// Do the test and copy CRCs differ?
+    #[must_use]
+    pub fn is_crc_mismatch(&self) -> bool {
+        match (&self.test_crc, &self.copy_crc) {
+            (Some(test), Some(copy)) => !test.eq_ignore_ascii_case(copy),
+            _ => false,
+        }
+    }
+}
+
+/// A log written by EAC or XLD when ripping a CD.
+#[derive(Clone, Debug)]
+pub struct RipLog {
+    pub ripper: Ripper,
+    /// EAC read mode or XLD ripper mode
+    pub read_mode: Option<String>,
+    pub checksum: LogChecksum,
+    pub tracks: Vec<RipLogTrack>,
+}
+
+impl RipLog {
+    /// Read and parse a rip log.
+    ///
+    /// Returns `None` if the file is not an EAC or XLD log.
+    pub fn read(path: &Path) -> Result<Option<RipLog>, Error> {
+        let text = read_text(path)?;
+        Ok(RipLog::parse(&text))
+    }
+
+    /// Parse a rip log.
+    ///
+    /// Returns `None` if the text is not an EAC or XLD log.
+    #[must_use]
+    pub fn parse(text: &str) -> Option<RipLog> {
+        let ripper = Ripper::from_header(text)?;
+        let track_heading = Regex::new(r"^Track\s+(\d+)$").expect("regex should compile");
+        let mut read_mode = None;
+        let mut tracks: Vec<RipLogTrack> = Vec::new();
+        for line in text.lines() {
+            let line = line.trim();
+            if let Some(captures) = track_heading.captures(line) {
+                tracks.push(RipLogTrack {
+                    number: captures
+                        .get(1)
+                        .and_then(|x| x.as_str().parse().ok())
+                        .unwrap_or_default(),
+                    ..RipLogTrack::default()
+                });
+                continue;
+            }
+            if read_mode.is_none() {
+                if let Some(value) = get_value(line, "Read mode").or(get_value(line, "Ripper mode"))
+                {
+                    read_mode = Some(value.to_owned());
+                }
+            }
+            let Some(track) = tracks.last_mut() else {
+                continue;
+            };
+            match ripper {
+                Ripper::Eac => parse_eac_track_line(track, line),
+                Ripper::Xld => parse_xld_track_line(track, line),
+            }
+        }
+        Some(RipLog {
+            ripper,
+            read_mode,
+            checksum: get_checksum(ripper, text),
+            tracks,
+        })
+    }
+
+    /// Was the CD ripped with a secure read mode?
+    #[must_use]
+    pub fn is_secure(&self) -> bool {
+        let Some(read_mode) = &self.read_mode else {
+            return false;
+        };
+        match self.ripper {
+            Ripper::Eac => read_mode.starts_with("Secure"),
+            Ripper::Xld => {
+                read_mode.contains("XLD Secure Ripper") || read_mode.contains("CDParanoia")
+            }
+        }
+    }
+}
+
+fn parse_eac_track_line(track: &mut RipLogTrack, line: &str) {
+    if let Some(crc) = line.strip_prefix("Test CRC") {
+        track.test_crc = crc.split_whitespace().next().map(ToOwned::to_owned);
+    } else if let Some(crc) = line.strip_prefix("Copy CRC") {
+        track.copy_crc = crc.split_whitespace().next().map(ToOwned::to_owned);
+    } else if line.starts_with("Accurately ripped") {
+        track.accurate = Some(true);
+    } else if line.starts_with("Cannot be verified as accurate") {
+        track.accurate = Some(false);
+    }
+}
+
+fn parse_xld_track_line(track: &mut RipLogTrack, line: &str) {
+    if let Some(crc) = get_value(line, "CRC32 hash (test run)") {
+        track.test_crc = Some(crc.to_owned());
+    } else if let Some(crc) = get_value(line, "CRC32 hash") {
+        track.copy_crc = Some(crc.to_owned());
+    } else if line.starts_with("->Accurately ripped") {
+        track.accurate = Some(true);
+    } else if line.starts_with("->Rip may not be accurate") && track.accurate.is_none() {
+        track.accurate = Some(false);
+    }
+}
+
+/// Get the value of a `key : value` line.
+fn get_value<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let value = line.strip_prefix(key)?.trim_start().strip_prefix(':')?;
+    Some(value.trim())
+}
+
+fn get_checksum(ripper: Ripper, text: &str) -> LogChecksum {
+    match ripper {
+        Ripper::Eac => match split_eac_checksum(text) {
+            Some((signed, checksum)) if get_eac_checksum(signed) == checksum => LogChecksum::Valid,
+            Some(_) => LogChecksum::Invalid,
+            None => LogChecksum::Missing,
+        },
+        Ripper::Xld if text.contains(XLD_SIGNATURE_MARKER) => LogChecksum::Unverified,
+        Ripper::Xld => LogChecksum::Missing,
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Software used to rip a CD.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Ripper {
+    /// Exact Audio Copy
+    Eac,
+    /// X Lossless Decoder
+    Xld,
+}
+
+impl Ripper {
+    /// Identify the ripper from the first lines of a log.
+    #[must_use]
+    pub fn from_header(text: &str) -> Option<Ripper> {
+        let header: String = text.lines().take(2).collect();
+        if header.contains("Exact Audio Copy") || header.contains("EAC extraction logfile") {
+            Some(Ripper::Eac)
+        } else if header.contains("X Lossless Decoder") {
+            Some(Ripper::Xld)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Ripper {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let output = match self {
+            Ripper::Eac => "EAC",
+            Ripper::Xld => "XLD",
+        };
+        output.fmt(formatter)
+    }
+}
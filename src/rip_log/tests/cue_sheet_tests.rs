@@ -0,0 +1,25 @@
+use crate::rip_log::CueSheet;
+
+#[test]
+fn cue_sheet_parse() {
+    // Arrange
+    let text = r#"REM GENRE Rock
+PERFORMER "Artist"
+TITLE "Album"
+FILE "01 - First.flac" WAVE
+  TRACK 01 AUDIO
+    INDEX 01 00:00:00
+FILE "02 - Second.flac" WAVE
+  TRACK 02 AUDIO
+    INDEX 01 00:00:00
+  TRACK 03 MODE1/2352
+    INDEX 01 04:00:00
+"#;
+
+    // Act
+    let cue = CueSheet::parse(text);
+
+    // Assert
+    assert_eq!(cue.files, 2);
+    assert_eq!(cue.audio_tracks, 2);
+}
@@ -0,0 +1,54 @@
+use crate::rip_log::{get_eac_checksum, split_eac_checksum};
+
+#[test]
+fn get_eac_checksum_ignores_line_breaks() {
+    // Arrange
+    let unix = "Exact Audio Copy V1.6\n\nRead mode : Secure\n";
+    let windows = "\u{feff}Exact Audio Copy V1.6\r\n\r\nRead mode : Secure\r\n";
+
+    // Act
+    let unix = get_eac_checksum(unix);
+    let windows = get_eac_checksum(windows);
+
+    // Assert
+    assert_eq!(unix.len(), 64);
+    assert_eq!(unix, windows);
+}
+
+#[test]
+fn get_eac_checksum_detects_edits() {
+    // Arrange
+    let original = "Read mode : Secure";
+    let edited = "Read mode : Burst";
+
+    // Act
+    let original = get_eac_checksum(original);
+    let edited = get_eac_checksum(edited);
+
+    // Assert
+    assert_ne!(original, edited);
+}
+
+#[test]
+fn split_eac_checksum_with_checksum() {
+    // Arrange
+    let text = "Exact Audio Copy\r\n\r\n==== Log checksum ABCDEF ====\r\n";
+
+    // Act
+    let split = split_eac_checksum(text);
+
+    // Assert
+    assert_eq!(split, Some(("Exact Audio Copy\r\n\r\n", "ABCDEF")));
+}
+
+#[test]
+fn split_eac_checksum_without_checksum() {
+    // Arrange
+    let text = "Exact Audio Copy\r\n\r\nEnd of status report\r\n";
+
+    // Act
+    let split = split_eac_checksum(text);
+
+    // Assert
+    assert_eq!(split, None);
+}
@@ -0,0 +1,4 @@
+mod cue_sheet_tests;
+mod eac_checksum_tests;
+mod rijndael_tests;
+mod rip_log_tests;
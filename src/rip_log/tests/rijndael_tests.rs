@@ -0,0 +1,40 @@
+use std::array::from_fn;
+use std::fmt::Write;
+
+use crate::rip_log::Rijndael;
+
+#[test]
+fn rijndael_aes_256_vector() {
+    // Arrange
+    // FIPS-197 Appendix C.3
+    let key: [u8; 32] = from_fn(|index| u8::try_from(index).expect("should fit"));
+    let mut block: Vec<u8> = (0..16_u8).map(|index| index * 0x11).collect();
+    let cipher = Rijndael::new(&key, 16);
+
+    // Act
+    cipher.encrypt(&mut block);
+
+    // Assert
+    let hex = block.iter().fold(String::new(), |mut hex, byte| {
+        write!(hex, "{byte:02x}").expect("should write");
+        hex
+    });
+    assert_eq!(hex, "8ea2b7ca516745bfeafc49904b496089");
+}
+
+#[test]
+fn rijndael_256_block_changes_every_byte() {
+    // Arrange
+    let cipher = Rijndael::new(&[0; 32], 32);
+    let mut first = [0_u8; 32];
+    let mut second = [0_u8; 32];
+    second[31] = 1;
+
+    // Act
+    cipher.encrypt(&mut first);
+    cipher.encrypt(&mut second);
+
+    // Assert
+    let changed = first.iter().zip(second).filter(|(a, b)| **a != *b).count();
+    assert!(changed > 24, "only {changed} bytes changed");
+}
@@ -0,0 +1,136 @@
+use crate::rip_log::*;
+
+const EAC_LOG: &str = "Exact Audio Copy V1.6 from 23. October 2020
+
+EAC extraction logfile from 1. January 2024, 12:00
+
+Artist / Album
+
+Used drive  : PLEXTOR DVDR   PX-716A   Adapter: 0  ID: 1
+
+Read mode               : Secure
+Utilize accurate stream : Yes
+
+Track  1
+
+     Filename C:\\Rips\\01 - First.wav
+
+     Peak level 98.8 %
+     Test CRC 1A2B3C4D
+     Copy CRC 1A2B3C4D
+     Accurately ripped (confidence 5)  [ABCDEF01]  (AR v2)
+     Copy OK
+
+Track  2
+
+     Filename C:\\Rips\\02 - Second.wav
+
+     Peak level 100.0 %
+     Test CRC 11111111
+     Copy CRC 22222222
+     Cannot be verified as accurate (confidence 3)  [12345678], AccurateRip returned [87654321]  (AR v2)
+     Copy finished
+
+Track  1  accurately ripped (confidence 5)  [ABCDEF01]  (AR v2)
+
+End of status report
+";
+
+const XLD_LOG: &str = "X Lossless Decoder version 20230627 (155.2)
+
+XLD extraction logfile from 2024-01-01 12:00:00 +0000
+
+Ripper mode             : XLD Secure Ripper
+
+AccurateRip Summary (DiscID: 00000000-00000000-00000000)
+    Track 01 : OK (A1, v1+v2, confidence 4+57/65)
+
+Track 01
+    Filename : /Rips/01 - First.aiff
+    CRC32 hash (test run)  : 1A2B3C4D
+    CRC32 hash             : 1A2B3C4D
+    CRC32 hash (skip zero) : 5E6F7A8B
+    AccurateRip v1 signature : 3D0D5F8F
+        ->Rip may not be accurate.
+    AccurateRip v2 signature : 4E0D5F8F
+        ->Accurately ripped (v2, confidence 57/65)
+
+No errors occurred
+
+End of status report
+";
+
+#[test]
+fn rip_log_parse_eac() {
+    // Arrange
+    let text = EAC_LOG;
+
+    // Act
+    let log = RipLog::parse(text).expect("should be a rip log");
+
+    // Assert
+    assert_eq!(log.ripper, Ripper::Eac);
+    assert_eq!(log.read_mode, Some("Secure".to_owned()));
+    assert!(log.is_secure());
+    assert_eq!(log.checksum, LogChecksum::Missing);
+    assert_eq!(log.tracks.len(), 2);
+    let first = log.tracks.first().expect("should have first track");
+    assert_eq!(first.number, 1);
+    assert_eq!(first.accurate, Some(true));
+    assert!(!first.is_crc_mismatch());
+    let second = log.tracks.get(1).expect("should have second track");
+    assert_eq!(second.accurate, Some(false));
+    assert!(second.is_crc_mismatch());
+}
+
+#[test]
+fn rip_log_parse_eac_checksum() {
+    // Arrange
+    let checksum = get_eac_checksum(EAC_LOG);
+    let valid = format!("{EAC_LOG}\n==== Log checksum {checksum} ====\n");
+    let edited = valid.replace(
+        "Read mode               : Secure",
+        "Read mode               : Burst",
+    );
+
+    // Act
+    let valid = RipLog::parse(&valid).expect("should be a rip log");
+    let edited = RipLog::parse(&edited).expect("should be a rip log");
+
+    // Assert
+    assert_eq!(valid.checksum, LogChecksum::Valid);
+    assert_eq!(edited.checksum, LogChecksum::Invalid);
+    assert!(!edited.is_secure());
+}
+
+#[test]
+fn rip_log_parse_xld() {
+    // Arrange
+    let text =
+        format!("{XLD_LOG}\n-----BEGIN XLD SIGNATURE-----\nABC\n-----END XLD SIGNATURE-----\n");
+
+    // Act
+    let log = RipLog::parse(&text).expect("should be a rip log");
+
+    // Assert
+    assert_eq!(log.ripper, Ripper::Xld);
+    assert!(log.is_secure());
+    assert_eq!(log.checksum, LogChecksum::Unverified);
+    assert_eq!(log.tracks.len(), 1);
+    let track = log.tracks.first().expect("should have track");
+    assert_eq!(track.test_crc, Some("1A2B3C4D".to_owned()));
+    assert_eq!(track.copy_crc, Some("1A2B3C4D".to_owned()));
+    assert_eq!(track.accurate, Some(true));
+}
+
+#[test]
+fn rip_log_parse_other() {
+    // Arrange
+    let text = "Some other log file";
+
+    // Act
+    let log = RipLog::parse(text);
+
+    // Assert
+    assert!(log.is_none());
+}
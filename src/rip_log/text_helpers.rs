@@ -0,0 +1,39 @@
+use std::fs::read;
+use std::path::Path;
+
+use rogue_logging::Error;
+
+use crate::errors::io_error;
+
+/// Read a text file that may be UTF-16 or UTF-8 encoded.
+///
+/// EAC writes logs as UTF-16 with a byte order mark whereas XLD writes UTF-8.
+pub fn read_text(path: &Path) -> Result<String, Error> {
+    let bytes = read(path).map_err(|e| io_error(e, "read text file"))?;
+    Ok(decode_text(&bytes))
+}
+
+/// Decode text that may be UTF-16 or UTF-8 encoded.
+///
+/// The byte order mark is removed.
+#[must_use]
+pub fn decode_text(bytes: &[u8]) -> String {
+    if let Some(bytes) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .filter_map(|pair| <[u8; 2]>::try_from(pair).ok())
+            .map(u16::from_le_bytes)
+            .collect();
+        String::from_utf16_lossy(&units)
+    } else if let Some(bytes) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .filter_map(|pair| <[u8; 2]>::try_from(pair).ok())
+            .map(u16::from_be_bytes)
+            .collect();
+        String::from_utf16_lossy(&units)
+    } else {
+        let bytes = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+        String::from_utf8_lossy(bytes).to_string()
+    }
+}
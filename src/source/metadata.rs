@@ -1,54 +1,134 @@
 use html_escape::decode_html_entities;
 
 use crate::naming::join_humanized;
-use gazelle_api::{Group, Torrent};
+use crate::source::ReleaseType;
+use gazelle_api::{Artist, Group, Torrent};
+use serde::Serialize;
 
-#[derive(Clone, Debug)]
+/// Release details of a source derived from the API.
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct Metadata {
+    /// Display name of the artists.
+    ///
+    /// Includes composers, guests and conductors, or a placeholder such as `Various Artists`.
     pub artist: String,
+    /// Names of the main artists.
+    pub artists: Vec<String>,
+    /// Names of the composers.
+    pub composers: Vec<String>,
     pub album: String,
     pub remaster_title: String,
+    /// Year of the edition, or the original year if the edition has no year.
     pub year: u16,
+    /// Year of the original release.
+    pub original_year: u16,
     pub media: String,
+    /// Record label of the edition, or the original record label if the edition has none.
+    pub label: String,
+    /// Catalogue number of the edition, or the original catalogue number if the edition has none.
+    pub catalogue_number: String,
+    /// Record label of the edition.
+    pub remaster_record_label: String,
+    /// Catalogue number of the edition.
+    pub remaster_catalogue_number: String,
+    pub release_type: ReleaseType,
 }
 
 impl Metadata {
     #[must_use]
     pub fn new(group: &Group, torrent: &Torrent) -> Self {
+        let music_info = group.music_info.clone().unwrap_or_default();
         Metadata {
             artist: get_artist(group).unwrap_or("Unknown Artist".to_owned()),
-            album: get_album(group),
-            remaster_title: get_remaster_title(torrent),
+            artists: get_names(&music_info.artists),
+            composers: get_names(&music_info.composers),
+            album: decode(&group.name),
+            remaster_title: decode(&torrent.remaster_title),
             year: get_year(group, torrent),
+            original_year: group.year,
             media: torrent.media.clone(),
+            label: get_label(group, torrent),
+            catalogue_number: get_catalogue_number(group, torrent),
+            remaster_record_label: decode(&torrent.remaster_record_label),
+            remaster_catalogue_number: decode(&torrent.remaster_catalogue_number),
+            release_type: ReleaseType::from_id(group.release_type),
         }
     }
 }
 
+/// Get the display name of the artists following the roles of the API.
+///
+/// - DJs take precedence if there are no more than two main artists.
+/// - Composers precede the main artists: `Composer performed by Artist`.
+/// - Guests follow the main artists: `Artist with Guest`.
+/// - Conductors follow last: `Artist under Conductor`.
+///
+/// More than two artists of a role are replaced by a placeholder such as `Various Artists`.
 fn get_artist(group: &Group) -> Option<String> {
-    let info = group.music_info.clone()?;
-    let artists = if !info.artists.is_empty() && info.artists.len() < 3 {
-        info.artists
-    } else if info.dj.len() == 1 {
-        info.dj
-    } else if info.artists.is_empty() {
-        return None;
+    let info = group.music_info.as_ref()?;
+    let artists = get_names(&info.artists);
+    let composers = get_names(&info.composers);
+    let conductors = get_names(&info.conductor);
+    let guests = get_names(&info.with);
+    let djs = get_names(&info.dj);
+    if !djs.is_empty() && (artists.is_empty() || artists.len() > 2) {
+        return Some(join_or_various(djs, "Various DJs"));
+    }
+    let mut artist = if artists.is_empty() {
+        if composers.is_empty() {
+            return None;
+        }
+        join_or_various(composers, "Various Composers")
     } else {
-        return Some("Various Artists".to_owned());
+        let performers = artists.len();
+        let mut artist = join_or_various(artists, "Various Artists");
+        if !guests.is_empty() && guests.len() < 3 && performers < 3 {
+            artist = format!("{artist} with {}", join_humanized(guests));
+        }
+        if !composers.is_empty() && composers.len() < 3 {
+            artist = format!("{} performed by {artist}", join_humanized(composers));
+        }
+        artist
     };
-    let artists: Vec<String> = artists
-        .into_iter()
-        .map(|x| decode_html_entities(&x.name).to_string())
-        .collect();
-    Some(join_humanized(artists))
+    if !conductors.is_empty() {
+        artist = format!(
+            "{artist} under {}",
+            join_or_various(conductors, "Various Conductors")
+        );
+    }
+    Some(artist)
+}
+
+fn get_names(artists: &[Artist]) -> Vec<String> {
+    artists.iter().map(|x| decode(&x.name)).collect()
+}
+
+fn join_or_various(names: Vec<String>, placeholder: &str) -> String {
+    if names.len() > 2 {
+        placeholder.to_owned()
+    } else {
+        join_humanized(names)
+    }
+}
+
+fn decode(value: &str) -> String {
+    decode_html_entities(value).to_string()
 }
 
-fn get_album(group: &Group) -> String {
-    decode_html_entities(&group.name).to_string()
+fn get_label(group: &Group, torrent: &Torrent) -> String {
+    if torrent.remaster_record_label.is_empty() {
+        decode(&group.record_label)
+    } else {
+        decode(&torrent.remaster_record_label)
+    }
 }
 
-fn get_remaster_title(torrent: &Torrent) -> String {
-    decode_html_entities(&torrent.remaster_title).to_string()
+fn get_catalogue_number(group: &Group, torrent: &Torrent) -> String {
+    if torrent.remaster_catalogue_number.is_empty() {
+        decode(&group.catalogue_number)
+    } else {
+        decode(&torrent.remaster_catalogue_number)
+    }
 }
 
 fn get_year(group: &Group, torrent: &Torrent) -> u16 {
@@ -62,7 +142,8 @@ fn get_year(group: &Group, torrent: &Torrent) -> u16 {
 #[cfg(test)]
 mod tests {
     use crate::source::metadata::get_artist;
-    use gazelle_api::{Artist, Group, MusicInfo};
+    use crate::source::{Metadata, ReleaseType};
+    use gazelle_api::{Artist, Group, MusicInfo, Torrent};
 
     #[test]
     fn get_artist_none() {
@@ -199,4 +280,104 @@ mod tests {
         // Assert
         assert_eq!(artist, Some(expected));
     }
+
+    #[test]
+    fn get_artist_composer() {
+        // Arrange
+        let expected = "Composer One performed by Artist One".to_owned();
+        let group = Group {
+            music_info: Some(MusicInfo {
+                artists: to_artists(&["Artist One"]),
+                composers: to_artists(&["Composer One"]),
+                ..MusicInfo::default()
+            }),
+            ..Group::default()
+        };
+
+        // Act
+        let artist = get_artist(&group);
+
+        // Assert
+        assert_eq!(artist, Some(expected));
+    }
+
+    #[test]
+    fn get_artist_guest_and_conductor() {
+        // Arrange
+        let expected = "Artist One with Guest One & Guest Two under Conductor One".to_owned();
+        let group = Group {
+            music_info: Some(MusicInfo {
+                artists: to_artists(&["Artist One"]),
+                with: to_artists(&["Guest One", "Guest Two"]),
+                conductor: to_artists(&["Conductor One"]),
+                ..MusicInfo::default()
+            }),
+            ..Group::default()
+        };
+
+        // Act
+        let artist = get_artist(&group);
+
+        // Assert
+        assert_eq!(artist, Some(expected));
+    }
+
+    #[test]
+    fn get_artist_various_composers() {
+        // Arrange
+        let expected = "Various Composers".to_owned();
+        let group = Group {
+            music_info: Some(MusicInfo {
+                composers: to_artists(&["Composer One", "Composer Two", "Composer Three"]),
+                ..MusicInfo::default()
+            }),
+            ..Group::default()
+        };
+
+        // Act
+        let artist = get_artist(&group);
+
+        // Assert
+        assert_eq!(artist, Some(expected));
+    }
+
+    #[test]
+    fn metadata_new_edition() {
+        // Arrange
+        let group = Group {
+            name: "Album &amp; More".to_owned(),
+            year: 1990,
+            record_label: "Original Label".to_owned(),
+            catalogue_number: "ORIG-1".to_owned(),
+            release_type: 5,
+            ..Group::default()
+        };
+        let torrent = Torrent {
+            remaster_year: Some(2010),
+            remaster_catalogue_number: "REM-1".to_owned(),
+            ..Torrent::default()
+        };
+
+        // Act
+        let metadata = Metadata::new(&group, &torrent);
+
+        // Assert
+        assert_eq!(metadata.album, "Album & More");
+        assert_eq!(metadata.year, 2010);
+        assert_eq!(metadata.original_year, 1990);
+        assert_eq!(metadata.label, "Original Label");
+        assert_eq!(metadata.remaster_record_label, "");
+        assert_eq!(metadata.catalogue_number, "REM-1");
+        assert_eq!(metadata.release_type, ReleaseType::Ep);
+    }
+
+    fn to_artists(names: &[&str]) -> Vec<Artist> {
+        names
+            .iter()
+            .map(|name| Artist {
+                id: 12345,
+                name: (*name).to_owned(),
+            })
+            .collect()
+    }
 }
@@ -1,5 +1,6 @@
 pub use id_provider::*;
 pub use metadata::*;
+pub use release_type::*;
 pub use source::*;
 pub use source_issue::*;
 pub use source_provider::*;
@@ -7,6 +8,7 @@ pub use url_helpers::*;
 
 pub(crate) mod id_provider;
 pub(crate) mod metadata;
+pub(crate) mod release_type;
 pub(crate) mod source;
 pub(crate) mod source_provider;
 #[cfg(test)]
@@ -0,0 +1,82 @@
+use serde::{Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use ReleaseType::*;
+
+/// Release type of a torrent group.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ReleaseType {
+    Album,
+    Soundtrack,
+    Ep,
+    Anthology,
+    Compilation,
+    Single,
+    LiveAlbum,
+    Remix,
+    Bootleg,
+    Interview,
+    Mixtape,
+    Demo,
+    ConcertRecording,
+    DjMix,
+    #[default]
+    Unknown,
+}
+
+impl ReleaseType {
+    /// Get the [`ReleaseType`] from the id used by the API.
+    #[must_use]
+    pub fn from_id(id: u8) -> Self {
+        match id {
+            1 => Album,
+            3 => Soundtrack,
+            5 => Ep,
+            6 => Anthology,
+            7 => Compilation,
+            9 => Single,
+            11 => LiveAlbum,
+            13 => Remix,
+            14 => Bootleg,
+            15 => Interview,
+            16 => Mixtape,
+            17 => Demo,
+            18 => ConcertRecording,
+            19 => DjMix,
+            _ => Unknown,
+        }
+    }
+
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        match self {
+            Album => "Album",
+            Soundtrack => "Soundtrack",
+            Ep => "EP",
+            Anthology => "Anthology",
+            Compilation => "Compilation",
+            Single => "Single",
+            LiveAlbum => "Live album",
+            Remix => "Remix",
+            Bootleg => "Bootleg",
+            Interview => "Interview",
+            Mixtape => "Mixtape",
+            Demo => "Demo",
+            ConcertRecording => "Concert Recording",
+            DjMix => "DJ Mix",
+            Unknown => "Unknown",
+        }
+    }
+}
+
+impl Display for ReleaseType {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{}", self.get_name())
+    }
+}
+
+impl Serialize for ReleaseType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.get_name())
+    }
+}
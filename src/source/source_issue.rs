@@ -10,6 +10,7 @@ use std::path::PathBuf;
 pub const MAX_PATH_LENGTH: isize = 180;
 pub const MIN_BIT_RATE_KBPS: u32 = 192;
 pub const MAX_DURATION: u32 = 12 * 60 * 60;
+pub const MIN_PEAK_DB: f64 = -20.0;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case", tag = "type")]
@@ -85,6 +86,60 @@ pub enum SourceIssue {
         path: PathBuf,
         count: u32,
     },
+    LossySource {
+        path: PathBuf,
+        cutoff: u32,
+    },
+    PaddedBitDepth {
+        path: PathBuf,
+        bits: u32,
+        effective: u32,
+    },
+    Upsampled {
+        path: PathBuf,
+        rate: u32,
+        original: u32,
+    },
+    Clipping {
+        path: PathBuf,
+        samples: u64,
+    },
+    LogError {
+        path: PathBuf,
+        error: String,
+    },
+    LogChecksumMissing {
+        path: PathBuf,
+    },
+    LogChecksumInvalid {
+        path: PathBuf,
+    },
+    ReadMode {
+        path: PathBuf,
+        mode: String,
+    },
+    AccurateRip {
+        path: PathBuf,
+        tracks: Vec<u32>,
+    },
+    CrcMismatch {
+        path: PathBuf,
+        tracks: Vec<u32>,
+    },
+    LogTrackCount {
+        path: PathBuf,
+        expected: usize,
+        actual: usize,
+    },
+    CueTrackCount {
+        path: PathBuf,
+        expected: usize,
+        actual: usize,
+    },
+    LowPeak {
+        path: PathBuf,
+        peak: f64,
+    },
     Error {
         domain: String,
         details: String,
@@ -93,7 +148,7 @@ pub enum SourceIssue {
 }
 
 impl Display for SourceIssue {
-    #[allow(clippy::absolute_paths)]
+    #[allow(clippy::absolute_paths, clippy::too_many_lines)]
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         let message = match self {
             IdError { details } => format!("Invalid source id: {details}"),
@@ -164,6 +219,80 @@ impl Display for SourceIssue {
             Channels { path, count } => {
                 format!("Too many channels: {count}: {}", path.display())
             }
+            LossySource { path, cutoff } => {
+                let khz = f64::from(*cutoff) / 1000.0;
+                format!(
+                    "Spectrum has a {khz:.1} kHz cutoff typical of a lossy source: {}",
+                    path.display()
+                )
+            }
+            PaddedBitDepth {
+                path,
+                bits,
+                effective,
+            } => format!(
+                "Only {effective} of {bits} bits are used: {}",
+                path.display()
+            ),
+            Upsampled {
+                path,
+                rate,
+                original,
+            } => format!(
+                "Upsampled from {original} Hz to {rate} Hz: {}",
+                path.display()
+            ),
+            Clipping { path, samples } => {
+                format!("{samples} samples are clipped: {}", path.display())
+            }
+            LogError { path, error } => {
+                format!("Rip log could not be read: {error}: {}", path.display())
+            }
+            LogChecksumMissing { path } => {
+                format!("Rip log does not have a checksum: {}", path.display())
+            }
+            LogChecksumInvalid { path } => {
+                format!(
+                    "Rip log checksum is invalid so the log has been edited: {}",
+                    path.display()
+                )
+            }
+            ReadMode { path, mode } => {
+                format!(
+                    "Rip log read mode is not secure: {mode}: {}",
+                    path.display()
+                )
+            }
+            AccurateRip { path, tracks } => format!(
+                "Tracks {} could not be verified as accurate: {}",
+                join_humanized(tracks),
+                path.display()
+            ),
+            CrcMismatch { path, tracks } => format!(
+                "Tracks {} have a test CRC that does not match the copy CRC: {}",
+                join_humanized(tracks),
+                path.display()
+            ),
+            LogTrackCount {
+                path,
+                expected,
+                actual,
+            } => format!(
+                "Rip log has {actual} tracks but there are {expected} FLACs: {}",
+                path.display()
+            ),
+            CueTrackCount {
+                path,
+                expected,
+                actual,
+            } => format!(
+                "Cue sheet has {actual} audio tracks but there are {expected} FLACs: {}",
+                path.display()
+            ),
+            LowPeak { path, peak } => format!(
+                "Peak level is less than {MIN_PEAK_DB} dBFS: {peak:.1} dBFS: {}",
+                path.display()
+            ),
             FlacError { path, error } => format!("FLAC stream error: {error}: {}", path.display()),
             Error { domain, details } => format!("A {domain} error occured:\n{details}"),
             Other(details) => details.clone(),
@@ -7,7 +7,7 @@ use di::{injectable, Ref, RefMut};
 use gazelle_api::{GazelleClient, Torrent};
 use html_escape::decode_html_entities;
 use log::{trace, warn};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Retrieve [Source] from the [Api] via a [provider design pattern](https://en.wikipedia.org/wiki/Provider_model)
 #[injectable]
@@ -19,6 +19,18 @@ pub struct SourceProvider {
 
 impl SourceProvider {
     pub async fn get(&mut self, id: u32) -> Result<Source, SourceIssue> {
+        self.get_with_content_dir(id, None).await
+    }
+
+    /// Get a [`Source`] by id.
+    ///
+    /// If `content_dir` is set it is used instead of searching the `content` directories.
+    /// This is the case for sources read from a torrent client.
+    pub async fn get_with_content_dir(
+        &mut self,
+        id: u32,
+        content_dir: Option<&Path>,
+    ) -> Result<Source, SourceIssue> {
         let mut api = self.api.write().expect("API should be available to read");
         let response = match api.get_torrent(id).await {
             Ok(response) => response,
@@ -54,7 +66,10 @@ impl SourceProvider {
             });
         };
         let existing = ExistingFormatProvider::get(&torrent, &group_torrents);
-        let directory = self.get_source_directory(&torrent)?;
+        let directory = match content_dir {
+            Some(content_dir) => get_client_source_directory(&torrent, content_dir)?,
+            None => self.get_source_directory(&torrent)?,
+        };
         let metadata = Metadata::new(&group, &torrent);
         Ok(Source {
             torrent,
@@ -102,3 +117,16 @@ impl SourceProvider {
         }
     }
 }
+
+fn get_client_source_directory(
+    torrent: &Torrent,
+    content_dir: &Path,
+) -> Result<PathBuf, SourceIssue> {
+    let path = decode_html_entities(&torrent.file_path).to_string();
+    let directory = content_dir.join(&path);
+    if directory.is_dir() {
+        Ok(directory)
+    } else {
+        Err(SourceIssue::MissingDirectory { path: directory })
+    }
+}
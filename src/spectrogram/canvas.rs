@@ -0,0 +1,139 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+use font8x8::{UnicodeFonts, BASIC_FONTS};
+use image::RgbImage;
+use png::{BitDepth, ColorType, Encoder};
+use rogue_logging::Error;
+
+use crate::errors::{error, io_error};
+
+/// Width and height of a character in pixels.
+pub const FONT_SIZE: usize = 8;
+
+/// An RGB image.
+pub struct Canvas {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    #[must_use]
+    pub fn new(width: usize, height: usize, background: [u8; 3]) -> Self {
+        let pixels = background.repeat(width * height);
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    #[must_use]
+    pub fn get_pixel(&self, x: usize, y: usize) -> Option<[u8; 3]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let index = (y * self.width + x) * 3;
+        let pixel = self.pixels.get(index..index + 3)?;
+        Some([*pixel.first()?, *pixel.get(1)?, *pixel.get(2)?])
+    }
+
+    /// Set a pixel. Pixels outside the canvas are ignored.
+    pub fn set_pixel(&mut self, x: usize, y: usize, color: [u8; 3]) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let index = (y * self.width + x) * 3;
+        if let Some(pixel) = self.pixels.get_mut(index..index + 3) {
+            pixel.copy_from_slice(&color);
+        }
+    }
+
+    pub fn fill_rect(&mut self, x: usize, y: usize, width: usize, height: usize, color: [u8; 3]) {
+        for row in y..y + height {
+            for column in x..x + width {
+                self.set_pixel(column, row, color);
+            }
+        }
+    }
+
+    pub fn draw_rect(&mut self, x: usize, y: usize, width: usize, height: usize, color: [u8; 3]) {
+        self.fill_rect(x, y, width, 1, color);
+        self.fill_rect(x, y + height - 1, width, 1, color);
+        self.fill_rect(x, y, 1, height, color);
+        self.fill_rect(x + width - 1, y, 1, height, color);
+    }
+
+    /// Draw `text` with its top left corner at `x`, `y`.
+    ///
+    /// Characters that are not ASCII are drawn as `?`.
+    pub fn draw_text(&mut self, x: usize, y: usize, text: &str, color: [u8; 3]) {
+        for (index, character) in text.chars().enumerate() {
+            let glyph = BASIC_FONTS
+                .get(character)
+                .or_else(|| BASIC_FONTS.get('?'))
+                .unwrap_or_default();
+            let left = x + index * FONT_SIZE;
+            for (row, bits) in glyph.iter().enumerate() {
+                for column in 0..FONT_SIZE {
+                    if bits & (1 << column) != 0 {
+                        self.set_pixel(left + column, y + row, color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draw `text` horizontally centered on `x`.
+    #[allow(clippy::integer_division)]
+    pub fn draw_text_centered(&mut self, x: usize, y: usize, text: &str, color: [u8; 3]) {
+        let width = get_text_width(text);
+        self.draw_text(x.saturating_sub(width / 2), y, text, color);
+    }
+
+    /// Draw `text` with its right edge at `x`.
+    pub fn draw_text_right(&mut self, x: usize, y: usize, text: &str, color: [u8; 3]) {
+        let width = get_text_width(text);
+        self.draw_text(x.saturating_sub(width), y, text, color);
+    }
+
+    /// Draw `image` with its top left corner at `x`, `y`.
+    #[allow(clippy::as_conversions)]
+    pub fn draw_image(&mut self, x: usize, y: usize, image: &RgbImage) {
+        for (column, row, pixel) in image.enumerate_pixels() {
+            self.set_pixel(x + column as usize, y + row as usize, pixel.0);
+        }
+    }
+
+    /// Draw `other` with its top left corner at `x`, `y`.
+    pub fn draw_canvas(&mut self, x: usize, y: usize, other: &Canvas) {
+        for row in 0..other.height {
+            for column in 0..other.width {
+                if let Some(pixel) = other.get_pixel(column, row) {
+                    self.set_pixel(x + column, y + row, pixel);
+                }
+            }
+        }
+    }
+
+    /// Encode the canvas as a PNG file.
+    #[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+    pub fn save_png(&self, path: &Path) -> Result<(), Error> {
+        let file = File::create(path).map_err(|e| io_error(e, "create spectrogram file"))?;
+        let mut encoder = Encoder::new(BufWriter::new(file), self.width as u32, self.height as u32);
+        encoder.set_color(ColorType::Rgb);
+        encoder.set_depth(BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.pixels))
+            .map_err(|e| error("encode spectrogram", e.to_string()))
+    }
+}
+
+/// Width of `text` in pixels.
+#[must_use]
+pub fn get_text_width(text: &str) -> usize {
+    text.chars().count() * FONT_SIZE
+}
@@ -0,0 +1,156 @@
+use std::mem::replace;
+
+use crate::spectrogram::*;
+
+/// Power spectrum of each column of a spectrogram.
+pub struct SpectrogramColumns {
+    /// Power of each frequency bin for each column
+    pub power: Vec<Vec<f64>>,
+    /// Sample rate in Hz
+    pub sample_rate: u32,
+    /// Offset of the first sample from the start of the track in seconds
+    pub start: f64,
+    /// Duration of the analyzed samples in seconds
+    pub duration: f64,
+}
+
+/// Analyze the power spectrum of each column of a spectrogram as samples are decoded.
+///
+/// If every column spans at least one DFT window the samples are analyzed as they are
+/// pushed so memory is independent of the track length. Otherwise the windows of adjacent
+/// columns overlap so the samples are buffered, which is at most `width * window_size`
+/// samples.
+pub struct ColumnAnalyzer {
+    analyzer: SpectrumAnalyzer,
+    width: usize,
+    sample_rate: u32,
+    start: f64,
+    /// Total number of samples if they're analyzed as they're pushed
+    streaming_total: Option<u64>,
+    position: u64,
+    frame: Vec<f32>,
+    sum: Vec<f64>,
+    count: u32,
+    columns: Vec<Vec<f64>>,
+    buffer: Vec<f32>,
+}
+
+impl ColumnAnalyzer {
+    /// Create a [`ColumnAnalyzer`] for the samples of `range`.
+    #[must_use]
+    #[allow(clippy::as_conversions)]
+    pub fn new(settings: &SpectrogramSettings, range: &FlacRange) -> Self {
+        let width = settings.width.max(1);
+        let window_size = settings.get_window_size();
+        let analyzer = SpectrumAnalyzer::new(window_size, settings.dynamic_range);
+        let streaming_total = range
+            .length
+            .filter(|total| *total >= (width * window_size) as u64);
+        Self {
+            sum: vec![0.0; analyzer.get_bin_count()],
+            analyzer,
+            width,
+            sample_rate: range.sample_rate,
+            start: range.start,
+            streaming_total,
+            position: 0,
+            frame: Vec::with_capacity(window_size),
+            count: 0,
+            columns: Vec::with_capacity(width),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Analyze the next samples.
+    pub fn push(&mut self, samples: &[f32]) {
+        let Some(total) = self.streaming_total else {
+            self.buffer.extend_from_slice(samples);
+            return;
+        };
+        let window_size = self.analyzer.get_window_size();
+        for sample in samples {
+            while self.columns.len() < self.width && self.position >= self.get_column_end(total) {
+                self.finish_column();
+            }
+            if self.columns.len() >= self.width {
+                return;
+            }
+            self.frame.push(*sample);
+            if self.frame.len() == window_size {
+                let power = self.analyzer.get_power(&self.frame);
+                for (sum, power) in self.sum.iter_mut().zip(power) {
+                    *sum += power;
+                }
+                self.count += 1;
+                self.frame.clear();
+            }
+            self.position += 1;
+        }
+    }
+
+    /// Get the power spectrum of each column.
+    #[must_use]
+    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+    pub fn finish(mut self) -> SpectrogramColumns {
+        let count = if self.streaming_total.is_some() {
+            while self.columns.len() < self.width {
+                self.finish_column();
+            }
+            self.position
+        } else {
+            self.analyze_buffer();
+            self.buffer.len() as u64
+        };
+        SpectrogramColumns {
+            power: self.columns,
+            sample_rate: self.sample_rate,
+            start: self.start,
+            duration: count as f64 / f64::from(self.sample_rate),
+        }
+    }
+
+    #[allow(clippy::as_conversions, clippy::integer_division)]
+    fn get_column_end(&self, total: u64) -> u64 {
+        (self.columns.len() as u64 + 1) * total / self.width as u64
+    }
+
+    /// Average the windows of the current column.
+    ///
+    /// A partial window at the end of the column is discarded.
+    fn finish_column(&mut self) {
+        let bins = self.analyzer.get_bin_count();
+        let mut power = replace(&mut self.sum, vec![0.0; bins]);
+        if self.count > 1 {
+            let count = f64::from(self.count);
+            for value in &mut power {
+                *value /= count;
+            }
+        }
+        self.columns.push(power);
+        self.count = 0;
+        self.frame.clear();
+    }
+
+    /// Analyze the buffered samples.
+    ///
+    /// If a column is shorter than the window a single window centered on the column is
+    /// analyzed.
+    #[allow(clippy::integer_division)]
+    fn analyze_buffer(&mut self) {
+        let window_size = self.analyzer.get_window_size();
+        let total = self.buffer.len();
+        for column in 0..self.width {
+            let from = column * total / self.width;
+            let to = (column + 1) * total / self.width;
+            let frame = if to - from >= window_size {
+                self.buffer.get(from..to)
+            } else {
+                let begin = usize::midpoint(from, to).saturating_sub(window_size / 2);
+                let end = (begin + window_size).min(total);
+                self.buffer.get(begin.min(end)..end)
+            };
+            let power = self.analyzer.get_average_power(frame.unwrap_or_default());
+            self.columns.push(power);
+        }
+    }
+}
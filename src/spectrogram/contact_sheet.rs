@@ -0,0 +1,88 @@
+use std::path::{Path, PathBuf};
+
+use image::imageops::{resize, FilterType};
+use image::ImageReader;
+use rogue_logging::Error;
+
+use crate::errors::{error, io_error};
+use crate::spectrogram::palette::{BACKGROUND, TEXT};
+use crate::spectrogram::{Canvas, FONT_SIZE};
+
+/// Number of tiles in each row of the contact sheet.
+const COLUMNS: usize = 2;
+/// Width of each tile in pixels.
+const TILE_WIDTH: usize = 1000;
+/// Height of the label above each tile in pixels.
+const LABEL_HEIGHT: usize = FONT_SIZE + 8;
+/// Gap between tiles in pixels.
+const GAP: usize = 4;
+
+/// A spectrogram image to include in a [`ContactSheet`].
+pub struct ContactSheetItem {
+    /// Label drawn above the image
+    pub label: String,
+    /// Path to the PNG
+    pub path: PathBuf,
+}
+
+/// A single mosaic image of multiple spectrograms with a label above each.
+pub struct ContactSheet;
+
+impl ContactSheet {
+    /// Create a contact sheet of `items` and save it as a PNG to `output_path`.
+    ///
+    /// Each image is scaled to the same width and laid out in rows of [`COLUMNS`].
+    #[allow(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::integer_division
+    )]
+    pub fn create(items: &[ContactSheetItem], output_path: &Path) -> Result<(), Error> {
+        if items.is_empty() {
+            return Err(error(
+                "create contact sheet",
+                "no spectrograms to include".to_owned(),
+            ));
+        }
+        let mut tiles = Vec::new();
+        for item in items {
+            let image = ImageReader::open(&item.path)
+                .map_err(|e| io_error(e, "open spectrogram"))?
+                .decode()
+                .map_err(|e| error("decode spectrogram", e.to_string()))?
+                .to_rgb8();
+            let width = image.width().max(1) as usize;
+            let height = (image.height() as usize * TILE_WIDTH / width).max(1);
+            let tile = resize(
+                &image,
+                TILE_WIDTH as u32,
+                height as u32,
+                FilterType::Triangle,
+            );
+            tiles.push((item, tile));
+        }
+        let tile_height = tiles
+            .iter()
+            .map(|(_, tile)| tile.height() as usize)
+            .max()
+            .unwrap_or_default();
+        let columns = COLUMNS.min(tiles.len());
+        let rows = tiles.len().div_ceil(columns);
+        let cell_width = TILE_WIDTH + GAP;
+        let cell_height = LABEL_HEIGHT + tile_height + GAP;
+        let mut canvas = Canvas::new(
+            columns * cell_width + GAP,
+            rows * cell_height + GAP,
+            BACKGROUND,
+        );
+        let max_chars = TILE_WIDTH / FONT_SIZE;
+        for (index, (item, tile)) in tiles.iter().enumerate() {
+            let x = GAP + (index % columns) * cell_width;
+            let y = GAP + (index / columns) * cell_height;
+            let label: String = item.label.chars().take(max_chars).collect();
+            canvas.draw_text(x, y + (LABEL_HEIGHT - FONT_SIZE) / 2, &label, TEXT);
+            canvas.draw_image(x, y + LABEL_HEIGHT, tile);
+        }
+        canvas.save_png(output_path)
+    }
+}
@@ -0,0 +1,179 @@
+use std::fs::File;
+use std::path::Path;
+use std::time::Duration;
+
+use claxon::FlacReader;
+use rogue_logging::Error;
+
+use crate::errors::{claxon_error, error};
+
+/// Mono PCM samples decoded from a FLAC file.
+pub struct DecodedAudio {
+    /// Samples scaled from `-1.0` to `1.0`
+    pub samples: Vec<f32>,
+    /// Sample rate in Hz
+    pub sample_rate: u32,
+    /// Bit depth of the source samples
+    pub bits_per_sample: u32,
+}
+
+/// Range of samples to decode from a FLAC file.
+#[derive(Clone, Debug)]
+pub struct FlacRange {
+    /// Sample rate in Hz
+    pub sample_rate: u32,
+    /// Bit depth of the source samples
+    pub bits_per_sample: u32,
+    /// Number of channels
+    pub channels: u32,
+    /// Offset of the first sample from the start of the track in seconds
+    pub start: f64,
+    /// Number of samples per channel, if known
+    pub length: Option<u64>,
+}
+
+/// Decode a range of a FLAC file block by block.
+///
+/// Samples are passed on as each block is decoded so the track is never held in memory.
+pub struct FlacStream {
+    reader: FlacReader<File>,
+    range: FlacRange,
+    start_sample: u64,
+    end_sample: u64,
+}
+
+impl FlacStream {
+    /// Open a FLAC file to decode the samples from `start` to `start + duration`.
+    ///
+    /// If the track is shorter than `start + duration` then `start` is moved earlier so
+    /// the full `duration` is decoded.
+    #[allow(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn open(path: &Path, start: Duration, duration: Option<Duration>) -> Result<Self, Error> {
+        let reader = FlacReader::open(path).map_err(|e| claxon_error(e, "read FLAC"))?;
+        let info = reader.streaminfo();
+        let sample_rate = f64::from(info.sample_rate);
+        let mut start_sample = (start.as_secs_f64() * sample_rate).round() as u64;
+        let length = duration.map(|duration| (duration.as_secs_f64() * sample_rate).round() as u64);
+        if let (Some(total), Some(length)) = (info.samples, length) {
+            start_sample = start_sample.min(total.saturating_sub(length));
+        }
+        let end_sample = length.map_or(u64::MAX, |length| start_sample + length);
+        let length = match info.samples {
+            Some(total) => Some(total.min(end_sample) - start_sample.min(total)),
+            None => length,
+        };
+        let range = FlacRange {
+            sample_rate: info.sample_rate,
+            bits_per_sample: info.bits_per_sample,
+            channels: info.channels,
+            start: start_sample as f64 / sample_rate,
+            length,
+        };
+        Ok(Self {
+            reader,
+            range,
+            start_sample,
+            end_sample,
+        })
+    }
+
+    #[must_use]
+    pub fn get_range(&self) -> &FlacRange {
+        &self.range
+    }
+
+    /// Decode the range, calling `visit` with the channel index and the samples of
+    /// each block scaled from `-1.0` to `1.0`.
+    ///
+    /// Decoding stops at the first error returned by `visit`.
+    #[allow(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss
+    )]
+    pub fn decode(
+        mut self,
+        mut visit: impl FnMut(u32, &[f32]) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let scale = 1.0 / f64::from(1_u32 << (self.range.bits_per_sample - 1));
+        let mut position: u64 = 0;
+        let mut blocks = self.reader.blocks();
+        let mut buffer = Vec::new();
+        let mut scaled = Vec::new();
+        while let Some(block) = blocks
+            .read_next_or_eof(buffer)
+            .map_err(|e| claxon_error(e, "decode FLAC"))?
+        {
+            let duration = u64::from(block.duration());
+            let from = self.start_sample.saturating_sub(position).min(duration) as usize;
+            let to = self.end_sample.saturating_sub(position).min(duration) as usize;
+            if from < to {
+                for channel in 0..self.range.channels {
+                    let samples = block.channel(channel).get(from..to).unwrap_or_default();
+                    scaled.clear();
+                    scaled.extend(
+                        samples
+                            .iter()
+                            .map(|sample| (f64::from(*sample) * scale) as f32),
+                    );
+                    visit(channel, &scaled)?;
+                }
+            }
+            position += duration;
+            if position >= self.end_sample {
+                break;
+            }
+            buffer = block.into_buffer();
+        }
+        Ok(())
+    }
+}
+
+/// Decode the first channel of a FLAC file.
+///
+/// This is equivalent to sox `remix 1`.
+pub fn decode_flac(
+    path: &Path,
+    start: Duration,
+    duration: Option<Duration>,
+) -> Result<DecodedAudio, Error> {
+    decode_flac_channel(path, start, duration, 0)
+}
+
+/// Decode a single channel of a FLAC file.
+///
+/// The samples are collected in memory so only use this for short ranges.
+/// Use [`FlacStream`] to process a full track.
+#[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+pub fn decode_flac_channel(
+    path: &Path,
+    start: Duration,
+    duration: Option<Duration>,
+    channel: u32,
+) -> Result<DecodedAudio, Error> {
+    let stream = FlacStream::open(path, start, duration)?;
+    let range = stream.get_range().clone();
+    if channel >= range.channels {
+        return Err(error(
+            "decode FLAC",
+            format!("channel {channel} does not exist in {}", path.display()),
+        ));
+    }
+    let mut samples = Vec::with_capacity(range.length.unwrap_or_default() as usize);
+    stream.decode(|index, block| {
+        if index == channel {
+            samples.extend_from_slice(block);
+        }
+        Ok(())
+    })?;
+    Ok(DecodedAudio {
+        samples,
+        sample_rate: range.sample_rate,
+        bits_per_sample: range.bits_per_sample,
+    })
+}
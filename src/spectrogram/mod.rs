@@ -1,16 +1,35 @@
-pub use size::*;
+pub use canvas::*;
+pub use column_analyzer::*;
+pub use contact_sheet::*;
+pub use flac_decoder::*;
 pub use spectrogram_command::*;
 pub use spectrogram_job::*;
 pub use spectrogram_job_factory::*;
+pub use spectrogram_profile::*;
+pub use spectrogram_renderer::*;
+pub use spectrogram_report::*;
+pub use spectrogram_settings::*;
 pub use spectrogram_status::*;
+pub use spectrogram_uploader::*;
+pub use spectrum::*;
 
 mod spectrogram_job;
 
-pub(crate) mod size;
+pub(crate) mod canvas;
+pub(crate) mod column_analyzer;
+pub(crate) mod contact_sheet;
+pub(crate) mod flac_decoder;
+pub(crate) mod palette;
+pub(crate) mod spectrogram_profile;
+pub(crate) mod spectrogram_renderer;
+pub(crate) mod spectrogram_report;
+pub(crate) mod spectrogram_settings;
+pub(crate) mod spectrum;
 
 mod spectrogram_job_factory;
 
 pub(crate) mod spectrogram_command;
 pub(crate) mod spectrogram_status;
+pub(crate) mod spectrogram_uploader;
 #[cfg(test)]
 mod tests;
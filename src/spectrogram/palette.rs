@@ -0,0 +1,49 @@
+use std::f64::consts::PI;
+
+/// Background color of the image.
+pub const BACKGROUND: [u8; 3] = [0, 0, 0];
+
+/// Color of the title and comment.
+pub const TEXT: [u8; 3] = [255, 255, 255];
+
+/// Color of the axis labels.
+pub const LABELS: [u8; 3] = [230, 230, 230];
+
+/// Color of the axis lines and ticks.
+pub const GRID: [u8; 3] = [102, 102, 102];
+
+/// Get the color of a `level` from `0.0` (silent) to `1.0` (loudest).
+///
+/// This is the default sox spectrogram palette which fades
+/// from black through purple and red to yellow and white.
+#[must_use]
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn get_color(level: f64) -> [u8; 3] {
+    let x = level.clamp(0.0, 1.0);
+    let red = if x < 0.13 {
+        0.0
+    } else if x < 0.73 {
+        ((x - 0.13) / 0.60 * PI / 2.0).sin()
+    } else {
+        1.0
+    };
+    let green = if x < 0.60 {
+        0.0
+    } else if x < 0.91 {
+        ((x - 0.60) / 0.31 * PI / 2.0).sin()
+    } else {
+        1.0
+    };
+    let blue = if x < 0.60 {
+        0.5 * (x / 0.60 * PI).sin()
+    } else if x < 0.78 {
+        0.0
+    } else {
+        (x - 0.78) / 0.22
+    };
+    [red, green, blue].map(|value| (value * 255.0).round().clamp(0.0, 255.0) as u8)
+}
@@ -1,25 +1,34 @@
 use colored::Colorize;
 use di::{injectable, Ref, RefMut};
-use log::{debug, info};
+use log::{debug, info, warn};
 
-use crate::errors::error;
+use crate::errors::{error, task_error};
 use crate::fs::*;
 use crate::jobs::JobRunner;
-use crate::options::{Options, SharedOptions, SourceArg, SpectrogramOptions};
+use crate::options::{
+    ImageHostOptions, NamingOptions, Options, RunnerOptions, SharedOptions, SourceArg,
+    SpectrogramOptions,
+};
 use crate::queue::TimeStamp;
 use crate::source::{Source, SourceProvider};
 use crate::spectrogram::*;
 use rogue_logging::Error;
+use std::path::PathBuf;
+use tokio::task::spawn_blocking;
 
 /// Generate spectrograms for each track of a FLAC source.
 #[injectable]
 pub struct SpectrogramCommand {
     arg: Ref<SourceArg>,
     shared_options: Ref<SharedOptions>,
+    naming_options: Ref<NamingOptions>,
     spectrogram_options: Ref<SpectrogramOptions>,
+    image_host_options: Ref<ImageHostOptions>,
+    runner_options: Ref<RunnerOptions>,
     source_provider: RefMut<SourceProvider>,
     paths: Ref<PathManager>,
     factory: Ref<SpectrogramJobFactory>,
+    uploader: Ref<SpectrogramUploader>,
     runner: Ref<JobRunner>,
 }
 
@@ -32,7 +41,10 @@ impl SpectrogramCommand {
     pub async fn execute_cli(&self) -> Result<bool, Error> {
         if !self.arg.validate()
             || !self.shared_options.validate()
+            || !self.naming_options.validate()
             || !self.spectrogram_options.validate()
+            || !self.image_host_options.validate()
+            || !self.runner_options.validate()
         {
             return Ok(false);
         }
@@ -54,6 +66,11 @@ impl SpectrogramCommand {
     ///
     /// Returns a [`SpectrogramStatus`] indicating the success of the operation and any errors.
     ///
+    /// A contact sheet and `index.html` report are created in the spectrogram directory.
+    ///
+    /// If an image host is configured the spectrograms are uploaded and the URLs are
+    /// included in the status. Failure to upload is logged as a warning.
+    ///
     /// Errors are not logged so should be handled by the caller.
     #[must_use]
     pub async fn execute(&self, source: &Source) -> SpectrogramStatus {
@@ -68,10 +85,24 @@ impl SpectrogramCommand {
                 let path = self.paths.get_spectrogram_dir(source);
                 let path_display = path.to_string_lossy().to_string();
                 debug!("in {path_display}");
+                let profile = self.spectrogram_options.get_contact_sheet_profile();
+                if let Err(error) =
+                    create_report(path.clone(), source.to_string(), collection, profile).await
+                {
+                    warn!("{} to create spectrogram report: {error}", "Failed".bold());
+                }
+                let urls = match self.uploader.upload(source).await {
+                    Ok(urls) => urls,
+                    Err(error) => {
+                        warn!("{} to upload spectrograms: {error}", "Failed".bold());
+                        None
+                    }
+                };
                 SpectrogramStatus {
                     success: true,
                     path: Some(path),
                     count,
+                    urls,
                     completed: TimeStamp::now(),
                     error: None,
                 }
@@ -80,9 +111,22 @@ impl SpectrogramCommand {
                 success: false,
                 path: None,
                 count,
+                urls: None,
                 completed: TimeStamp::now(),
                 error: Some(error),
             },
         }
     }
 }
+
+/// Create the contact sheet and HTML report on a blocking thread.
+async fn create_report(
+    dir: PathBuf,
+    title: String,
+    flacs: Vec<FlacFile>,
+    profile: Option<String>,
+) -> Result<(), Error> {
+    spawn_blocking(move || SpectrogramReport::create(&dir, &title, &flacs, profile.as_deref()))
+        .await
+        .map_err(|e| task_error(e, "create spectrogram report"))?
+}
@@ -1,97 +1,120 @@
 use rogue_logging::Error;
 use std::fs::create_dir_all;
 use std::path::PathBuf;
-use std::process::Output;
-use tokio::process::Command;
 
-use crate::dependencies::SOX;
-use crate::errors::{command_error, io_error, OutputHandler};
+use crate::built_info::PKG_NAME;
+use crate::errors::io_error;
+use crate::jobs::{get_partial_path, rename_partial, Cancellation};
+use crate::spectrogram::palette::BACKGROUND;
 use crate::spectrogram::*;
 
-/// A command to generate a spectrogram image of a FLAC file using sox.
+/// A command to render a spectrogram image of a FLAC file.
 ///
 /// A [command design pattern](https://refactoring.guru/design-patterns/command) is used
 /// so the execution of the command can be deferred and multiple commands can be executed
 /// in parallel via the multithreaded [`SpectrogramCommandRunner`].
+#[derive(Clone)]
 pub struct SpectrogramJob {
     pub id: String,
-    pub source_path: String,
+    pub source_path: PathBuf,
     pub output_path: PathBuf,
     pub image_title: String,
-    pub size: Size,
+    pub settings: SpectrogramSettings,
+    pub channels: ChannelHandling,
 }
 
 impl SpectrogramJob {
     /// Execute the command to generate the spectrogram.
-    pub async fn execute(self) -> Result<(), Error> {
+    ///
+    /// Decoding and rendering are CPU bound so run on a blocking thread.
+    ///
+    /// The image is written to a partial path and renamed once complete.
+    pub async fn execute(self, cancellation: &Cancellation) -> Result<(), Error> {
         let output_dir = self
             .output_path
             .parent()
             .expect("output path should have a parent");
         create_dir_all(output_dir)
             .map_err(|e| io_error(e, "create spectrogram output directory"))?;
-        match self.size {
-            Size::Full => self.execute_full().await,
-            Size::Zoom => self.execute_zoom().await,
-        }?;
-        Ok(())
+        let output_path = self.output_path.clone();
+        cancellation
+            .spawn_blocking("generate spectrogram", move |cancellation| {
+                self.render(cancellation)
+            })
+            .await?;
+        rename_partial(&output_path)
     }
 
-    async fn execute_zoom(&self) -> Result<Output, Error> {
-        let output = Command::new(SOX)
-            .arg(&self.source_path)
-            .arg("-n")
-            .arg("remix")
-            .arg("1")
-            .arg("spectrogram")
-            .arg("-x")
-            .arg("500")
-            .arg("-y")
-            .arg("1025")
-            .arg("-z")
-            .arg("120")
-            .arg("-w")
-            .arg("Kaiser")
-            .arg("-S")
-            .arg("1:00")
-            .arg("-d")
-            .arg("0:02")
-            .arg("-t")
-            .arg(&self.image_title)
-            .arg("-c")
-            .arg("red_oxide")
-            .arg("-o")
-            .arg(&self.output_path)
-            .output()
-            .await
-            .map_err(|e| command_error(e, "execute generate spectrogram", SOX))?;
-        OutputHandler::execute(output, "generate spectrogram", "IMDL")
+    fn render(&self, cancellation: &Cancellation) -> Result<(), Error> {
+        let separate = self.channels == ChannelHandling::Separate;
+        let mut plots = self.render_plots(separate, cancellation)?;
+        let canvas = if plots.len() == 1 {
+            plots.remove(0)
+        } else {
+            stack_plots(&plots)
+        };
+        cancellation.check("save spectrogram")?;
+        canvas.save_png(&get_partial_path(&self.output_path))
     }
 
-    async fn execute_full(&self) -> Result<Output, Error> {
-        let output = Command::new(SOX)
-            .arg(&self.source_path)
-            .arg("-n")
-            .arg("remix")
-            .arg("1")
-            .arg("spectrogram")
-            .arg("-x")
-            .arg("3000")
-            .arg("-y")
-            .arg("513")
-            .arg("-z")
-            .arg("120")
-            .arg("-w")
-            .arg("Kaiser")
-            .arg("-t")
-            .arg(&self.image_title)
-            .arg("-c")
-            .arg("red_oxide")
-            .arg("-o")
-            .arg(&self.output_path)
-            .output()
-            .await
-            .map_err(|e| command_error(e, "execute generate spectrogram", SOX))?;
-        OutputHandler::execute(output, "generate spectrogram", "IMDL")
+    /// Render the first channel, or each channel if `separate`.
+    ///
+    /// The track is decoded once and the samples are analyzed as they are decoded so the
+    /// track is never held in memory.
+    ///
+    /// Cancellation is checked before each block is analyzed.
+    fn render_plots(
+        &self,
+        separate: bool,
+        cancellation: &Cancellation,
+    ) -> Result<Vec<Canvas>, Error> {
+        let stream = FlacStream::open(
+            &self.source_path,
+            self.settings.start,
+            self.settings.duration,
+        )?;
+        let range = stream.get_range();
+        let count = if separate { range.channels } else { 1 };
+        let mut analyzers: Vec<ColumnAnalyzer> = (0..count)
+            .map(|_| ColumnAnalyzer::new(&self.settings, range))
+            .collect();
+        stream.decode(|index, samples| {
+            if index == 0 {
+                cancellation.check("generate spectrogram")?;
+            }
+            let analyzer = usize::try_from(index)
+                .ok()
+                .and_then(|index| analyzers.get_mut(index));
+            if let Some(analyzer) = analyzer {
+                analyzer.push(samples);
+            }
+            Ok(())
+        })?;
+        let plots = analyzers
+            .into_iter()
+            .enumerate()
+            .map(|(index, analyzer)| {
+                let title = if separate {
+                    format!("{} (channel {})", self.image_title, index + 1)
+                } else {
+                    self.image_title.clone()
+                };
+                SpectrogramRenderer::render(&analyzer.finish(), &self.settings, &title, PKG_NAME)
+            })
+            .collect();
+        Ok(plots)
     }
 }
+
+/// Stack the plots vertically.
+fn stack_plots(plots: &[Canvas]) -> Canvas {
+    let width = plots.iter().map(|x| x.width).max().unwrap_or_default();
+    let height = plots.iter().map(|x| x.height).sum();
+    let mut canvas = Canvas::new(width, height, BACKGROUND);
+    let mut y = 0;
+    for plot in plots {
+        canvas.draw_canvas(0, y, plot);
+        y += plot.height;
+    }
+    canvas
+}
@@ -1,7 +1,11 @@
+use std::time::Duration;
+
 use di::{injectable, Ref};
+use rand::Rng;
 
 use crate::fs::{FlacFile, PathManager};
 use crate::jobs::Job;
+use crate::options::SpectrogramOptions;
 use crate::source::Source;
 use crate::spectrogram::*;
 
@@ -9,16 +13,19 @@ use crate::spectrogram::*;
 #[injectable]
 pub struct SpectrogramJobFactory {
     paths: Ref<PathManager>,
+    options: Ref<SpectrogramOptions>,
 }
 
 impl SpectrogramJobFactory {
-    /// Create a [`SpectrogramJob`] for each [`FlacFile`] in the [`Vec<FlacFile>`].
+    /// Create a [`SpectrogramJob`] for each [`FlacFile`] and each selected [`SpectrogramProfile`].
     #[must_use]
     pub fn create(&self, flacs: &[FlacFile], source: &Source) -> Vec<Job> {
+        let profiles = self.options.get_selected_profiles();
         let mut jobs = Vec::new();
         for (index, flac) in flacs.iter().enumerate() {
-            jobs.push(self.create_single(source, index, flac, Size::Zoom));
-            jobs.push(self.create_single(source, index, flac, Size::Full));
+            for profile in &profiles {
+                jobs.push(self.create_single(source, index, flac, profile));
+            }
         }
         jobs
     }
@@ -28,15 +35,17 @@ impl SpectrogramJobFactory {
     /// Arguments:
     ///
     /// * `flac_file`: Path to the flac file.
-    /// * `size`: Size of the spectrogram to create.
-    fn create_single(&self, source: &Source, index: usize, flac: &FlacFile, size: Size) -> Job {
-        let out_filename = flac.file_name.clone()
-            + match size {
-                Size::Full => ".full.png",
-                Size::Zoom => ".zoom.png",
-            };
-        let id = format!("Spectrogram {size:<4?}{index:>3}");
-        let source_path = flac.get_path_string();
+    /// * `profile`: Profile of the spectrogram to create.
+    fn create_single(
+        &self,
+        source: &Source,
+        index: usize,
+        flac: &FlacFile,
+        profile: &SpectrogramProfile,
+    ) -> Job {
+        let out_filename = format!("{}.{}.png", flac.file_name, profile.name);
+        let id = format!("Spectrogram {:<4}{index:>3}", profile.name);
+        let source_path = flac.path.clone();
         let output_path = self
             .paths
             .get_spectrogram_dir(source)
@@ -48,7 +57,56 @@ impl SpectrogramJobFactory {
             source_path,
             output_path,
             image_title,
-            size,
+            settings: self.get_settings(flac, profile),
+            channels: profile.channels,
         })
     }
+
+    fn get_settings(&self, flac: &FlacFile, profile: &SpectrogramProfile) -> SpectrogramSettings {
+        let window_size = profile.window_size.or(self.options.spectrogram_window_size);
+        let dynamic_range = f64::from(
+            profile.dynamic_range.unwrap_or(
+                self.options
+                    .spectrogram_dynamic_range
+                    .expect("spectrogram_dynamic_range should be set"),
+            ),
+        );
+        let duration = profile
+            .get_duration()
+            .expect("spectrogram profile duration should be valid");
+        let start = match profile
+            .get_start()
+            .expect("spectrogram profile start should be valid")
+        {
+            SpectrogramStart::Offset(start) => start,
+            SpectrogramStart::Random => get_random_start(flac, duration),
+        };
+        SpectrogramSettings {
+            width: profile.width,
+            height: profile.height,
+            window_size,
+            dynamic_range,
+            start,
+            duration,
+        }
+    }
+}
+
+/// Get a random offset so the region of `duration` fits within the track.
+///
+/// If the length of the track is unknown the start of the track is used.
+#[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+fn get_random_start(flac: &FlacFile, duration: Option<Duration>) -> Duration {
+    let Ok(info) = flac.get_stream_info() else {
+        return Duration::ZERO;
+    };
+    let Some(samples) = info.samples else {
+        return Duration::ZERO;
+    };
+    let total = samples as f64 / f64::from(info.sample_rate);
+    let latest = total - duration.unwrap_or_default().as_secs_f64();
+    if latest <= 0.0 {
+        return Duration::ZERO;
+    }
+    Duration::from_secs_f64(rand::thread_rng().gen_range(0.0..latest))
 }
@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Name of the built-in profile of the full track.
+pub const FULL_PROFILE: &str = "full";
+
+/// Name of the built-in profile of a short region of the track.
+pub const ZOOM_PROFILE: &str = "zoom";
+
+/// Value of a start offset that selects a random position in the track.
+pub const RANDOM_START: &str = "random";
+
+/// How the channels of a track are rendered.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChannelHandling {
+    /// Render only the first channel.
+    ///
+    /// Equivalent to sox `remix 1`.
+    #[default]
+    Remix,
+    /// Render each channel as a separate plot in the same image.
+    Separate,
+}
+
+/// Offset from the start of a track.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SpectrogramStart {
+    Offset(Duration),
+    Random,
+}
+
+impl SpectrogramStart {
+    /// Parse `random` or a duration such as `1m30s`.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        if value == RANDOM_START {
+            return Some(Self::Random);
+        }
+        humantime::parse_duration(value).ok().map(Self::Offset)
+    }
+}
+
+/// Dimensions and region of a spectrogram image.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SpectrogramProfile {
+    /// Name of the profile.
+    ///
+    /// The name is the suffix of the image, for example `01 Track.zoom.png`.
+    pub name: String,
+    /// Width of the plot in pixels
+    pub width: usize,
+    /// Height of the plot in pixels
+    pub height: usize,
+    /// Dynamic range in dB.
+    ///
+    /// If not set `spectrogram_dynamic_range` is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dynamic_range: Option<u32>,
+    /// Number of samples in each DFT window.
+    ///
+    /// If not set `spectrogram_window_size` is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window_size: Option<usize>,
+    #[serde(default)]
+    pub channels: ChannelHandling,
+    /// Offset from the start of the track as a duration or `random`.
+    ///
+    /// If not set the spectrogram starts at the beginning of the track.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+    /// Duration to render.
+    ///
+    /// If not set the spectrogram continues to the end of the track.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+}
+
+impl SpectrogramProfile {
+    #[must_use]
+    pub fn get_start(&self) -> Option<SpectrogramStart> {
+        match &self.start {
+            Some(start) => SpectrogramStart::parse(start),
+            None => Some(SpectrogramStart::Offset(Duration::ZERO)),
+        }
+    }
+
+    /// Get the duration to render, or `None` for the remainder of the track.
+    ///
+    /// Returns `Err` if the duration can't be parsed.
+    pub fn get_duration(&self) -> Result<Option<Duration>, humantime::DurationError> {
+        self.duration
+            .as_deref()
+            .map(humantime::parse_duration)
+            .transpose()
+    }
+}
@@ -0,0 +1,212 @@
+use crate::spectrogram::palette::*;
+use crate::spectrogram::*;
+
+/// Margin left of the plot for the frequency axis.
+const LEFT: usize = 58;
+/// Margin right of the plot for the color bar.
+const RIGHT: usize = 90;
+/// Margin below the plot for the time axis and comment.
+const BELOW: usize = 48;
+/// Margin above the plot for the title.
+const ABOVE: usize = 47;
+/// Width of the color bar.
+const SPECTRUM_WIDTH: usize = 14;
+/// Gap between the plot and the color bar.
+const SPECTRUM_GAP: usize = 12;
+/// Length of the axis ticks.
+const TICK_LENGTH: usize = 3;
+
+const TIME_STEPS: [f64; 19] = [
+    0.01, 0.02, 0.05, 0.1, 0.2, 0.5, 1.0, 2.0, 5.0, 10.0, 15.0, 20.0, 30.0, 60.0, 120.0, 300.0,
+    600.0, 1200.0, 3600.0,
+];
+const FREQUENCY_STEPS: [f64; 9] = [
+    100.0, 200.0, 500.0, 1000.0, 2000.0, 5000.0, 10000.0, 20000.0, 50000.0,
+];
+
+/// Render a spectrogram in the style of sox `spectrogram`.
+///
+/// The plot is surrounded by a time axis below, a frequency axis to the left,
+/// a color bar in dBFS to the right, the title above and the comment in the bottom left.
+pub struct SpectrogramRenderer;
+
+impl SpectrogramRenderer {
+    /// Render the columns analyzed by [`ColumnAnalyzer`].
+    #[must_use]
+    #[allow(clippy::integer_division)]
+    pub fn render(
+        columns: &SpectrogramColumns,
+        settings: &SpectrogramSettings,
+        title: &str,
+        comment: &str,
+    ) -> Canvas {
+        let width = settings.width.max(1);
+        let height = settings.height.max(2);
+        let mut canvas = Canvas::new(LEFT + width + RIGHT, ABOVE + height + BELOW, BACKGROUND);
+        draw_plot(&mut canvas, columns, settings);
+        draw_time_axis(&mut canvas, columns, width, height);
+        draw_frequency_axis(&mut canvas, columns, height);
+        draw_color_bar(&mut canvas, settings.dynamic_range, width, height);
+        let max_chars = canvas.width / FONT_SIZE;
+        let title: String = title.chars().take(max_chars).collect();
+        canvas.draw_text_centered(LEFT + width / 2, ABOVE / 2 - FONT_SIZE / 2, &title, TEXT);
+        canvas.draw_text(4, canvas.height - FONT_SIZE - 6, comment, TEXT);
+        canvas
+    }
+}
+
+#[allow(
+    clippy::as_conversions,
+    clippy::integer_division,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn draw_plot(canvas: &mut Canvas, columns: &SpectrogramColumns, settings: &SpectrogramSettings) {
+    let width = settings.width.max(1);
+    let height = settings.height.max(2);
+    for (column, power) in columns.power.iter().take(width).enumerate() {
+        let last_bin = power.len().saturating_sub(1) as f64;
+        for row in 0..height {
+            let position = row as f64 * last_bin / (height - 1) as f64;
+            let lower = position.floor() as usize;
+            let fraction = position - position.floor();
+            let lower_power = power.get(lower).copied().unwrap_or_default();
+            let upper_power = power.get(lower + 1).copied().unwrap_or(lower_power);
+            let value = lower_power + (upper_power - lower_power) * fraction;
+            let level = (power_to_db(value) + settings.dynamic_range) / settings.dynamic_range;
+            canvas.set_pixel(LEFT + column, ABOVE + height - 1 - row, get_color(level));
+        }
+    }
+    canvas.draw_rect(LEFT - 1, ABOVE - 1, width + 2, height + 2, GRID);
+}
+
+#[allow(
+    clippy::as_conversions,
+    clippy::integer_division,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn draw_time_axis(canvas: &mut Canvas, columns: &SpectrogramColumns, width: usize, height: usize) {
+    let duration = columns.duration;
+    if duration <= 0.0 {
+        return;
+    }
+    let step = get_step(duration, width as f64, 64.0, &TIME_STEPS);
+    let end = columns.start + duration;
+    let top = ABOVE + height + 1;
+    let mut index = (columns.start / step).ceil();
+    while index * step <= end + f64::EPSILON {
+        let time = index * step;
+        let x = LEFT + (((time - columns.start) / duration) * (width - 1) as f64).round() as usize;
+        canvas.fill_rect(x, top, 1, TICK_LENGTH, GRID);
+        canvas.draw_text_centered(x, top + TICK_LENGTH + 2, &format_value(time, step), LABELS);
+        index += 1.0;
+    }
+    canvas.draw_text_centered(
+        LEFT + width / 2,
+        top + TICK_LENGTH + FONT_SIZE + 6,
+        "Time (s)",
+        LABELS,
+    );
+}
+
+#[allow(
+    clippy::as_conversions,
+    clippy::integer_division,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn draw_frequency_axis(canvas: &mut Canvas, columns: &SpectrogramColumns, height: usize) {
+    let nyquist = f64::from(columns.sample_rate) / 2.0;
+    if nyquist <= 0.0 {
+        return;
+    }
+    let step = get_step(nyquist, height as f64, 20.0, &FREQUENCY_STEPS);
+    let mut index = 0.0;
+    while index * step <= nyquist {
+        let frequency = index * step;
+        let offset = ((frequency / nyquist) * (height - 1) as f64).round() as usize;
+        let y = ABOVE + height - 1 - offset;
+        canvas.fill_rect(LEFT - 1 - TICK_LENGTH, y, TICK_LENGTH, 1, GRID);
+        canvas.draw_text_right(
+            LEFT - TICK_LENGTH - 3,
+            y.saturating_sub(FONT_SIZE / 2),
+            &format_value(frequency / 1000.0, step / 1000.0),
+            LABELS,
+        );
+        index += 1.0;
+    }
+    canvas.draw_text_right(LEFT - TICK_LENGTH - 3, ABOVE - FONT_SIZE - 4, "kHz", LABELS);
+}
+
+#[allow(
+    clippy::as_conversions,
+    clippy::integer_division,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn draw_color_bar(canvas: &mut Canvas, dynamic_range: f64, width: usize, height: usize) {
+    let left = LEFT + width + SPECTRUM_GAP;
+    for row in 0..height {
+        let level = row as f64 / (height - 1) as f64;
+        canvas.fill_rect(
+            left,
+            ABOVE + height - 1 - row,
+            SPECTRUM_WIDTH,
+            1,
+            get_color(level),
+        );
+    }
+    canvas.draw_rect(left - 1, ABOVE - 1, SPECTRUM_WIDTH + 2, height + 2, GRID);
+    let step = if dynamic_range > 60.0 { 20.0 } else { 10.0 };
+    let mut value = 0.0;
+    while value <= dynamic_range {
+        let offset = ((value / dynamic_range) * (height - 1) as f64).round() as usize;
+        let y = ABOVE + offset;
+        let right = left + SPECTRUM_WIDTH + 1;
+        canvas.fill_rect(right, y, TICK_LENGTH, 1, GRID);
+        canvas.draw_text(
+            right + TICK_LENGTH + 2,
+            y.saturating_sub(FONT_SIZE / 2),
+            &format!("{}", -(value.round() as i64)),
+            LABELS,
+        );
+        value += step;
+    }
+    canvas.draw_text_centered(
+        left + SPECTRUM_WIDTH / 2,
+        ABOVE - FONT_SIZE - 4,
+        "dBFS",
+        LABELS,
+    );
+}
+
+/// Get the smallest step that places ticks at least `min_spacing` pixels apart.
+fn get_step(range: f64, pixels: f64, min_spacing: f64, steps: &[f64]) -> f64 {
+    steps
+        .iter()
+        .copied()
+        .find(|step| step / range * pixels >= min_spacing)
+        .or_else(|| steps.last().copied())
+        .unwrap_or(range)
+}
+
+/// Format `value` with enough decimal places to distinguish each `step`.
+#[allow(
+    clippy::as_conversions,
+    clippy::integer_division,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn format_value(value: f64, step: f64) -> String {
+    let decimals = if step >= 1.0 {
+        0
+    } else {
+        (-step.log10()).ceil() as usize
+    };
+    format!("{value:.decimals$}")
+}
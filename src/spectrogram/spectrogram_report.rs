@@ -0,0 +1,227 @@
+use std::fs::{read_dir, write};
+use std::path::{Path, PathBuf};
+
+use minijinja::{AutoEscape, Environment};
+use rogue_logging::Error;
+use serde::Serialize;
+
+use crate::errors::{claxon_error, error, io_error};
+use crate::fs::FlacFile;
+use crate::spectrogram::{ContactSheet, ContactSheetItem};
+use crate::transcode::{get_average_bit_rate, get_duration};
+use crate::upload::format_duration;
+
+/// File name of the contact sheet in the spectrogram directory.
+pub const CONTACT_SHEET_FILE_NAME: &str = "contact-sheet.png";
+
+/// File name of the HTML report in the spectrogram directory.
+pub const REPORT_FILE_NAME: &str = "index.html";
+
+/// Details of a track in the [`SpectrogramReport`].
+#[derive(Clone, Debug, Default)]
+pub struct ReportTrack {
+    /// Path of the track relative to the source directory
+    pub name: String,
+    /// Sample rate in Hz
+    pub sample_rate: u32,
+    pub bits_per_sample: u32,
+    pub channels: u32,
+    /// Duration in seconds
+    pub duration: Option<u32>,
+    /// Average bit rate in bits per second
+    pub bit_rate: Option<u32>,
+    /// Paths of the spectrograms relative to the spectrogram directory
+    pub images: Vec<PathBuf>,
+}
+
+/// Template of the HTML report.
+const REPORT_TEMPLATE: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{{ title }}</title>
+<style>
+body { background: #111; color: #eee; font-family: sans-serif; margin: 2em; }
+a { color: #9cf; }
+img { display: block; max-width: 100%; margin: 0.5em 0; }
+th, td { padding: 0.1em 1em 0.1em 0; text-align: left; }
+section { margin-bottom: 3em; }
+</style>
+</head>
+<body>
+<h1>{{ title }}</h1>
+{% if contact_sheet %}<section>
+<h2>Contact sheet</h2>
+<a href="{{ contact_sheet }}"><img src="{{ contact_sheet }}" alt="Contact sheet"></a>
+</section>
+{% endif %}<ol>
+{% for track in tracks %}<li><a href="#track-{{ loop.index }}">{{ track.name }}</a></li>
+{% endfor %}</ol>
+{% for track in tracks %}<section id="track-{{ loop.index }}">
+<h2>{{ track.name }}</h2>
+<table>
+<tr><th>Sample rate</th><td>{{ track.sample_rate }} Hz</td></tr>
+<tr><th>Bit depth</th><td>{{ track.bits_per_sample }} bit</td></tr>
+<tr><th>Channels</th><td>{{ track.channels }}</td></tr>
+<tr><th>Duration</th><td>{{ track.duration }}</td></tr>
+<tr><th>Bitrate</th><td>{% if track.bit_rate %}{{ track.bit_rate }} kb/s{% endif %}</td></tr>
+</table>
+{% for image in track.images %}<a href="{{ image }}"><img src="{{ image }}" alt="{{ track.name }}" loading="lazy"></a>
+{% endfor %}</section>
+{% endfor %}</body>
+</html>
+"##;
+
+#[derive(Serialize)]
+struct ReportContext {
+    title: String,
+    contact_sheet: Option<String>,
+    tracks: Vec<TrackContext>,
+}
+
+#[derive(Serialize)]
+struct TrackContext {
+    name: String,
+    sample_rate: u32,
+    bits_per_sample: u32,
+    channels: u32,
+    duration: String,
+    /// Average bit rate in kb/s
+    bit_rate: Option<u32>,
+    images: Vec<String>,
+}
+
+/// An `index.html` and contact sheet summarizing the spectrograms of a source.
+pub struct SpectrogramReport;
+
+impl SpectrogramReport {
+    /// Create the contact sheet and `index.html` in the spectrogram directory.
+    ///
+    /// The contact sheet includes the spectrogram of the `profile` of each track.
+    /// If `profile` is `None` the contact sheet is not created.
+    pub fn create(
+        dir: &Path,
+        title: &str,
+        flacs: &[FlacFile],
+        profile: Option<&str>,
+    ) -> Result<(), Error> {
+        let mut tracks = Vec::new();
+        for flac in flacs {
+            tracks.push(get_track(dir, flac)?);
+        }
+        let suffix = profile.map(|name| format!(".{name}.png"));
+        let items: Vec<ContactSheetItem> = tracks
+            .iter()
+            .flat_map(|track| {
+                track
+                    .images
+                    .iter()
+                    .filter(|image| {
+                        suffix
+                            .as_ref()
+                            .is_some_and(|suffix| image.to_string_lossy().ends_with(suffix))
+                    })
+                    .map(|image| ContactSheetItem {
+                        label: track.name.clone(),
+                        path: dir.join(image),
+                    })
+            })
+            .collect();
+        let contact_sheet = if items.is_empty() {
+            None
+        } else {
+            ContactSheet::create(&items, &dir.join(CONTACT_SHEET_FILE_NAME))?;
+            Some(Path::new(CONTACT_SHEET_FILE_NAME))
+        };
+        let html = Self::render(title, contact_sheet, &tracks)?;
+        write(dir.join(REPORT_FILE_NAME), html).map_err(|e| io_error(e, "write spectrogram report"))
+    }
+
+    /// Render the HTML report.
+    ///
+    /// Image paths are relative to the report.
+    #[allow(clippy::integer_division)]
+    pub fn render(
+        title: &str,
+        contact_sheet: Option<&Path>,
+        tracks: &[ReportTrack],
+    ) -> Result<String, Error> {
+        let context = ReportContext {
+            title: title.to_owned(),
+            contact_sheet: contact_sheet.map(get_url),
+            tracks: tracks
+                .iter()
+                .map(|track| TrackContext {
+                    name: track.name.clone(),
+                    sample_rate: track.sample_rate,
+                    bits_per_sample: track.bits_per_sample,
+                    channels: track.channels,
+                    duration: track.duration.map(format_duration).unwrap_or_default(),
+                    bit_rate: track.bit_rate.map(|rate| rate / 1000),
+                    images: track.images.iter().map(|image| get_url(image)).collect(),
+                })
+                .collect(),
+        };
+        let mut environment = Environment::new();
+        environment.set_auto_escape_callback(|_| AutoEscape::Html);
+        environment
+            .render_str(REPORT_TEMPLATE, context)
+            .map_err(|e| error("render spectrogram report", e.to_string()))
+    }
+}
+
+/// Get the details and spectrograms of a track.
+///
+/// Spectrograms are the PNG files in the same sub directory with the track name as prefix.
+fn get_track(dir: &Path, flac: &FlacFile) -> Result<ReportTrack, Error> {
+    let info = flac
+        .get_stream_info()
+        .map_err(|e| claxon_error(e, "read stream info"))?;
+    let prefix = format!("{}.", flac.file_name);
+    let mut images: Vec<PathBuf> = read_dir(dir.join(&flac.sub_dir))
+        .map_err(|e| io_error(e, "read spectrogram directory"))?
+        .filter_map(Result::ok)
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| {
+            name.starts_with(&prefix) && Path::new(name).extension().is_some_and(|x| x == "png")
+        })
+        .map(|name| flac.sub_dir.join(name))
+        .collect();
+    images.sort();
+    Ok(ReportTrack {
+        name: flac
+            .sub_dir
+            .join(&flac.file_name)
+            .to_string_lossy()
+            .to_string(),
+        sample_rate: info.sample_rate,
+        bits_per_sample: info.bits_per_sample,
+        channels: info.channels,
+        duration: get_duration(&info),
+        bit_rate: get_average_bit_rate(&info),
+        images,
+    })
+}
+
+/// Get a relative URL for a relative `path`.
+fn get_url(path: &Path) -> String {
+    path.components()
+        .map(|component| encode_path_segment(&component.as_os_str().to_string_lossy()))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Percent encode the characters of a path segment that would break a relative URL.
+fn encode_path_segment(segment: &str) -> String {
+    let mut output = String::new();
+    for character in segment.chars() {
+        match character {
+            '%' => output.push_str("%25"),
+            ' ' => output.push_str("%20"),
+            '#' => output.push_str("%23"),
+            '?' => output.push_str("%3F"),
+            _ => output.push(character),
+        }
+    }
+    output
+}
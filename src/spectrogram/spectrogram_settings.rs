@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+/// Settings for rendering a spectrogram image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpectrogramSettings {
+    /// Width of the plot in pixels
+    pub width: usize,
+    /// Height of the plot in pixels
+    pub height: usize,
+    /// Number of samples in each DFT window.
+    ///
+    /// If `None` the window is sized so each row is one frequency bin, as sox does.
+    pub window_size: Option<usize>,
+    /// Dynamic range in dB
+    pub dynamic_range: f64,
+    /// Offset from the start of the track
+    pub start: Duration,
+    /// Duration to render, or `None` for the remainder of the track
+    pub duration: Option<Duration>,
+}
+
+impl SpectrogramSettings {
+    /// Number of samples in each DFT window.
+    #[must_use]
+    pub fn get_window_size(&self) -> usize {
+        self.window_size
+            .unwrap_or_else(|| 2 * self.height.saturating_sub(1))
+            .max(2)
+    }
+}
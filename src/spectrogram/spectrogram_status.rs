@@ -11,6 +11,8 @@ pub struct SpectrogramStatus {
     pub path: Option<PathBuf>,
     /// Number of spectrograms created
     pub count: usize,
+    /// URLs of the spectrograms uploaded to the image host
+    pub urls: Option<Vec<String>>,
     /// Time the spectrogram completed
     pub completed: TimeStamp,
     /// Error message if the spectrogram failed
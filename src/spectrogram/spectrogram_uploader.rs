@@ -0,0 +1,51 @@
+use colored::Colorize;
+use di::{injectable, Ref};
+use log::{debug, info};
+use rogue_logging::Error;
+
+use crate::errors::io_error;
+use crate::fs::{DirectoryReader, PathManager};
+use crate::image_host::ImageHostFactory;
+use crate::source::Source;
+use crate::spectrogram::CONTACT_SHEET_FILE_NAME;
+
+/// Upload the spectrograms of a source to the configured image host.
+#[injectable]
+pub struct SpectrogramUploader {
+    paths: Ref<PathManager>,
+    factory: Ref<ImageHostFactory>,
+}
+
+impl SpectrogramUploader {
+    /// Upload each spectrogram of `source` to the image host.
+    ///
+    /// The contact sheet is only for local review so it's not uploaded.
+    ///
+    /// Returns the URLs of the uploaded images, or `None` if no image host is configured.
+    pub async fn upload(&self, source: &Source) -> Result<Option<Vec<String>>, Error> {
+        let Some(client) = self.factory.create() else {
+            return Ok(None);
+        };
+        let dir = self.paths.get_spectrogram_dir(source);
+        let mut paths = DirectoryReader::new()
+            .with_extension("png")
+            .read(&dir)
+            .map_err(|e| io_error(e, "read spectrogram directory"))?;
+        let contact_sheet = dir.join(CONTACT_SHEET_FILE_NAME);
+        paths.retain(|path| path != &contact_sheet);
+        paths.sort();
+        let mut urls = Vec::new();
+        for path in paths {
+            let url = client.upload(&path).await?;
+            debug!("{} {} to {url}", "Uploaded".bold(), path.display());
+            urls.push(url);
+        }
+        info!(
+            "{} {} spectrograms to {}",
+            "Uploaded".bold(),
+            urls.len(),
+            client.get_kind()
+        );
+        Ok(Some(urls))
+    }
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use rustfft::num_complex::Complex;
+use rustfft::{Fft, FftPlanner};
+
+/// Power spectrum analysis with a Kaiser window.
+///
+/// Power is normalized so a full scale sine wave is `0 dBFS`.
+pub struct SpectrumAnalyzer {
+    fft: Arc<dyn Fft<f64>>,
+    window: Vec<f64>,
+    normalization: f64,
+    buffer: Vec<Complex<f64>>,
+}
+
+impl SpectrumAnalyzer {
+    /// Create a [`SpectrumAnalyzer`].
+    ///
+    /// The Kaiser window is shaped so its side lobes are below the `dynamic_range` in dB.
+    #[must_use]
+    pub fn new(window_size: usize, dynamic_range: f64) -> Self {
+        let fft = FftPlanner::new().plan_fft_forward(window_size);
+        let window = get_kaiser_window(window_size, get_kaiser_beta(dynamic_range));
+        let sum: f64 = window.iter().sum();
+        Self {
+            fft,
+            window,
+            normalization: 4.0 / (sum * sum),
+            buffer: vec![Complex::default(); window_size],
+        }
+    }
+
+    /// Number of frequency bins returned by [`SpectrumAnalyzer::get_power`].
+    #[must_use]
+    #[allow(clippy::integer_division)]
+    pub fn get_bin_count(&self) -> usize {
+        self.window.len() / 2 + 1
+    }
+
+    #[must_use]
+    pub fn get_window_size(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Get the power of each frequency bin from `0` to the Nyquist frequency.
+    ///
+    /// `frame` is zero padded if it is shorter than the window.
+    pub fn get_power(&mut self, frame: &[f32]) -> Vec<f64> {
+        for (index, value) in self.buffer.iter_mut().enumerate() {
+            let sample = frame.get(index).copied().map_or(0.0, f64::from);
+            let weight = self.window.get(index).copied().unwrap_or_default();
+            *value = Complex::new(sample * weight, 0.0);
+        }
+        self.fft.process(&mut self.buffer);
+        self.buffer
+            .iter()
+            .take(self.get_bin_count())
+            .map(|value| value.norm_sqr() * self.normalization)
+            .collect()
+    }
+
+    /// Get the average power of each frequency bin over consecutive windows of `samples`.
+    ///
+    /// If `samples` is shorter than the window a single zero padded window is analyzed.
+    pub fn get_average_power(&mut self, samples: &[f32]) -> Vec<f64> {
+        let window_size = self.get_window_size();
+        let mut total = vec![0.0; self.get_bin_count()];
+        let mut count = 0_u32;
+        for frame in samples.chunks(window_size) {
+            if count > 0 && frame.len() < window_size {
+                break;
+            }
+            for (sum, power) in total.iter_mut().zip(self.get_power(frame)) {
+                *sum += power;
+            }
+            count += 1;
+        }
+        if count > 1 {
+            let count = f64::from(count);
+            for sum in &mut total {
+                *sum /= count;
+            }
+        }
+        total
+    }
+}
+
+/// Convert power to decibels.
+#[must_use]
+pub fn power_to_db(power: f64) -> f64 {
+    10.0 * power.max(1e-30).log10()
+}
+
+/// Get the Kaiser window `beta` for a side lobe attenuation in dB.
+#[must_use]
+pub fn get_kaiser_beta(attenuation: f64) -> f64 {
+    if attenuation > 50.0 {
+        0.1102 * (attenuation - 8.7)
+    } else if attenuation > 21.0 {
+        0.5842 * (attenuation - 21.0).powf(0.4) + 0.07886 * (attenuation - 21.0)
+    } else {
+        0.0
+    }
+}
+
+#[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+fn get_kaiser_window(size: usize, beta: f64) -> Vec<f64> {
+    if size < 2 {
+        return vec![1.0; size];
+    }
+    let denominator = bessel_i0(beta);
+    let last = (size - 1) as f64;
+    (0..size)
+        .map(|index| {
+            let x = 2.0 * index as f64 / last - 1.0;
+            bessel_i0(beta * (1.0 - x * x).max(0.0).sqrt()) / denominator
+        })
+        .collect()
+}
+
+/// Zeroth order modified Bessel function of the first kind.
+fn bessel_i0(x: f64) -> f64 {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let half = x / 2.0;
+    let mut k = 1.0;
+    while term > sum * 1e-12 {
+        term *= (half / k) * (half / k);
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
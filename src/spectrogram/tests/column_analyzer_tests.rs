@@ -0,0 +1,82 @@
+use std::f64::consts::PI;
+use std::time::Duration;
+
+use crate::spectrogram::*;
+
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation
+)]
+fn create_samples(count: usize) -> Vec<f32> {
+    (0..count)
+        .map(|index| {
+            let time = index as f64 / 44100.0;
+            (0.5 * (2.0 * PI * 1000.0 * time).sin()) as f32
+        })
+        .collect()
+}
+
+fn create_settings() -> SpectrogramSettings {
+    SpectrogramSettings {
+        width: 50,
+        height: 129,
+        window_size: None,
+        dynamic_range: 120.0,
+        start: Duration::ZERO,
+        duration: None,
+    }
+}
+
+fn create_range(length: Option<u64>) -> FlacRange {
+    FlacRange {
+        sample_rate: 44100,
+        bits_per_sample: 16,
+        channels: 1,
+        start: 0.0,
+        length,
+    }
+}
+
+#[test]
+#[allow(clippy::as_conversions)]
+fn column_analyzer_streaming_matches_buffered() {
+    // Arrange
+    let samples = create_samples(50 * 256 * 3 + 100);
+    let settings = create_settings();
+    let mut streaming = ColumnAnalyzer::new(&settings, &create_range(Some(samples.len() as u64)));
+    let mut buffered = ColumnAnalyzer::new(&settings, &create_range(None));
+
+    // Act
+    for block in samples.chunks(4096) {
+        streaming.push(block);
+        buffered.push(block);
+    }
+    let streaming = streaming.finish();
+    let buffered = buffered.finish();
+
+    // Assert
+    assert_eq!(streaming.power.len(), 50);
+    assert_eq!(streaming.power, buffered.power);
+    assert!((streaming.duration - buffered.duration).abs() < f64::EPSILON);
+}
+
+#[test]
+#[allow(clippy::as_conversions)]
+fn column_analyzer_short_columns_overlap() {
+    // Arrange
+    let samples = create_samples(50 * 100);
+    let settings = create_settings();
+    let mut analyzer = ColumnAnalyzer::new(&settings, &create_range(Some(samples.len() as u64)));
+
+    // Act
+    analyzer.push(&samples);
+    let columns = analyzer.finish();
+
+    // Assert
+    assert_eq!(columns.power.len(), 50);
+    assert!(columns
+        .power
+        .iter()
+        .all(|power| power.iter().any(|value| *value > 0.0)));
+}
@@ -0,0 +1,52 @@
+use image::ImageReader;
+
+use crate::spectrogram::*;
+use crate::testing::TempDirectory;
+
+#[test]
+fn contact_sheet_create() {
+    // Arrange
+    let dir = TempDirectory::create("contact_sheet");
+    let items: Vec<ContactSheetItem> = ["01 First", "02 Second", "03 Third"]
+        .iter()
+        .map(|label| {
+            let path = dir.join(format!("{label}.full.png"));
+            Canvas::new(300, 60, [255, 0, 0])
+                .save_png(&path)
+                .expect("should save image");
+            ContactSheetItem {
+                label: (*label).to_owned(),
+                path,
+            }
+        })
+        .collect();
+    let output_path = dir.join("contact-sheet.png");
+
+    // Act
+    let result = ContactSheet::create(&items, &output_path);
+
+    // Assert
+    assert!(result.is_ok());
+    let image = ImageReader::open(&output_path)
+        .expect("should open contact sheet")
+        .decode()
+        .expect("should decode contact sheet")
+        .to_rgb8();
+    assert_eq!(image.width(), 2 * 1004 + 4);
+    assert_eq!(image.height(), 2 * (16 + 200 + 4) + 4);
+    assert_eq!(image.get_pixel(500, 4 + 16 + 100).0, [255, 0, 0]);
+    assert_eq!(image.get_pixel(1500, 224 + 16 + 100).0, [0, 0, 0]);
+}
+
+#[test]
+fn contact_sheet_create_empty() {
+    // Arrange
+    let output_path = TempDirectory::create("contact_sheet").join("contact-sheet.png");
+
+    // Act
+    let result = ContactSheet::create(&[], &output_path);
+
+    // Assert
+    assert!(result.is_err());
+    assert!(!output_path.exists());
+}
@@ -1 +1,7 @@
+mod column_analyzer_tests;
+mod contact_sheet_tests;
 mod spectrogram_command_tests;
+mod spectrogram_profile_tests;
+mod spectrogram_renderer_tests;
+mod spectrogram_report_tests;
+mod spectrum_tests;
@@ -46,7 +46,8 @@ async fn spectrogram_command() -> Result<(), Error> {
         .read(&source.directory)
         .expect("Should be able to read source dir")
         .len()
-        * 2;
+        * 2
+        + 1;
     assert_eq!(generated_files.len(), expected_file_count);
     Ok(())
 }
@@ -0,0 +1,207 @@
+use std::time::Duration;
+
+use crate::options::{Options, SpectrogramOptions};
+use crate::spectrogram::*;
+
+fn create_options() -> SpectrogramOptions {
+    let mut options = SpectrogramOptions::default();
+    options.apply_defaults();
+    options
+}
+
+#[test]
+fn spectrogram_start_parse() {
+    // Arrange
+    // Act
+    let random = SpectrogramStart::parse("random");
+    let offset = SpectrogramStart::parse("1m30s");
+    let invalid = SpectrogramStart::parse("later");
+
+    // Assert
+    assert_eq!(random, Some(SpectrogramStart::Random));
+    assert_eq!(
+        offset,
+        Some(SpectrogramStart::Offset(Duration::from_secs(90)))
+    );
+    assert_eq!(invalid, None);
+}
+
+#[test]
+fn spectrogram_profile_deserialize() {
+    // Arrange
+    let yaml =
+        "name: stereo\nwidth: 1000\nheight: 257\nchannels: separate\nstart: random\nduration: 5s\n";
+
+    // Act
+    let profile: SpectrogramProfile = serde_yaml::from_str(yaml).expect("should deserialize");
+
+    // Assert
+    assert_eq!(profile.name, "stereo");
+    assert_eq!(profile.channels, ChannelHandling::Separate);
+    assert_eq!(profile.dynamic_range, None);
+    assert_eq!(profile.get_start(), Some(SpectrogramStart::Random));
+    assert_eq!(profile.get_duration(), Ok(Some(Duration::from_secs(5))));
+}
+
+#[test]
+fn spectrogram_options_get_selected_profiles_defaults() {
+    // Arrange
+    let options = create_options();
+
+    // Act
+    let profiles = options.get_selected_profiles();
+
+    // Assert
+    let names: Vec<&str> = profiles.iter().map(|x| x.name.as_str()).collect();
+    assert_eq!(names, vec![FULL_PROFILE, ZOOM_PROFILE]);
+    let zoom = profiles.get(1).expect("zoom profile should exist");
+    assert_eq!(
+        zoom.get_start(),
+        Some(SpectrogramStart::Offset(Duration::from_mins(1)))
+    );
+    assert_eq!(zoom.get_duration(), Ok(Some(Duration::from_secs(2))));
+}
+
+#[test]
+fn spectrogram_options_get_selected_profiles_custom() {
+    // Arrange
+    let mut options = SpectrogramOptions {
+        spectrogram_size: Some(vec!["full".to_owned(), "stereo".to_owned()]),
+        spectrogram_profiles: Some(vec![
+            SpectrogramProfile {
+                name: "full".to_owned(),
+                width: 1500,
+                height: 257,
+                ..SpectrogramProfile::default()
+            },
+            SpectrogramProfile {
+                name: "stereo".to_owned(),
+                width: 800,
+                height: 513,
+                channels: ChannelHandling::Separate,
+                ..SpectrogramProfile::default()
+            },
+        ]),
+        ..SpectrogramOptions::default()
+    };
+    options.apply_defaults();
+
+    // Act
+    let profiles = options.get_selected_profiles();
+
+    // Assert
+    assert_eq!(profiles.len(), 2);
+    let full = profiles.first().expect("full profile should exist");
+    assert_eq!(full.width, 1500);
+    let stereo = profiles.get(1).expect("stereo profile should exist");
+    assert_eq!(stereo.channels, ChannelHandling::Separate);
+    assert!(options.validate());
+}
+
+#[test]
+fn spectrogram_options_validate_unknown_profile() {
+    // Arrange
+    let mut options = SpectrogramOptions {
+        spectrogram_size: Some(vec!["huge".to_owned()]),
+        ..SpectrogramOptions::default()
+    };
+    options.apply_defaults();
+
+    // Act
+    let is_valid = options.validate();
+
+    // Assert
+    assert!(!is_valid);
+}
+
+#[test]
+fn spectrogram_options_validate_profile_names() {
+    // Arrange
+    let profile = |name: &str| SpectrogramProfile {
+        name: name.to_owned(),
+        width: 800,
+        height: 257,
+        ..SpectrogramProfile::default()
+    };
+    let names = [
+        vec![profile("")],
+        vec![profile("a/b")],
+        vec![profile("a\\b")],
+        vec![profile("stereo"), profile("stereo")],
+    ];
+
+    // Act
+    let results: Vec<bool> = names
+        .into_iter()
+        .map(|profiles| {
+            let mut options = SpectrogramOptions {
+                spectrogram_profiles: Some(profiles),
+                ..SpectrogramOptions::default()
+            };
+            options.apply_defaults();
+            options.validate()
+        })
+        .collect();
+
+    // Assert
+    assert_eq!(results, vec![false, false, false, false]);
+}
+
+#[test]
+fn spectrogram_options_validate_random_zoom_start() {
+    // Arrange
+    let mut options = SpectrogramOptions {
+        spectrogram_zoom_start: Some("random".to_owned()),
+        ..SpectrogramOptions::default()
+    };
+    options.apply_defaults();
+
+    // Act
+    let is_valid = options.validate();
+    let profiles = options.get_selected_profiles();
+
+    // Assert
+    assert!(is_valid);
+    let zoom = profiles.get(1).expect("zoom profile should exist");
+    assert_eq!(zoom.get_start(), Some(SpectrogramStart::Random));
+}
+
+#[test]
+fn spectrogram_options_get_contact_sheet_profile() {
+    // Arrange
+    let mut options = SpectrogramOptions {
+        spectrogram_size: Some(vec![
+            "zoom".to_owned(),
+            "wide".to_owned(),
+            "narrow".to_owned(),
+        ]),
+        spectrogram_profiles: Some(vec![
+            SpectrogramProfile {
+                name: "wide".to_owned(),
+                width: 2000,
+                height: 513,
+                ..SpectrogramProfile::default()
+            },
+            SpectrogramProfile {
+                name: "narrow".to_owned(),
+                width: 1000,
+                height: 513,
+                ..SpectrogramProfile::default()
+            },
+        ]),
+        ..SpectrogramOptions::default()
+    };
+    options.apply_defaults();
+
+    // Act
+    let profile = options.get_contact_sheet_profile();
+    let zoom_only = SpectrogramOptions {
+        spectrogram_size: Some(vec!["zoom".to_owned()]),
+        ..options.clone()
+    }
+    .get_contact_sheet_profile();
+
+    // Assert
+    assert_eq!(profile, Some("wide".to_owned()));
+    assert_eq!(zoom_only, Some(ZOOM_PROFILE.to_owned()));
+}
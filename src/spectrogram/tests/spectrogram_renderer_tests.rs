@@ -0,0 +1,97 @@
+use std::f64::consts::PI;
+use std::time::Duration;
+
+use crate::spectrogram::palette::BACKGROUND;
+use crate::spectrogram::*;
+
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation
+)]
+fn create_columns(
+    frequency: f64,
+    seconds: usize,
+    settings: &SpectrogramSettings,
+) -> SpectrogramColumns {
+    let sample_rate = 44100;
+    let samples: Vec<f32> = (0..seconds * 44100)
+        .map(|index| {
+            let time = index as f64 / f64::from(sample_rate);
+            (0.5 * (2.0 * PI * frequency * time).sin()) as f32
+        })
+        .collect();
+    let range = FlacRange {
+        sample_rate,
+        bits_per_sample: 16,
+        channels: 1,
+        start: 0.0,
+        length: Some(samples.len() as u64),
+    };
+    let mut analyzer = ColumnAnalyzer::new(settings, &range);
+    analyzer.push(&samples);
+    analyzer.finish()
+}
+
+fn create_settings() -> SpectrogramSettings {
+    SpectrogramSettings {
+        width: 200,
+        height: 257,
+        window_size: None,
+        dynamic_range: 120.0,
+        start: Duration::ZERO,
+        duration: None,
+    }
+}
+
+#[test]
+fn render_has_sox_layout() {
+    // Arrange
+    let settings = create_settings();
+    let columns = create_columns(1000.0, 2, &settings);
+
+    // Act
+    let canvas = SpectrogramRenderer::render(&columns, &settings, "Title", "comment");
+
+    // Assert
+    assert_eq!(canvas.width, 58 + 200 + 90);
+    assert_eq!(canvas.height, 47 + 257 + 48);
+    assert_eq!(canvas.get_pixel(0, 0), Some(BACKGROUND));
+}
+
+#[test]
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn render_plots_tone_at_frequency() {
+    // Arrange
+    let frequency = 11025.0;
+    let settings = create_settings();
+    let columns = create_columns(frequency, 2, &settings);
+
+    // Act
+    let canvas = SpectrogramRenderer::render(&columns, &settings, "Title", "comment");
+
+    // Assert
+    let row = (frequency / 22050.0 * 256.0).round() as usize;
+    let y = 47 + 256 - row;
+    let x = 58 + 100;
+    let tone = canvas.get_pixel(x, y).expect("pixel should exist");
+    let silence = canvas.get_pixel(x, 47 + 10).expect("pixel should exist");
+    let brightness = |pixel: [u8; 3]| pixel.iter().map(|value| u32::from(*value)).sum::<u32>();
+    assert!(brightness(tone) > brightness(silence) + 300);
+}
+
+#[test]
+fn get_color_fades_from_black_to_white() {
+    // Arrange
+    // Act
+    let silent = palette::get_color(0.0);
+    let loud = palette::get_color(1.0);
+
+    // Assert
+    assert_eq!(silent, [0, 0, 0]);
+    assert_eq!(loud, [255, 255, 255]);
+}
@@ -0,0 +1,38 @@
+use std::path::{Path, PathBuf};
+
+use crate::spectrogram::*;
+
+#[test]
+fn spectrogram_report_render() {
+    // Arrange
+    let tracks = vec![ReportTrack {
+        name: "CD1/01 Rock & Roll".to_owned(),
+        sample_rate: 44100,
+        bits_per_sample: 16,
+        channels: 2,
+        duration: Some(185),
+        bit_rate: Some(1_411_200),
+        images: vec![
+            PathBuf::from("CD1/01 Rock & Roll #1.full.png"),
+            PathBuf::from("CD1/01 Rock & Roll #1.zoom.png"),
+        ],
+    }];
+
+    // Act
+    let html = SpectrogramReport::render(
+        "Artist - Album <2020>",
+        Some(Path::new(CONTACT_SHEET_FILE_NAME)),
+        &tracks,
+    )
+    .expect("should render");
+
+    // Assert
+    assert!(html.contains("<title>Artist - Album &lt;2020&gt;</title>"));
+    assert!(html.contains("<img src=\"contact-sheet.png\" alt=\"Contact sheet\">"));
+    assert!(html.contains("<li><a href=\"#track-1\">CD1&#x2f;01 Rock &amp; Roll</a></li>"));
+    assert!(html.contains("<tr><th>Sample rate</th><td>44100 Hz</td></tr>"));
+    assert!(html.contains("<tr><th>Bit depth</th><td>16 bit</td></tr>"));
+    assert!(html.contains("<tr><th>Duration</th><td>3:05</td></tr>"));
+    assert!(html.contains("<tr><th>Bitrate</th><td>1411 kb/s</td></tr>"));
+    assert!(html.contains("src=\"CD1&#x2f;01%20Rock%20&amp;%20Roll%20%231.zoom.png\""));
+}
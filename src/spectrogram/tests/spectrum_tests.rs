@@ -0,0 +1,50 @@
+use std::f64::consts::PI;
+
+use crate::spectrogram::*;
+
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation
+)]
+fn create_sine(frequency: f64, sample_rate: u32, count: usize) -> Vec<f32> {
+    (0..count)
+        .map(|index| {
+            let time = index as f64 / f64::from(sample_rate);
+            (2.0 * PI * frequency * time).sin() as f32
+        })
+        .collect()
+}
+
+#[test]
+fn spectrum_full_scale_sine_is_zero_db() {
+    // Arrange
+    let window_size = 1024;
+    let bin = 100;
+    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+    let frequency = bin as f64 * 44100.0 / window_size as f64;
+    let samples = create_sine(frequency, 44100, window_size * 4);
+    let mut analyzer = SpectrumAnalyzer::new(window_size, 120.0);
+
+    // Act
+    let power = analyzer.get_average_power(&samples);
+
+    // Assert
+    assert_eq!(power.len(), 513);
+    let peak = power_to_db(*power.get(bin).expect("bin should exist"));
+    assert!(peak.abs() < 0.1, "peak was {peak} dB");
+    let far = power_to_db(*power.get(bin + 50).expect("bin should exist"));
+    assert!(far < -100.0, "side lobe was {far} dB");
+}
+
+#[test]
+fn spectrum_silence_is_below_range() {
+    // Arrange
+    let mut analyzer = SpectrumAnalyzer::new(1024, 120.0);
+
+    // Act
+    let power = analyzer.get_power(&[0.0; 1024]);
+
+    // Assert
+    assert!(power.iter().all(|value| power_to_db(*value) < -120.0));
+}
@@ -0,0 +1,35 @@
+use std::fs::write;
+use std::path::PathBuf;
+
+use crate::testing::TempDirectory;
+use crate::torrent_client::AddTorrentRequest;
+
+/// Info hash of the torrent returned by a mock torrent client.
+pub const TORRENT_HASH: &str = "0123456789abcdef0123456789abcdef01234567";
+
+/// A minimal bencoded torrent file.
+pub const TORRENT_BUFFER: &[u8] = b"d4:infod4:name4:testee";
+
+/// [`TORRENT_BUFFER`] encoded as base64 as sent to an RPC torrent client.
+pub const TORRENT_BUFFER_BASE64: &str = "ZDQ6aW5mb2Q0Om5hbWU0OnRlc3RlZQ==";
+
+/// Create a request to add [`TORRENT_BUFFER`] to a torrent client.
+#[must_use]
+pub fn create_add_torrent_request() -> AddTorrentRequest {
+    AddTorrentRequest {
+        buffer: TORRENT_BUFFER.to_vec(),
+        file_name: "Artist - Album [2020] [CD 320].red.torrent".to_owned(),
+        save_path: PathBuf::from("/srv/output"),
+        category: Some("caesura".to_owned()),
+    }
+}
+
+/// Create an image to upload to an image host.
+///
+/// Only the PNG signature is written as image hosts are mocked.
+#[must_use]
+pub fn create_png(sub_dir_name: &str, file_name: &str) -> PathBuf {
+    let path = TempDirectory::create(sub_dir_name).join(file_name);
+    write(&path, b"\x89PNG\r\n\x1a\n").expect("should write image");
+    path
+}
@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+/// A request received by a [`MockServer`].
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+    pub method: String,
+    /// Path including the query string
+    pub path: String,
+    /// Headers with lowercase names
+    pub headers: BTreeMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl MockRequest {
+    #[must_use]
+    pub fn get_body_text(&self) -> String {
+        String::from_utf8_lossy(&self.body).to_string()
+    }
+
+    #[must_use]
+    pub fn get_body_json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).expect("body should be JSON")
+    }
+
+    /// Get the path without the query string.
+    #[must_use]
+    pub fn get_route(&self) -> &str {
+        self.path.split('?').next().unwrap_or_default()
+    }
+
+    /// Get the `method` of a JSON-RPC request body.
+    #[must_use]
+    pub fn get_rpc_method(&self) -> Option<String> {
+        self.get_body_json()
+            .get("method")
+            .and_then(|method| method.as_str())
+            .map(ToOwned::to_owned)
+    }
+}
+
+/// A response returned by a [`MockServer`].
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    #[must_use]
+    pub fn text(body: &str) -> Self {
+        Self {
+            status: 200,
+            headers: vec![("Content-Type".to_owned(), "text/plain".to_owned())],
+            body: body.to_owned(),
+        }
+    }
+
+    #[must_use]
+    pub fn json(body: &serde_json::Value) -> Self {
+        Self {
+            status: 200,
+            headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
+            body: body.to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn status(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: String::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+}
+
+type Handler = dyn Fn(&MockRequest) -> MockResponse + Send + Sync;
+
+/// A minimal HTTP server for testing API clients.
+///
+/// Each request is recorded and answered by the handler.
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockServer {
+    pub async fn start<F>(handler: F) -> MockServer
+    where
+        F: Fn(&MockRequest) -> MockResponse + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("should be able to bind");
+        let address = listener.local_addr().expect("should have an address");
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let handler: Arc<Handler> = Arc::new(handler);
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let handler = handler.clone();
+                let recorded = recorded.clone();
+                tokio::spawn(async move {
+                    handle_connection(stream, handler, recorded).await;
+                });
+            }
+        });
+        MockServer {
+            url: format!("http://{address}"),
+            requests,
+        }
+    }
+
+    /// Get the requests received so far.
+    #[must_use]
+    pub fn get_requests(&self) -> Vec<MockRequest> {
+        self.requests
+            .lock()
+            .expect("requests should be available")
+            .clone()
+    }
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    handler: Arc<Handler>,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+) {
+    let mut reader = BufReader::new(stream);
+    while let Some(request) = read_request(&mut reader).await {
+        let response = handler(&request);
+        requests
+            .lock()
+            .expect("requests should be available")
+            .push(request);
+        let mut output = format!("HTTP/1.1 {} MOCK\r\n", response.status);
+        for (name, value) in &response.headers {
+            let _ = write!(output, "{name}: {value}\r\n");
+        }
+        let _ = write!(output, "Content-Length: {}\r\n\r\n", response.body.len());
+        output.push_str(&response.body);
+        if reader.get_mut().write_all(output.as_bytes()).await.is_err() {
+            return;
+        }
+    }
+}
+
+async fn read_request(reader: &mut BufReader<TcpStream>) -> Option<MockRequest> {
+    let mut line = String::new();
+    reader.read_line(&mut line).await.ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_owned();
+    let path = parts.next()?.to_owned();
+    let mut headers = BTreeMap::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).await.ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line.split_once(':')?;
+        headers.insert(name.trim().to_lowercase(), value.trim().to_owned());
+    }
+    let mut body = Vec::new();
+    if let Some(length) = headers.get("content-length") {
+        let length: usize = length.parse().ok()?;
+        body.resize(length, 0);
+        reader.read_exact(&mut body).await.ok()?;
+    } else if headers.get("transfer-encoding").map(String::as_str) == Some("chunked") {
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).await.ok()?;
+            let size = usize::from_str_radix(line.trim(), 16).ok()?;
+            let mut chunk = vec![0; size + 2];
+            reader.read_exact(&mut chunk).await.ok()?;
+            chunk.truncate(size);
+            body.extend(chunk);
+            if size == 0 {
+                break;
+            }
+        }
+    }
+    Some(MockRequest {
+        method,
+        path,
+        headers,
+        body,
+    })
+}
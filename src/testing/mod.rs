@@ -1,4 +1,10 @@
+pub use fixtures::*;
 pub use fs::*;
+pub use mock_server::*;
+pub use signal::*;
 
+pub(crate) mod fixtures;
 pub(crate) mod fs;
+pub(crate) mod mock_server;
 pub(crate) mod options;
+pub(crate) mod signal;
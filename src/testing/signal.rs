@@ -0,0 +1,43 @@
+use std::f64::consts::PI;
+
+use crate::spectrogram::DecodedAudio;
+
+/// Create `seconds` of audio with a sine every `step` Hz from `step` up to `max_frequency`.
+///
+/// Each sine starts at a different phase so their peaks don't align, and the sum is scaled
+/// by `amplitude`.
+#[must_use]
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation
+)]
+pub fn create_sines(
+    sample_rate: u32,
+    bits_per_sample: u32,
+    seconds: u32,
+    step: u32,
+    max_frequency: u32,
+    amplitude: f64,
+) -> DecodedAudio {
+    let frequencies: Vec<f64> = (step..=max_frequency)
+        .step_by(step as usize)
+        .map(f64::from)
+        .collect();
+    let samples = (0..sample_rate * seconds)
+        .map(|index| {
+            let time = f64::from(index) / f64::from(sample_rate);
+            let sum: f64 = frequencies
+                .iter()
+                .enumerate()
+                .map(|(offset, frequency)| (2.0 * PI * frequency * time + offset as f64).sin())
+                .sum();
+            (sum * amplitude) as f32
+        })
+        .collect();
+    DecodedAudio {
+        samples,
+        sample_rate,
+        bits_per_sample,
+    }
+}
@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+
+/// A `.torrent` file to add to a torrent client.
+pub struct AddTorrentRequest {
+    /// Content of the `.torrent` file
+    pub buffer: Vec<u8>,
+    /// File name of the `.torrent` file
+    pub file_name: String,
+    /// Directory containing the torrent content.
+    ///
+    /// This is the parent of the transcode directory.
+    pub save_path: PathBuf,
+    /// Category or label to assign
+    pub category: Option<String>,
+}
@@ -0,0 +1,14 @@
+use std::path::PathBuf;
+
+/// A torrent as reported by a torrent client.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClientTorrent {
+    /// Info hash
+    pub hash: String,
+    /// Torrent name
+    pub name: String,
+    /// Directory containing the torrent content
+    pub save_path: PathBuf,
+    /// Path of the `.torrent` file if the client exposes it
+    pub torrent_file: Option<PathBuf>,
+}
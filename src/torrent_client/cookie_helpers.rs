@@ -0,0 +1,14 @@
+use reqwest::header::SET_COOKIE;
+use reqwest::Response;
+
+/// Get the `name=value` pair of the first cookie set by a response.
+#[must_use]
+pub fn get_cookie(response: &Response) -> Option<String> {
+    let header = response.headers().get(SET_COOKIE)?.to_str().ok()?;
+    let cookie = header.split(';').next()?.trim();
+    if cookie.is_empty() {
+        None
+    } else {
+        Some(cookie.to_owned())
+    }
+}
@@ -0,0 +1,188 @@
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use colored::Colorize;
+use log::trace;
+use reqwest::header::COOKIE;
+use reqwest::{Client, Response};
+use rogue_logging::Error;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::errors::{error, http_error, json_error};
+use crate::torrent_client::{get_cookie, AddTorrentRequest, TorrentProgress};
+
+/// Client for the Deluge Web JSON-RPC.
+///
+/// <https://deluge.readthedocs.io/en/latest/reference/webapi.html>
+pub struct DelugeClient {
+    url: String,
+    password: Option<String>,
+    client: Client,
+    cookie: Option<String>,
+    request_id: u32,
+}
+
+#[derive(Deserialize)]
+struct RpcResponse {
+    result: Value,
+    error: Option<RpcError>,
+}
+
+#[derive(Deserialize)]
+struct RpcError {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct DelugeTorrent {
+    progress: f64,
+    state: String,
+}
+
+impl DelugeClient {
+    #[must_use]
+    pub fn new(url: String, password: Option<String>) -> Self {
+        Self {
+            url,
+            password,
+            client: Client::new(),
+            cookie: None,
+            request_id: 0,
+        }
+    }
+
+    /// Add a `.torrent` file to the client.
+    pub async fn add(&mut self, request: &AddTorrentRequest) -> Result<(), Error> {
+        self.login().await?;
+        let action = "add torrent to Deluge";
+        let params = json!([
+            request.file_name,
+            BASE64_STANDARD.encode(&request.buffer),
+            {
+                "download_location": request.save_path.to_string_lossy(),
+                "add_paused": false,
+            }
+        ]);
+        let hash: Option<String> = self.call("core.add_torrent_file", params, action).await?;
+        let Some(hash) = hash else {
+            return Err(error(
+                action,
+                "Deluge did not return the hash of the added torrent".to_owned(),
+            ));
+        };
+        if let Some(category) = &request.category {
+            // Deluge responds with an error if the label exists so failures are ignored
+            let result: Result<Value, Error> = self
+                .call("label.add", json!([category]), "add Deluge label")
+                .await;
+            if let Err(error) = result {
+                trace!("{error}");
+            }
+            let _: Value = self
+                .call(
+                    "label.set_torrent",
+                    json!([hash, category]),
+                    "set Deluge label",
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Get the progress of a torrent by its info hash.
+    ///
+    /// Returns `None` if the client does not have the torrent.
+    pub async fn get_progress(&mut self, hash: &str) -> Result<Option<TorrentProgress>, Error> {
+        self.login().await?;
+        let params = json!([hash, ["progress", "state"]]);
+        let torrent: Value = self
+            .call("core.get_torrent_status", params, "get torrent from Deluge")
+            .await?;
+        if torrent.as_object().is_none_or(serde_json::Map::is_empty) {
+            return Ok(None);
+        }
+        let torrent: DelugeTorrent = serde_json::from_value(torrent)
+            .map_err(|e| json_error(e, "deserialize Deluge torrent"))?;
+        trace!("Deluge reports {hash} as {}", torrent.state);
+        Ok(Some(TorrentProgress {
+            progress: torrent.progress / 100.0,
+            checking: matches!(torrent.state.as_str(), "Checking" | "Allocating" | "Moving"),
+        }))
+    }
+
+    /// Log in to the web interface and ensure it is connected to a daemon.
+    async fn login(&mut self) -> Result<(), Error> {
+        if self.cookie.is_some() {
+            return Ok(());
+        }
+        let action = "log in to Deluge";
+        let password = self.password.clone().unwrap_or_default();
+        let response = self.send("auth.login", json!([password]), action).await?;
+        let cookie = get_cookie(&response);
+        let accepted: bool = Self::read(response, action).await?;
+        if !accepted || cookie.is_none() {
+            return Err(error(action, "Password was not accepted".to_owned()));
+        }
+        self.cookie = cookie;
+        trace!("{} in to Deluge", "Logged".bold());
+        let connected: bool = self
+            .call("web.connected", json!([]), "check Deluge connection")
+            .await?;
+        if !connected {
+            let hosts: Vec<Vec<Value>> = self
+                .call("web.get_hosts", json!([]), "get Deluge hosts")
+                .await?;
+            let Some(host_id) = hosts.first().and_then(|host| host.first()).cloned() else {
+                return Err(error(
+                    "connect to Deluge daemon",
+                    "Deluge web interface has no hosts".to_owned(),
+                ));
+            };
+            let _: Value = self
+                .call("web.connect", json!([host_id]), "connect to Deluge daemon")
+                .await?;
+            trace!("{} to Deluge daemon", "Connected".bold());
+        }
+        Ok(())
+    }
+
+    async fn call<T: DeserializeOwned>(
+        &mut self,
+        method: &str,
+        params: Value,
+        action: &str,
+    ) -> Result<T, Error> {
+        let response = self.send(method, params, action).await?;
+        Self::read(response, action).await
+    }
+
+    async fn send(&mut self, method: &str, params: Value, action: &str) -> Result<Response, Error> {
+        self.request_id += 1;
+        let body = json!({
+            "method": method,
+            "params": params,
+            "id": self.request_id,
+        });
+        let mut builder = self.client.post(format!("{}/json", self.url)).json(&body);
+        if let Some(cookie) = &self.cookie {
+            builder = builder.header(COOKIE, cookie);
+        }
+        builder
+            .send()
+            .await
+            .and_then(Response::error_for_status)
+            .map_err(|e| http_error(e, action))
+    }
+
+    async fn read<T: DeserializeOwned>(response: Response, action: &str) -> Result<T, Error> {
+        let response: RpcResponse = response.json().await.map_err(|e| http_error(e, action))?;
+        if let Some(rpc_error) = response.error {
+            return Err(error(
+                action,
+                format!("Deluge responded with: {}", rpc_error.message),
+            ));
+        }
+        serde_json::from_value(response.result).map_err(|e| json_error(e, action))
+    }
+}
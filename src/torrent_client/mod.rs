@@ -0,0 +1,23 @@
+pub use add_torrent_request::*;
+pub use client_torrent::*;
+pub use cookie_helpers::*;
+pub use deluge_client::*;
+pub use qbittorrent_client::*;
+pub use torrent_client::*;
+pub use torrent_client_factory::*;
+pub use torrent_client_kind::*;
+pub use torrent_progress::*;
+pub use transmission_client::*;
+
+pub(crate) mod add_torrent_request;
+pub(crate) mod client_torrent;
+pub(crate) mod cookie_helpers;
+pub(crate) mod deluge_client;
+pub(crate) mod qbittorrent_client;
+#[cfg(test)]
+mod tests;
+pub(crate) mod torrent_client;
+pub(crate) mod torrent_client_factory;
+pub(crate) mod torrent_client_kind;
+pub(crate) mod torrent_progress;
+pub(crate) mod transmission_client;
@@ -0,0 +1,220 @@
+use std::path::PathBuf;
+
+use colored::Colorize;
+use log::trace;
+use reqwest::header::COOKIE;
+use reqwest::multipart::{Form, Part};
+use reqwest::{Client, RequestBuilder, Response};
+use rogue_logging::Error;
+use serde::Deserialize;
+
+use crate::errors::{error, http_error};
+use crate::torrent_client::{get_cookie, AddTorrentRequest, ClientTorrent, TorrentProgress};
+
+const SUCCESS_RESPONSE: &str = "Ok.";
+
+/// Client for the qBittorrent Web API.
+///
+/// <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)>
+pub struct QBittorrentClient {
+    url: String,
+    username: Option<String>,
+    password: Option<String>,
+    client: Client,
+    cookie: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct QBittorrentTorrent {
+    #[serde(default)]
+    hash: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    save_path: PathBuf,
+    progress: f64,
+    state: String,
+}
+
+impl QBittorrentClient {
+    #[must_use]
+    pub fn new(url: String, username: Option<String>, password: Option<String>) -> Self {
+        Self {
+            url,
+            username,
+            password,
+            client: Client::new(),
+            cookie: None,
+        }
+    }
+
+    /// Add a `.torrent` file to the client.
+    pub async fn add(&mut self, request: &AddTorrentRequest) -> Result<(), Error> {
+        self.login().await?;
+        if let Some(category) = &request.category {
+            self.create_category(category).await;
+        }
+        let part = Part::bytes(request.buffer.clone())
+            .file_name(request.file_name.clone())
+            .mime_str("application/x-bittorrent")
+            .map_err(|e| http_error(e, "create qBittorrent request"))?;
+        let mut form = Form::new()
+            .part("torrents", part)
+            .text("savepath", request.save_path.to_string_lossy().to_string())
+            // Automatic Torrent Management ignores `savepath` so must be disabled
+            .text("autoTMM", "false")
+            .text("paused", "false")
+            .text("stopped", "false");
+        if let Some(category) = &request.category {
+            form = form.text("category", category.clone());
+        }
+        let url = format!("{}/api/v2/torrents/add", self.url);
+        let body = self
+            .with_cookie(self.client.post(url))
+            .multipart(form)
+            .send()
+            .await
+            .and_then(Response::error_for_status)
+            .map_err(|e| http_error(e, "add torrent to qBittorrent"))?
+            .text()
+            .await
+            .map_err(|e| http_error(e, "add torrent to qBittorrent"))?;
+        if body.trim() != SUCCESS_RESPONSE {
+            return Err(error(
+                "add torrent to qBittorrent",
+                format!("qBittorrent rejected the torrent: {body}"),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Get the progress of a torrent by its info hash.
+    ///
+    /// Returns `None` if the client does not have the torrent.
+    pub async fn get_progress(&mut self, hash: &str) -> Result<Option<TorrentProgress>, Error> {
+        self.login().await?;
+        let url = format!("{}/api/v2/torrents/info", self.url);
+        let torrents: Vec<QBittorrentTorrent> = self
+            .with_cookie(self.client.get(url))
+            .query(&[("hashes", hash)])
+            .send()
+            .await
+            .and_then(Response::error_for_status)
+            .map_err(|e| http_error(e, "get torrent from qBittorrent"))?
+            .json()
+            .await
+            .map_err(|e| http_error(e, "deserialize qBittorrent torrent"))?;
+        let progress = torrents.first().map(|torrent| {
+            trace!("qBittorrent reports {hash} as {}", torrent.state);
+            TorrentProgress {
+                progress: torrent.progress,
+                checking: torrent.state.starts_with("checking")
+                    || torrent.state == "allocating"
+                    || torrent.state == "moving",
+            }
+        });
+        Ok(progress)
+    }
+
+    /// Get the torrents that have completed downloading.
+    pub async fn get_completed(&mut self) -> Result<Vec<ClientTorrent>, Error> {
+        self.login().await?;
+        let url = format!("{}/api/v2/torrents/info", self.url);
+        let torrents: Vec<QBittorrentTorrent> = self
+            .with_cookie(self.client.get(url))
+            .query(&[("filter", "completed")])
+            .send()
+            .await
+            .and_then(Response::error_for_status)
+            .map_err(|e| http_error(e, "get torrents from qBittorrent"))?
+            .json()
+            .await
+            .map_err(|e| http_error(e, "deserialize qBittorrent torrents"))?;
+        let torrents = torrents
+            .into_iter()
+            .map(|torrent| ClientTorrent {
+                hash: torrent.hash,
+                name: torrent.name,
+                save_path: torrent.save_path,
+                torrent_file: None,
+            })
+            .collect();
+        Ok(torrents)
+    }
+
+    /// Export the `.torrent` file of a torrent.
+    pub async fn get_torrent_file(&mut self, torrent: &ClientTorrent) -> Result<Vec<u8>, Error> {
+        self.login().await?;
+        let url = format!("{}/api/v2/torrents/export", self.url);
+        let bytes = self
+            .with_cookie(self.client.get(url))
+            .query(&[("hash", torrent.hash.as_str())])
+            .send()
+            .await
+            .and_then(Response::error_for_status)
+            .map_err(|e| http_error(e, "export torrent from qBittorrent"))?
+            .bytes()
+            .await
+            .map_err(|e| http_error(e, "export torrent from qBittorrent"))?;
+        Ok(bytes.to_vec())
+    }
+
+    async fn login(&mut self) -> Result<(), Error> {
+        if self.cookie.is_some() || (self.username.is_none() && self.password.is_none()) {
+            return Ok(());
+        }
+        let url = format!("{}/api/v2/auth/login", self.url);
+        let form = [
+            ("username", self.username.clone().unwrap_or_default()),
+            ("password", self.password.clone().unwrap_or_default()),
+        ];
+        let response = self
+            .client
+            .post(url)
+            .form(&form)
+            .send()
+            .await
+            .and_then(Response::error_for_status)
+            .map_err(|e| http_error(e, "log in to qBittorrent"))?;
+        let cookie = get_cookie(&response);
+        let body = response
+            .text()
+            .await
+            .map_err(|e| http_error(e, "log in to qBittorrent"))?;
+        if body.trim() != SUCCESS_RESPONSE || cookie.is_none() {
+            return Err(error(
+                "log in to qBittorrent",
+                "Username or password was not accepted".to_owned(),
+            ));
+        }
+        trace!("{} in to qBittorrent", "Logged".bold());
+        self.cookie = cookie;
+        Ok(())
+    }
+
+    /// Create the category if it does not exist.
+    ///
+    /// qBittorrent responds with a conflict if the category exists so failures are ignored.
+    async fn create_category(&self, category: &str) {
+        let url = format!("{}/api/v2/torrents/createCategory", self.url);
+        let result = self
+            .with_cookie(self.client.post(url))
+            .form(&[("category", category)])
+            .send()
+            .await;
+        if let Ok(response) = result {
+            trace!(
+                "{} qBittorrent category {category}: {}",
+                "Creating".bold(),
+                response.status()
+            );
+        }
+    }
+
+    fn with_cookie(&self, builder: RequestBuilder) -> RequestBuilder {
+        match &self.cookie {
+            Some(cookie) => builder.header(COOKIE, cookie),
+            None => builder,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::testing::{
+    create_add_torrent_request, MockRequest, MockResponse, MockServer, TORRENT_BUFFER_BASE64,
+    TORRENT_HASH,
+};
+use crate::torrent_client::*;
+
+fn handler(request: &MockRequest) -> MockResponse {
+    let body = request.get_body_json();
+    let id = body.get("id").cloned().unwrap_or_default();
+    let result = match request.get_rpc_method().as_deref() {
+        Some("auth.login") => {
+            return MockResponse::json(&json!({ "id": id, "result": true, "error": null }))
+                .with_header("Set-Cookie", "_session_id=abc123; Path=/json");
+        }
+        Some("web.connected") => json!(true),
+        Some("core.add_torrent_file") => json!(TORRENT_HASH),
+        Some("label.add") => {
+            return MockResponse::json(&json!({
+                "id": id,
+                "result": null,
+                "error": { "message": "Label already exists", "code": 4 }
+            }));
+        }
+        Some("core.get_torrent_status") => json!({ "progress": 100.0, "state": "Seeding" }),
+        _ => json!(null),
+    };
+    MockResponse::json(&json!({ "id": id, "result": result, "error": null }))
+}
+
+#[tokio::test]
+async fn deluge_add() {
+    // Arrange
+    let server = MockServer::start(handler).await;
+    let mut client = TorrentClient::Deluge(DelugeClient::new(
+        server.url.clone(),
+        Some("deluge".to_owned()),
+    ));
+
+    // Act
+    let result = client.add(&create_add_torrent_request()).await;
+
+    // Assert
+    assert!(result.is_ok());
+    let methods: Vec<String> = server
+        .get_requests()
+        .iter()
+        .map(|request| request.get_rpc_method().unwrap_or_default())
+        .collect();
+    assert_eq!(
+        methods,
+        vec![
+            "auth.login",
+            "web.connected",
+            "core.add_torrent_file",
+            "label.add",
+            "label.set_torrent",
+        ]
+    );
+    let requests = server.get_requests();
+    let add = requests.get(2).expect("should have add request");
+    assert_eq!(
+        add.headers.get("cookie").map(String::as_str),
+        Some("_session_id=abc123")
+    );
+    let body = add.get_body_json();
+    let params = body.get("params").expect("should have params");
+    assert_eq!(
+        params.get(1).expect("should have torrent"),
+        TORRENT_BUFFER_BASE64
+    );
+    assert_eq!(
+        params
+            .get(2)
+            .and_then(|x| x.get("download_location"))
+            .expect("should have options"),
+        "/srv/output"
+    );
+    let label = requests.get(4).expect("should have label request");
+    assert_eq!(
+        label.get_body_json().get("params"),
+        Some(&json!([TORRENT_HASH, "caesura"]))
+    );
+}
+
+#[tokio::test]
+async fn deluge_wait_for_hash_check() {
+    // Arrange
+    let server = MockServer::start(handler).await;
+    let mut client = TorrentClient::Deluge(DelugeClient::new(
+        server.url.clone(),
+        Some("deluge".to_owned()),
+    ));
+
+    // Act
+    let result = client
+        .wait_for_hash_check(TORRENT_HASH, Duration::ZERO)
+        .await;
+
+    // Assert
+    assert!(result.is_ok());
+}
@@ -0,0 +1,4 @@
+mod deluge_client_tests;
+mod qbittorrent_client_tests;
+mod torrent_client_tests;
+mod transmission_client_tests;
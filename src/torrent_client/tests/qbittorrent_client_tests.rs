@@ -0,0 +1,142 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::testing::{
+    create_add_torrent_request, MockRequest, MockResponse, MockServer, TORRENT_BUFFER, TORRENT_HASH,
+};
+use crate::torrent_client::*;
+
+fn handler(request: &MockRequest) -> MockResponse {
+    match request.get_route() {
+        "/api/v2/auth/login" => {
+            MockResponse::text("Ok.").with_header("Set-Cookie", "SID=abc123; HttpOnly; path=/")
+        }
+        "/api/v2/torrents/createCategory" => MockResponse::status(409),
+        "/api/v2/torrents/add" => MockResponse::text("Ok."),
+        "/api/v2/torrents/info" => MockResponse::json(&json!([{
+            "hash": TORRENT_HASH,
+            "name": "Artist - Album (2020) [FLAC]",
+            "save_path": "/srv/content",
+            "progress": 1.0,
+            "state": "stalledUP",
+        }])),
+        "/api/v2/torrents/export" => MockResponse::text("d4:infod4:name4:testee"),
+        _ => MockResponse::status(404),
+    }
+}
+
+#[tokio::test]
+async fn qbittorrent_add() {
+    // Arrange
+    let server = MockServer::start(handler).await;
+    let mut client = TorrentClient::QBittorrent(QBittorrentClient::new(
+        server.url.clone(),
+        Some("admin".to_owned()),
+        Some("password".to_owned()),
+    ));
+
+    // Act
+    let result = client.add(&create_add_torrent_request()).await;
+
+    // Assert
+    assert!(result.is_ok());
+    let requests = server.get_requests();
+    let login = requests.first().expect("should have login request");
+    assert_eq!(login.path, "/api/v2/auth/login");
+    assert_eq!(login.get_body_text(), "username=admin&password=password");
+    let add = requests
+        .iter()
+        .find(|request| request.path == "/api/v2/torrents/add")
+        .expect("should have add request");
+    assert_eq!(
+        add.headers.get("cookie").map(String::as_str),
+        Some("SID=abc123")
+    );
+    let body = add.get_body_text();
+    assert!(body.contains("name=\"savepath\"\r\n\r\n/srv/output"));
+    assert!(body.contains("name=\"autoTMM\"\r\n\r\nfalse"));
+    assert!(body.contains("name=\"category\"\r\n\r\ncaesura"));
+    assert!(body.contains("d4:infod4:name4:testee"));
+}
+
+#[tokio::test]
+async fn qbittorrent_wait_for_hash_check() {
+    // Arrange
+    let server = MockServer::start(handler).await;
+    let mut client = TorrentClient::QBittorrent(QBittorrentClient::new(
+        server.url.clone(),
+        Some("admin".to_owned()),
+        Some("password".to_owned()),
+    ));
+
+    // Act
+    let result = client
+        .wait_for_hash_check(TORRENT_HASH, Duration::ZERO)
+        .await;
+
+    // Assert
+    assert!(result.is_ok());
+    let requests = server.get_requests();
+    let info = requests.last().expect("should have info request");
+    assert_eq!(
+        info.path,
+        format!("/api/v2/torrents/info?hashes={TORRENT_HASH}")
+    );
+}
+
+#[tokio::test]
+async fn qbittorrent_login_rejected() {
+    // Arrange
+    let server = MockServer::start(|_| MockResponse::text("Fails.")).await;
+    let mut client = TorrentClient::QBittorrent(QBittorrentClient::new(
+        server.url.clone(),
+        Some("admin".to_owned()),
+        Some("wrong".to_owned()),
+    ));
+
+    // Act
+    let result = client.add(&create_add_torrent_request()).await;
+
+    // Assert
+    assert!(result.is_err());
+    assert_eq!(server.get_requests().len(), 1);
+}
+
+#[tokio::test]
+async fn qbittorrent_get_completed() {
+    // Arrange
+    let server = MockServer::start(handler).await;
+    let mut client =
+        TorrentClient::QBittorrent(QBittorrentClient::new(server.url.clone(), None, None));
+
+    // Act
+    let torrents = client.get_completed().await.expect("should get torrents");
+    let torrent = torrents.first().expect("should be one torrent");
+    let buffer = client
+        .get_torrent_file(torrent)
+        .await
+        .expect("should export torrent");
+
+    // Assert
+    assert_eq!(
+        torrents,
+        vec![ClientTorrent {
+            hash: TORRENT_HASH.to_owned(),
+            name: "Artist - Album (2020) [FLAC]".to_owned(),
+            save_path: PathBuf::from("/srv/content"),
+            torrent_file: None,
+        }]
+    );
+    assert_eq!(buffer, TORRENT_BUFFER);
+    let requests = server.get_requests();
+    let paths: Vec<&str> = requests.iter().map(|x| x.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "/api/v2/torrents/info?filter=completed".to_owned(),
+            format!("/api/v2/torrents/export?hash={TORRENT_HASH}"),
+        ]
+    );
+}
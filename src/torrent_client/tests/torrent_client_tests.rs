@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::testing::{MockResponse, MockServer, TORRENT_HASH};
+use crate::torrent_client::*;
+
+#[tokio::test]
+async fn wait_for_hash_check_incomplete() {
+    // Arrange
+    let server = MockServer::start(|_| {
+        MockResponse::json(&json!([{
+            "hash": TORRENT_HASH,
+            "progress": 0.5,
+            "state": "checkingUP",
+        }]))
+    })
+    .await;
+    let mut client =
+        TorrentClient::QBittorrent(QBittorrentClient::new(server.url.clone(), None, None));
+
+    // Act
+    let result = client
+        .wait_for_hash_check(TORRENT_HASH, Duration::ZERO)
+        .await;
+
+    // Assert
+    let error = result.expect_err("should time out");
+    assert!(error.message.contains("50.0%"));
+}
+
+#[tokio::test]
+async fn wait_for_hash_check_missing() {
+    // Arrange
+    let server = MockServer::start(|_| MockResponse::json(&json!([]))).await;
+    let mut client =
+        TorrentClient::QBittorrent(QBittorrentClient::new(server.url.clone(), None, None));
+
+    // Act
+    let result = client
+        .wait_for_hash_check(TORRENT_HASH, Duration::ZERO)
+        .await;
+
+    // Assert
+    assert!(result.is_err());
+}
@@ -0,0 +1,153 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::testing::{
+    create_add_torrent_request, MockRequest, MockResponse, MockServer, TORRENT_BUFFER_BASE64,
+    TORRENT_HASH,
+};
+use crate::torrent_client::*;
+
+const SESSION_ID: &str = "session-123";
+
+fn handler(request: &MockRequest) -> MockResponse {
+    if request
+        .headers
+        .get("x-transmission-session-id")
+        .map(String::as_str)
+        != Some(SESSION_ID)
+    {
+        return MockResponse::status(409).with_header("X-Transmission-Session-Id", SESSION_ID);
+    }
+    match request.get_rpc_method().as_deref() {
+        Some("torrent-add") => MockResponse::json(&json!({
+            "result": "success",
+            "arguments": {
+                "torrent-added": { "hashString": TORRENT_HASH, "id": 1, "name": "test" }
+            }
+        })),
+        Some("torrent-get") => MockResponse::json(&json!({
+            "result": "success",
+            "arguments": {
+                "torrents": [
+                    {
+                        "hashString": TORRENT_HASH,
+                        "name": "Complete",
+                        "downloadDir": "/srv/content",
+                        "torrentFile": "/config/torrents/complete.torrent",
+                        "percentDone": 1.0,
+                        "status": 6
+                    },
+                    {
+                        "hashString": "fedcba9876543210fedcba9876543210fedcba98",
+                        "name": "Incomplete",
+                        "downloadDir": "/srv/content",
+                        "percentDone": 0.5,
+                        "status": 4
+                    }
+                ]
+            }
+        })),
+        _ => MockResponse::json(&json!({ "result": "method name not recognized" })),
+    }
+}
+
+#[tokio::test]
+async fn transmission_add() {
+    // Arrange
+    let server = MockServer::start(handler).await;
+    let mut client = TorrentClient::Transmission(TransmissionClient::new(
+        server.url.clone(),
+        Some("admin".to_owned()),
+        Some("password".to_owned()),
+    ));
+
+    // Act
+    let result = client.add(&create_add_torrent_request()).await;
+
+    // Assert
+    assert!(result.is_ok());
+    let requests = server.get_requests();
+    assert_eq!(requests.len(), 2);
+    let add = requests.last().expect("should have add request");
+    assert_eq!(add.path, "/transmission/rpc");
+    assert!(add.headers.contains_key("authorization"));
+    let body = add.get_body_json();
+    assert_eq!(body.get("method"), Some(&json!("torrent-add")));
+    let arguments = body.get("arguments").expect("should have arguments");
+    assert_eq!(
+        arguments.get("metainfo"),
+        Some(&json!(TORRENT_BUFFER_BASE64))
+    );
+    assert_eq!(arguments.get("download-dir"), Some(&json!("/srv/output")));
+    assert_eq!(arguments.get("labels"), Some(&json!(["caesura"])));
+}
+
+#[tokio::test]
+async fn transmission_wait_for_hash_check() {
+    // Arrange
+    let server = MockServer::start(handler).await;
+    let mut client =
+        TorrentClient::Transmission(TransmissionClient::new(server.url.clone(), None, None));
+
+    // Act
+    let result = client
+        .wait_for_hash_check(TORRENT_HASH, Duration::ZERO)
+        .await;
+
+    // Assert
+    assert!(result.is_ok());
+    let requests = server.get_requests();
+    let get = requests.last().expect("should have get request");
+    let body = get.get_body_json();
+    assert_eq!(body.get("method"), Some(&json!("torrent-get")));
+    assert_eq!(
+        body.get("arguments").and_then(|x| x.get("ids")),
+        Some(&json!([TORRENT_HASH]))
+    );
+}
+
+#[tokio::test]
+async fn transmission_get_completed() {
+    // Arrange
+    let server = MockServer::start(handler).await;
+    let mut client =
+        TorrentClient::Transmission(TransmissionClient::new(server.url.clone(), None, None));
+
+    // Act
+    let torrents = client.get_completed().await;
+
+    // Assert
+    assert_eq!(
+        torrents.expect("should get torrents"),
+        vec![ClientTorrent {
+            hash: TORRENT_HASH.to_owned(),
+            name: "Complete".to_owned(),
+            save_path: PathBuf::from("/srv/content"),
+            torrent_file: Some(PathBuf::from("/config/torrents/complete.torrent")),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn transmission_get_torrent_file_not_accessible() {
+    // Arrange
+    let server = MockServer::start(handler).await;
+    let mut client = TransmissionClient::new(server.url.clone(), None, None);
+    let torrent = ClientTorrent {
+        hash: TORRENT_HASH.to_owned(),
+        name: "Complete".to_owned(),
+        save_path: PathBuf::from("/srv/content"),
+        torrent_file: Some(PathBuf::from("/config/torrents/complete.torrent")),
+    };
+
+    // Act
+    let result = client.get_torrent_file(&torrent).await;
+
+    // Assert
+    let error = result.expect_err("should fail");
+    assert!(error
+        .message
+        .contains("is not accessible from this machine"));
+}
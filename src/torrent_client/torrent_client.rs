@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use colored::Colorize;
+use log::trace;
+use rogue_logging::Error;
+use tokio::time::{sleep, Instant};
+
+use crate::errors::error;
+use crate::torrent_client::*;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A torrent client with a supported API.
+pub enum TorrentClient {
+    QBittorrent(QBittorrentClient),
+    Transmission(TransmissionClient),
+    Deluge(DelugeClient),
+}
+
+impl TorrentClient {
+    #[must_use]
+    pub fn get_kind(&self) -> TorrentClientKind {
+        match self {
+            TorrentClient::QBittorrent(_) => TorrentClientKind::QBittorrent,
+            TorrentClient::Transmission(_) => TorrentClientKind::Transmission,
+            TorrentClient::Deluge(_) => TorrentClientKind::Deluge,
+        }
+    }
+
+    /// Add a `.torrent` file to the client.
+    pub async fn add(&mut self, request: &AddTorrentRequest) -> Result<(), Error> {
+        match self {
+            TorrentClient::QBittorrent(client) => client.add(request).await,
+            TorrentClient::Transmission(client) => client.add(request).await,
+            TorrentClient::Deluge(client) => client.add(request).await,
+        }
+    }
+
+    /// Get the progress of a torrent by its info hash.
+    ///
+    /// Returns `None` if the client does not have the torrent.
+    pub async fn get_progress(&mut self, hash: &str) -> Result<Option<TorrentProgress>, Error> {
+        match self {
+            TorrentClient::QBittorrent(client) => client.get_progress(hash).await,
+            TorrentClient::Transmission(client) => client.get_progress(hash).await,
+            TorrentClient::Deluge(client) => client.get_progress(hash).await,
+        }
+    }
+
+    /// Get the torrents that have completed downloading.
+    pub async fn get_completed(&mut self) -> Result<Vec<ClientTorrent>, Error> {
+        match self {
+            TorrentClient::QBittorrent(client) => client.get_completed().await,
+            TorrentClient::Transmission(client) => client.get_completed().await,
+            TorrentClient::Deluge(_) => Err(self.unsupported("get completed torrents")),
+        }
+    }
+
+    /// Get the content of the `.torrent` file of a torrent.
+    pub async fn get_torrent_file(&mut self, torrent: &ClientTorrent) -> Result<Vec<u8>, Error> {
+        match self {
+            TorrentClient::QBittorrent(client) => client.get_torrent_file(torrent).await,
+            TorrentClient::Transmission(client) => client.get_torrent_file(torrent).await,
+            TorrentClient::Deluge(_) => Err(self.unsupported("get torrent file")),
+        }
+    }
+
+    /// Wait until the client reports the torrent is 100% complete after its hash check.
+    ///
+    /// Returns an error if the torrent is not complete within the `timeout`.
+    pub async fn wait_for_hash_check(
+        &mut self,
+        hash: &str,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let start = Instant::now();
+        loop {
+            let progress = self.get_progress(hash).await?;
+            match progress {
+                Some(progress) if progress.is_complete() => return Ok(()),
+                Some(progress) => {
+                    trace!(
+                        "{} hash check of {hash}: {:.1}%",
+                        "Waiting for".bold(),
+                        progress.progress * 100.0
+                    );
+                }
+                None => trace!("{} {hash} to be added", "Waiting for".bold()),
+            }
+            if start.elapsed() >= timeout {
+                let message = match progress {
+                    Some(progress) => format!(
+                        "{} reported {:.1}% after {}",
+                        self.get_kind(),
+                        progress.progress * 100.0,
+                        humantime::format_duration(timeout)
+                    ),
+                    None => format!(
+                        "{} did not report the torrent after {}",
+                        self.get_kind(),
+                        humantime::format_duration(timeout)
+                    ),
+                };
+                return Err(error("confirm hash check", message));
+            }
+            sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    fn unsupported(&self, action: &str) -> Error {
+        error(action, format!("{} is not supported", self.get_kind()))
+    }
+}
@@ -0,0 +1,32 @@
+use di::{injectable, Ref};
+
+use crate::options::ClientOptions;
+use crate::torrent_client::*;
+
+#[injectable]
+pub struct TorrentClientFactory {
+    options: Ref<ClientOptions>,
+}
+
+impl TorrentClientFactory {
+    /// Create a [`TorrentClient`] from the [`ClientOptions`].
+    ///
+    /// Returns `None` if no client is configured.
+    #[must_use]
+    pub fn create(&self) -> Option<TorrentClient> {
+        let kind = self.options.client?;
+        let url = self.options.client_url.clone()?;
+        let username = self.options.client_username.clone();
+        let password = self.options.client_password.clone();
+        let client = match kind {
+            TorrentClientKind::QBittorrent => {
+                TorrentClient::QBittorrent(QBittorrentClient::new(url, username, password))
+            }
+            TorrentClientKind::Transmission => {
+                TorrentClient::Transmission(TransmissionClient::new(url, username, password))
+            }
+            TorrentClientKind::Deluge => TorrentClient::Deluge(DelugeClient::new(url, password)),
+        };
+        Some(client)
+    }
+}
@@ -0,0 +1,28 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Torrent client with a supported API.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum TorrentClientKind {
+    /// qBittorrent Web API
+    #[value(name = "qbittorrent")]
+    QBittorrent,
+    /// Transmission RPC
+    Transmission,
+    /// Deluge JSON-RPC
+    Deluge,
+}
+
+impl Display for TorrentClientKind {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let output = match self {
+            TorrentClientKind::QBittorrent => "qBittorrent",
+            TorrentClientKind::Transmission => "Transmission",
+            TorrentClientKind::Deluge => "Deluge",
+        };
+        output.fmt(formatter)
+    }
+}
@@ -0,0 +1,16 @@
+/// Progress of a torrent as reported by a torrent client.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TorrentProgress {
+    /// Fraction of the content that is complete from `0.0` to `1.0`
+    pub progress: f64,
+    /// Is the client checking, or queued to check, the content?
+    pub checking: bool,
+}
+
+impl TorrentProgress {
+    /// Has the hash check completed with all content present?
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        !self.checking && self.progress >= 1.0
+    }
+}
@@ -0,0 +1,228 @@
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use log::{debug, trace};
+use reqwest::{Client, Response, StatusCode};
+use rogue_logging::Error;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::fs::read;
+
+use crate::errors::{error, http_error, io_error, json_error};
+use crate::torrent_client::{AddTorrentRequest, ClientTorrent, TorrentProgress};
+
+const SESSION_ID_HEADER: &str = "X-Transmission-Session-Id";
+const STATUS_CHECK_WAIT: u8 = 1;
+const STATUS_CHECK: u8 = 2;
+
+/// Client for the Transmission RPC.
+///
+/// <https://github.com/transmission/transmission/blob/main/docs/rpc-spec.md>
+pub struct TransmissionClient {
+    url: String,
+    username: Option<String>,
+    password: Option<String>,
+    client: Client,
+    session_id: Option<String>,
+}
+
+#[derive(Serialize)]
+struct RpcRequest<'a> {
+    method: &'a str,
+    arguments: Value,
+}
+
+#[derive(Deserialize)]
+struct RpcResponse {
+    result: String,
+    arguments: Option<Value>,
+}
+
+#[derive(Deserialize)]
+struct TorrentGetArguments {
+    torrents: Vec<TransmissionTorrent>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransmissionTorrent {
+    #[serde(default)]
+    hash_string: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    download_dir: PathBuf,
+    #[serde(default)]
+    torrent_file: Option<PathBuf>,
+    percent_done: f64,
+    status: u8,
+}
+
+impl TransmissionClient {
+    #[must_use]
+    pub fn new(url: String, username: Option<String>, password: Option<String>) -> Self {
+        Self {
+            url,
+            username,
+            password,
+            client: Client::new(),
+            session_id: None,
+        }
+    }
+
+    /// Add a `.torrent` file to the client.
+    pub async fn add(&mut self, request: &AddTorrentRequest) -> Result<(), Error> {
+        let labels: Vec<&String> = request.category.iter().collect();
+        let arguments = json!({
+            "metainfo": BASE64_STANDARD.encode(&request.buffer),
+            "download-dir": request.save_path.to_string_lossy(),
+            "paused": false,
+            "labels": labels,
+        });
+        let response: Value = self
+            .call("torrent-add", arguments, "add torrent to Transmission")
+            .await?;
+        if response.get("torrent-duplicate").is_some() {
+            debug!("Transmission already has {}", request.file_name);
+        }
+        Ok(())
+    }
+
+    /// Get the progress of a torrent by its info hash.
+    ///
+    /// Returns `None` if the client does not have the torrent.
+    pub async fn get_progress(&mut self, hash: &str) -> Result<Option<TorrentProgress>, Error> {
+        let arguments = json!({
+            "ids": [hash],
+            "fields": ["hashString", "percentDone", "status"],
+        });
+        let response: TorrentGetArguments = self
+            .call("torrent-get", arguments, "get torrent from Transmission")
+            .await?;
+        let progress = response.torrents.first().map(|torrent| {
+            trace!("Transmission reports {hash} with status {}", torrent.status);
+            TorrentProgress {
+                progress: torrent.percent_done,
+                checking: torrent.status == STATUS_CHECK_WAIT || torrent.status == STATUS_CHECK,
+            }
+        });
+        Ok(progress)
+    }
+
+    /// Get the torrents that have completed downloading.
+    pub async fn get_completed(&mut self) -> Result<Vec<ClientTorrent>, Error> {
+        let arguments = json!({
+            "fields": [
+                "hashString",
+                "name",
+                "downloadDir",
+                "torrentFile",
+                "percentDone",
+                "status",
+            ],
+        });
+        let response: TorrentGetArguments = self
+            .call("torrent-get", arguments, "get torrents from Transmission")
+            .await?;
+        let torrents = response
+            .torrents
+            .into_iter()
+            .filter(|torrent| torrent.percent_done >= 1.0)
+            .map(|torrent| ClientTorrent {
+                hash: torrent.hash_string,
+                name: torrent.name,
+                save_path: torrent.download_dir,
+                torrent_file: torrent.torrent_file,
+            })
+            .collect();
+        Ok(torrents)
+    }
+
+    /// Read the `.torrent` file of a torrent.
+    ///
+    /// Transmission's RPC doesn't provide the `.torrent` file, only its path within
+    /// the config directory of the daemon. If Transmission runs on another machine or
+    /// in another container its config directory must be mounted at the same path.
+    pub async fn get_torrent_file(&mut self, torrent: &ClientTorrent) -> Result<Vec<u8>, Error> {
+        let Some(path) = &torrent.torrent_file else {
+            return Err(error(
+                "read torrent from Transmission",
+                format!(
+                    "Transmission did not report a torrent file for {}",
+                    torrent.name
+                ),
+            ));
+        };
+        read(path).await.map_err(|e| {
+            if e.kind() == ErrorKind::NotFound {
+                error(
+                    "read torrent from Transmission",
+                    format!(
+                        "{} is not accessible from this machine. \
+                        Mount the config directory of Transmission at the same path.",
+                        path.display()
+                    ),
+                )
+            } else {
+                io_error(e, "read torrent from Transmission")
+            }
+        })
+    }
+
+    /// Call an RPC method.
+    ///
+    /// Transmission responds with `409 Conflict` and a new session id if the
+    /// session id is missing or expired, in which case the request is repeated.
+    async fn call<T: DeserializeOwned>(
+        &mut self,
+        method: &str,
+        arguments: Value,
+        action: &str,
+    ) -> Result<T, Error> {
+        let url = format!("{}/transmission/rpc", self.url);
+        let request = RpcRequest { method, arguments };
+        let mut response = self.send(&url, &request, action).await?;
+        if response.status() == StatusCode::CONFLICT {
+            self.session_id = response
+                .headers()
+                .get(SESSION_ID_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(ToOwned::to_owned);
+            trace!("Received Transmission session id");
+            response = self.send(&url, &request, action).await?;
+        }
+        let response: RpcResponse = response
+            .error_for_status()
+            .map_err(|e| http_error(e, action))?
+            .json()
+            .await
+            .map_err(|e| http_error(e, action))?;
+        if response.result != "success" {
+            return Err(error(
+                action,
+                format!("Transmission responded with: {}", response.result),
+            ));
+        }
+        let arguments = response.arguments.unwrap_or_default();
+        serde_json::from_value(arguments).map_err(|e| json_error(e, action))
+    }
+
+    async fn send(
+        &self,
+        url: &str,
+        request: &RpcRequest<'_>,
+        action: &str,
+    ) -> Result<Response, Error> {
+        let mut builder = self.client.post(url).json(request);
+        if let Some(session_id) = &self.session_id {
+            builder = builder.header(SESSION_ID_HEADER, session_id);
+        }
+        if let Some(username) = &self.username {
+            builder = builder.basic_auth(username, self.password.clone());
+        }
+        builder.send().await.map_err(|e| http_error(e, action))
+    }
+}
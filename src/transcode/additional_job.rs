@@ -1,18 +1,22 @@
-use crate::dependencies::CONVERT;
-use crate::errors::{command_error, OutputHandler};
+use crate::jobs::{get_partial_path, rename_partial, Cancellation};
 use crate::transcode::resize::Resize;
 use colored::Colorize;
 use log::trace;
 use rogue_logging::Error;
 
+#[derive(Clone)]
 pub struct AdditionalJob {
     pub id: String,
     pub resize: Resize,
 }
 
 impl AdditionalJob {
-    #[allow(clippy::integer_division)]
-    pub async fn execute(self) -> Result<(), Error> {
+    /// Resize the image.
+    ///
+    /// Decoding and encoding are CPU bound so run on a blocking thread.
+    ///
+    /// The image is written to a partial path and renamed once complete.
+    pub async fn execute(self, cancellation: &Cancellation) -> Result<(), Error> {
         trace!(
             "{} image to maximum {} px and {}% quality: {}",
             "Resizing".bold(),
@@ -20,14 +24,14 @@ impl AdditionalJob {
             self.resize.quality,
             self.resize.input.display()
         );
-        let info = self.resize.to_info();
-        trace!("{info}");
-        let output = info
-            .to_command()
-            .output()
-            .await
-            .map_err(|e| command_error(e, "execute resize image", CONVERT))?;
-        OutputHandler::execute(output, "resize image", "convert")?;
-        Ok(())
+        let output = self.resize.output.clone();
+        let resize = Resize {
+            output: get_partial_path(&output),
+            ..self.resize
+        };
+        cancellation
+            .spawn_blocking("resize image", move |_| resize.execute())
+            .await?;
+        rename_partial(&output)
     }
 }
@@ -1,7 +1,7 @@
 use crate::errors::io_error;
 use crate::formats::target_format::TargetFormat;
-use crate::fs::{AdditionalFile, PathManager};
-use crate::jobs::Job;
+use crate::fs::{AdditionalFile, AdditionalFilePolicy, FileAction, PathManager};
+use crate::jobs::{hard_link_or_copy, Job, Scheduler};
 use crate::options::FileOptions;
 use crate::source::Source;
 use crate::transcode::resize::Resize;
@@ -10,32 +10,46 @@ use colored::Colorize;
 use di::{injectable, Ref};
 use log::{trace, warn};
 use rogue_logging::Error;
-use tokio::fs::{copy, create_dir_all, hard_link};
+use tokio::fs::create_dir_all;
 
 #[injectable]
 pub struct AdditionalJobFactory {
     options: Ref<FileOptions>,
     paths: Ref<PathManager>,
+    scheduler: Ref<Scheduler>,
 }
 
 impl AdditionalJobFactory {
-    /// Create a [`AdditionalJob`] for each [`FlacFile`] in the [`Vec<FlacFile>`].
+    /// Apply the [`AdditionalFilePolicy`] to each [`AdditionalFile`].
+    ///
+    /// Files that are copied or hard linked are added immediately, each holding an IO permit
+    /// of the [`Scheduler`].
+    /// An [`AdditionalJob`] is returned for each image that requires compression.
     pub async fn create(
         &self,
         files: &[AdditionalFile],
         source: &Source,
         target: TargetFormat,
     ) -> Result<Vec<Job>, Error> {
+        let policy = AdditionalFilePolicy::new(&self.options)?;
         let mut jobs = Vec::new();
         for (index, file) in files.iter().enumerate() {
-            if let Some(job) = self.create_single(index, file, source, target).await? {
+            let action = policy.get_action(file, target);
+            if action == FileAction::Exclude {
+                trace!("{} {}", "Excluded".bold(), file.path.display());
+                continue;
+            }
+            if let Some(job) = self
+                .create_single(index, file, source, target, action)
+                .await?
+            {
                 jobs.push(job);
             };
         }
         Ok(jobs)
     }
 
-    /// Create a single [`AdditionalJob`] from a `flac_file`.
+    /// Apply a [`FileAction`] to a single [`AdditionalFile`].
     #[allow(clippy::integer_division)]
     async fn create_single(
         &self,
@@ -43,6 +57,7 @@ impl AdditionalJobFactory {
         file: &AdditionalFile,
         source: &Source,
         target: TargetFormat,
+        action: FileAction,
     ) -> Result<Option<Job>, Error> {
         let source_path = file.path.clone();
         let output_dir = self
@@ -67,9 +82,11 @@ impl AdditionalJobFactory {
             .extension()
             .expect("Source has extension")
             .to_string_lossy()
-            .into_owned();
-        if no_image_compression || !is_large {
-            if is_large {
+            .to_lowercase();
+        let is_image = matches!(extension.as_str(), "jpg" | "jpeg" | "png");
+        let is_compressed = action == FileAction::Compress && is_image;
+        if no_image_compression || !is_large || !is_compressed {
+            if is_large && is_compressed {
                 warn!(
                     "Including large {} ({} KB): {}",
                     extension,
@@ -78,17 +95,10 @@ impl AdditionalJobFactory {
                 );
             }
             let hard_link_option = self.options.hard_link.expect("hard_link should be set");
-            let verb = if hard_link_option {
-                hard_link(&source_path, &output_path)
-                    .await
-                    .map_err(|e| io_error(e, "hard link additional file"))?;
-                "Hard Linked"
-            } else {
-                copy(&source_path, &output_path)
-                    .await
-                    .map_err(|e| io_error(e, "copy additional file"))?;
-                "Copied"
-            };
+            let is_hard_link = hard_link_option || action == FileAction::HardLink;
+            let permits = self.scheduler.acquire_io().await;
+            let verb = hard_link_or_copy(&source_path, &output_path, is_hard_link).await?;
+            drop(permits);
             trace!(
                 "{} {} to {}",
                 verb.bold(),
@@ -116,6 +126,10 @@ impl AdditionalJobFactory {
             .max_pixel_size
             .expect("max_pixel_size should be set");
         let quality = self.options.jpg_quality.expect("jpg_quality should be set");
+        let iterative_compression = self
+            .options
+            .iterative_compression
+            .expect("iterative_compression should be set");
         let job = Job::Additional(AdditionalJob {
             id,
             resize: Resize {
@@ -123,6 +137,7 @@ impl AdditionalJobFactory {
                 output: output_path,
                 max_pixel_size,
                 quality,
+                max_file_size: iterative_compression.then_some(max_file_size),
             },
         });
         Ok(Some(job))
@@ -11,11 +11,15 @@ pub struct CommandInfo {
 
 impl CommandInfo {
     /// Create a [`Command`] from the program and its arguments
+    ///
+    /// The process is killed if the [`Command`] is dropped, for example when a job times out
+    /// or is aborted.
     #[must_use]
     #[allow(clippy::wrong_self_convention)]
     pub fn to_command(self) -> Command {
         let mut cmd = Command::new(self.program);
         cmd.args(self.args);
+        cmd.kill_on_drop(true);
         cmd
     }
 
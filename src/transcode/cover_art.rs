@@ -0,0 +1,119 @@
+use crate::errors::{error, io_error};
+use crate::fs::DirectoryReader;
+use crate::transcode::resize::resize_to_jpg;
+use lofty::config::{ParseOptions, WriteOptions};
+use lofty::flac::FlacFile as FlacTaggedFile;
+use lofty::ogg::OggPictureStorage;
+use lofty::picture::{MimeType, Picture, PictureInformation, PictureType};
+use lofty::prelude::AudioFile;
+use lofty::tag::Tag;
+use log::trace;
+use rogue_logging::Error;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// Names of folder images in order of preference.
+const FOLDER_IMAGE_NAMES: [&str; 3] = ["cover", "folder", "front"];
+
+/// Extensions of folder images in order of preference.
+const FOLDER_IMAGE_EXTENSIONS: [&str; 3] = ["jpg", "jpeg", "png"];
+
+/// Cover art to apply to a transcoded file.
+#[derive(Clone, Debug)]
+pub struct CoverArt {
+    /// Front cover to embed in place of any existing front cover.
+    pub picture: Option<Picture>,
+    /// Embedded pictures larger than this in bytes are removed.
+    pub max_file_size: u64,
+}
+
+impl CoverArt {
+    /// Create a front cover [`Picture`] by resizing an image to jpg.
+    pub fn get_picture(
+        input: &Path,
+        max_pixel_size: u32,
+        quality: u8,
+        max_file_size: Option<u64>,
+    ) -> Result<Picture, Error> {
+        let data = resize_to_jpg(input, max_pixel_size, quality, max_file_size)?;
+        Ok(Picture::new_unchecked(
+            PictureType::CoverFront,
+            Some(MimeType::Jpeg),
+            None,
+            data,
+        ))
+    }
+
+    /// Find the folder image of a source directory.
+    ///
+    /// Only the root of the directory is searched. Names are matched case insensitively.
+    #[must_use]
+    pub fn get_folder_image(source_dir: &Path) -> Option<PathBuf> {
+        let paths = DirectoryReader::new()
+            .with_max_depth(0)
+            .read(source_dir)
+            .ok()?;
+        let mut candidates: Vec<(usize, usize, PathBuf)> = paths
+            .into_iter()
+            .filter_map(|path| {
+                let stem = path.file_stem()?.to_string_lossy().to_lowercase();
+                let extension = path.extension()?.to_string_lossy().to_lowercase();
+                let name = FOLDER_IMAGE_NAMES.iter().position(|x| *x == stem)?;
+                let extension = FOLDER_IMAGE_EXTENSIONS
+                    .iter()
+                    .position(|x| *x == extension)?;
+                Some((name, extension, path))
+            })
+            .collect();
+        candidates.sort();
+        candidates.into_iter().next().map(|(_, _, path)| path)
+    }
+
+    /// Apply to the pictures of a [`Tag`].
+    pub fn apply_to_tag(&self, tags: &mut Tag) {
+        let mut index = 0;
+        while let Some(picture) = tags.pictures().get(index) {
+            if self.is_oversize(picture) {
+                trace!("Removing oversize {:?} picture", picture.pic_type());
+                tags.remove_picture(index);
+            } else {
+                index += 1;
+            }
+        }
+        if let Some(picture) = &self.picture {
+            tags.remove_picture_type(PictureType::CoverFront);
+            tags.push_picture(picture.clone());
+        }
+    }
+
+    /// Apply to the PICTURE blocks of a FLAC file.
+    pub fn save_to_flac(&self, path: &Path) -> Result<(), Error> {
+        let mut file = File::open(path).map_err(|e| io_error(e, "open FLAC"))?;
+        let mut flac = FlacTaggedFile::read_from(&mut file, ParseOptions::new())
+            .map_err(|e| error("read FLAC pictures", e.to_string()))?;
+        drop(file);
+        let mut index = 0;
+        while let Some((picture, _)) = flac.pictures().get(index) {
+            if self.is_oversize(picture) {
+                trace!("Removing oversize {:?} picture", picture.pic_type());
+                flac.remove_picture(index);
+            } else {
+                index += 1;
+            }
+        }
+        if let Some(picture) = &self.picture {
+            let info = PictureInformation::from_picture(picture)
+                .map_err(|e| error("read cover art", e.to_string()))?;
+            flac.remove_picture_type(PictureType::CoverFront);
+            flac.insert_picture(picture.clone(), Some(info))
+                .map_err(|e| error("embed cover art", e.to_string()))?;
+        }
+        flac.save_to_path(path, WriteOptions::default())
+            .map_err(|e| error("write FLAC pictures", e.to_string()))
+    }
+
+    #[allow(clippy::as_conversions)]
+    fn is_oversize(&self, picture: &Picture) -> bool {
+        picture.data().len() as u64 > self.max_file_size
+    }
+}
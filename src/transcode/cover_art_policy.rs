@@ -0,0 +1,30 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// How cover art embedded in transcoded files is handled.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum CoverArtPolicy {
+    /// Embedded art is left as is
+    #[default]
+    Keep,
+    /// Embedded art larger than `max_file_size` is removed
+    Strip,
+    /// The folder image is resized and embedded as the front cover
+    ///
+    /// Embedded art larger than `max_file_size` is removed.
+    Embed,
+}
+
+impl Display for CoverArtPolicy {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let output = match self {
+            CoverArtPolicy::Keep => "keep",
+            CoverArtPolicy::Strip => "strip",
+            CoverArtPolicy::Embed => "embed",
+        };
+        output.fmt(formatter)
+    }
+}
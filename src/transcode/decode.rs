@@ -2,6 +2,7 @@ use crate::dependencies::{FLAC, SOX};
 use crate::transcode::CommandInfo;
 use std::path::PathBuf;
 
+#[derive(Clone)]
 pub struct Decode {
     /// Path to the input file
     pub input: PathBuf,
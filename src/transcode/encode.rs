@@ -5,6 +5,7 @@ use crate::transcode::CommandInfo;
 use std::path::PathBuf;
 
 /// Information required to create an encode command [`Command`].
+#[derive(Clone)]
 pub struct Encode {
     /// Path to the input file
     pub output: PathBuf,
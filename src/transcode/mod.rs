@@ -1,6 +1,8 @@
 pub use additional_job::*;
 pub use additional_job_factory::*;
 pub use command_info::*;
+pub use cover_art::*;
+pub use cover_art_policy::*;
 pub use decode::*;
 pub use encode::*;
 pub use resample::*;
@@ -14,10 +16,12 @@ pub use variant::*;
 pub(crate) mod additional_job;
 pub(crate) mod additional_job_factory;
 pub(crate) mod command_info;
+pub(crate) mod cover_art;
+pub(crate) mod cover_art_policy;
 pub(crate) mod decode;
 pub(crate) mod encode;
 pub(crate) mod resample;
-mod resize;
+pub(crate) mod resize;
 mod streaminfo_helpers;
 #[cfg(test)]
 mod tests;
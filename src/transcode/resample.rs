@@ -3,6 +3,7 @@ use crate::transcode::CommandInfo;
 use std::path::PathBuf;
 
 /// Information needed to resample a FLAC.
+#[derive(Clone)]
 pub struct Resample {
     /// Path to the input file
     pub input: PathBuf,
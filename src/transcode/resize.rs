@@ -1,34 +1,163 @@
-use crate::dependencies::CONVERT;
-use crate::transcode::CommandInfo;
-use std::path::PathBuf;
+use crate::errors::{error, io_error};
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageReader, Rgb, RgbImage};
+use log::{trace, warn};
+use rogue_logging::Error;
+use std::fs::write;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+/// Amount to lower the jpg quality by on each iteration.
+const QUALITY_STEP: u8 = 5;
+
+/// Lowest jpg quality to try when compressing iteratively.
+const MIN_QUALITY: u8 = 30;
 
 /// Information needed to resize an image
+#[derive(Clone)]
 pub struct Resize {
     /// Path to the input file
     pub input: PathBuf,
     /// Path to the output file
+    ///
+    /// The format is determined by the extension.
     pub output: PathBuf,
     /// Maximum size in pixels
     pub max_pixel_size: u32,
     /// Quality percentage to apply for jpg compression.
     pub quality: u8,
+    /// Maximum file size in bytes to aim for by lowering the jpg quality.
+    ///
+    /// If `None` the quality is applied once.
+    pub max_file_size: Option<u64>,
 }
 
 impl Resize {
-    /// Create a new convert command.
-    #[must_use]
-    #[allow(clippy::wrong_self_convention)]
-    pub fn to_info(self) -> CommandInfo {
-        CommandInfo {
-            program: CONVERT.to_owned(),
-            args: vec![
-                self.input.to_string_lossy().to_string(),
-                "-resize".to_owned(),
-                format!("{}x{}>", self.max_pixel_size, self.max_pixel_size),
-                "-quality".to_owned(),
-                format!("{}%", self.quality),
-                self.output.to_string_lossy().to_string(),
-            ],
+    /// Shrink the image to fit within `max_pixel_size` and save with `quality`.
+    ///
+    /// Images smaller than `max_pixel_size` are not enlarged.
+    pub fn execute(&self) -> Result<(), Error> {
+        let image = read_image(&self.input, self.max_pixel_size)?;
+        let bytes = if self.is_jpg() {
+            compress_jpg(&image, &self.input, self.quality, self.max_file_size)?
+        } else {
+            encode_png(&image)?
+        };
+        write(&self.output, bytes).map_err(|e| io_error(e, "write image"))
+    }
+
+    fn is_jpg(&self) -> bool {
+        self.output.extension().is_some_and(|extension| {
+            let extension = extension.to_string_lossy().to_lowercase();
+            extension == "jpg" || extension == "jpeg"
+        })
+    }
+}
+
+/// Shrink the image at `input` to fit within `max_pixel_size` and encode as jpg with
+/// `quality`, lowering the quality until it is under `max_file_size`.
+///
+/// Images smaller than `max_pixel_size` are not enlarged.
+pub fn resize_to_jpg(
+    input: &Path,
+    max_pixel_size: u32,
+    quality: u8,
+    max_file_size: Option<u64>,
+) -> Result<Vec<u8>, Error> {
+    let image = read_image(input, max_pixel_size)?;
+    compress_jpg(&image, input, quality, max_file_size)
+}
+
+fn read_image(input: &Path, max_pixel_size: u32) -> Result<DynamicImage, Error> {
+    let image = ImageReader::open(input)
+        .map_err(|e| io_error(e, "open image"))?
+        .with_guessed_format()
+        .map_err(|e| io_error(e, "read image"))?
+        .decode()
+        .map_err(|e| error("decode image", e.to_string()))?;
+    if image.width() > max_pixel_size || image.height() > max_pixel_size {
+        Ok(image.resize(max_pixel_size, max_pixel_size, FilterType::Lanczos3))
+    } else {
+        Ok(image)
+    }
+}
+
+/// Encode as jpg, lowering the quality until it is under `max_file_size`.
+#[allow(clippy::as_conversions, clippy::integer_division)]
+fn compress_jpg(
+    image: &DynamicImage,
+    input: &Path,
+    quality: u8,
+    max_file_size: Option<u64>,
+) -> Result<Vec<u8>, Error> {
+    let image = flatten(image);
+    let mut quality = quality;
+    loop {
+        let bytes = encode_jpg(&image, quality)?;
+        let Some(max_file_size) = max_file_size else {
+            return Ok(bytes);
+        };
+        let size = bytes.len() as u64;
+        if size <= max_file_size {
+            return Ok(bytes);
+        }
+        if quality <= MIN_QUALITY {
+            warn!(
+                "Unable to compress image under {} KB at {}% quality: {}",
+                max_file_size / 1_000,
+                quality,
+                input.display()
+            );
+            return Ok(bytes);
         }
+        quality = quality.saturating_sub(QUALITY_STEP).max(MIN_QUALITY);
+        trace!(
+            "Compressed image is {} KB, retrying at {}% quality: {}",
+            size / 1_000,
+            quality,
+            input.display()
+        );
+    }
+}
+
+/// Convert to RGB by blending any transparency onto a white background.
+///
+/// jpg does not support transparency.
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::integer_division
+)]
+fn flatten(image: &DynamicImage) -> RgbImage {
+    if !image.color().has_alpha() {
+        return image.to_rgb8();
     }
+    let rgba = image.to_rgba8();
+    RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+        let [red, green, blue, alpha] = rgba.get_pixel(x, y).0;
+        let alpha = u32::from(alpha);
+        let blend = |channel: u8| ((u32::from(channel) * alpha + 255 * (255 - alpha)) / 255) as u8;
+        Rgb([blend(red), blend(green), blend(blue)])
+    })
+}
+
+fn encode_jpg(image: &RgbImage, quality: u8) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::new();
+    let encoder = JpegEncoder::new_with_quality(&mut bytes, quality);
+    image
+        .write_with_encoder(encoder)
+        .map_err(|e| error("encode jpg", e.to_string()))?;
+    Ok(bytes)
+}
+
+fn encode_png(image: &DynamicImage) -> Result<Vec<u8>, Error> {
+    let mut bytes = Cursor::new(Vec::new());
+    let encoder = PngEncoder::new(&mut bytes);
+    image
+        .write_with_encoder(encoder)
+        .map_err(|e| error("encode png", e.to_string()))?;
+    Ok(bytes.into_inner())
 }
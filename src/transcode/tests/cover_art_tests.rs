@@ -0,0 +1,78 @@
+use std::fs::write;
+
+use image::{Rgb, RgbImage};
+use lofty::picture::{MimeType, Picture, PictureType};
+use lofty::tag::{Tag, TagType};
+use rogue_logging::Error;
+
+use crate::testing::TempDirectory;
+use crate::transcode::CoverArt;
+
+fn create_picture(pic_type: PictureType, size: usize) -> Picture {
+    Picture::new_unchecked(pic_type, Some(MimeType::Jpeg), None, vec![0; size])
+}
+
+#[test]
+fn cover_art_get_folder_image() {
+    // Arrange
+    let dir = TempDirectory::create("cover_art_get_folder_image");
+    for name in ["folder.png", "Cover.JPG", "cover.png", "back.jpg"] {
+        write(dir.join(name), []).expect("should write file");
+    }
+
+    // Act
+    let path = CoverArt::get_folder_image(&dir);
+
+    // Assert
+    assert_eq!(path, Some(dir.join("Cover.JPG")));
+}
+
+#[test]
+fn cover_art_apply_to_tag_strip() {
+    // Arrange
+    let mut tags = Tag::new(TagType::Id3v2);
+    tags.push_picture(create_picture(PictureType::CoverFront, 2_000));
+    tags.push_picture(create_picture(PictureType::CoverBack, 500));
+    tags.push_picture(create_picture(PictureType::Media, 3_000));
+    let cover_art = CoverArt {
+        picture: None,
+        max_file_size: 1_000,
+    };
+
+    // Act
+    cover_art.apply_to_tag(&mut tags);
+
+    // Assert
+    let types: Vec<PictureType> = tags.pictures().iter().map(Picture::pic_type).collect();
+    assert_eq!(types, vec![PictureType::CoverBack]);
+}
+
+#[test]
+fn cover_art_apply_to_tag_embed() -> Result<(), Error> {
+    // Arrange
+    let dir = TempDirectory::create("cover_art_apply_to_tag_embed");
+    let input = dir.join("cover.png");
+    RgbImage::from_pixel(800, 400, Rgb([0, 0, 255]))
+        .save(&input)
+        .expect("should save image");
+    let picture = CoverArt::get_picture(&input, 200, 80, None)?;
+    let mut tags = Tag::new(TagType::Id3v2);
+    tags.push_picture(create_picture(PictureType::CoverFront, 500));
+    let cover_art = CoverArt {
+        picture: Some(picture),
+        max_file_size: 750_000,
+    };
+
+    // Act
+    cover_art.apply_to_tag(&mut tags);
+
+    // Assert
+    assert_eq!(tags.pictures().len(), 1);
+    let picture = tags
+        .get_picture_type(PictureType::CoverFront)
+        .expect("should have front cover");
+    assert_eq!(picture.mime_type(), Some(&MimeType::Jpeg));
+    let image = image::load_from_memory(picture.data()).expect("should decode picture");
+    assert_eq!((image.width(), image.height()), (200, 100));
+    Ok(())
+}
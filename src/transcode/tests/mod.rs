@@ -1 +1,3 @@
+mod cover_art_tests;
+mod resize_tests;
 mod transcode_command_tests;
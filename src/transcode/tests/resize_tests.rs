@@ -0,0 +1,124 @@
+use image::{ImageReader, Rgb, RgbImage, Rgba, RgbaImage};
+use rogue_logging::Error;
+
+use crate::testing::TempDirectory;
+use crate::transcode::resize::Resize;
+
+/// Create a noisy image that does not compress well.
+#[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+fn create_noise(width: u32, height: u32) -> RgbImage {
+    let mut state: u32 = 1;
+    RgbImage::from_fn(width, height, |_, _| {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        let [red, green, blue, _] = state.to_le_bytes();
+        Rgb([red, green, blue])
+    })
+}
+
+#[test]
+fn resize_shrinks_large_image() -> Result<(), Error> {
+    // Arrange
+    let dir = TempDirectory::create("resize_shrinks_large_image");
+    let input = dir.join("cover.png");
+    let output = dir.join("cover.jpg");
+    RgbaImage::from_pixel(400, 200, Rgba([255, 0, 0, 0]))
+        .save(&input)
+        .expect("should save image");
+    let resize = Resize {
+        input,
+        output: output.clone(),
+        max_pixel_size: 100,
+        quality: 80,
+        max_file_size: None,
+    };
+
+    // Act
+    resize.execute()?;
+
+    // Assert
+    let image = ImageReader::open(&output)
+        .expect("should open image")
+        .with_guessed_format()
+        .expect("should guess format")
+        .decode()
+        .expect("should decode image");
+    assert_eq!((image.width(), image.height()), (100, 50));
+    let pixel = image.to_rgb8().get_pixel(50, 25).0;
+    assert!(
+        pixel.iter().all(|channel| *channel > 240),
+        "transparency should be white: {pixel:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn resize_does_not_enlarge_small_image() -> Result<(), Error> {
+    // Arrange
+    let dir = TempDirectory::create("resize_does_not_enlarge_small_image");
+    let input = dir.join("cover.png");
+    let output = dir.join("cover.resized.png");
+    create_noise(64, 32)
+        .save(&input)
+        .expect("should save image");
+    let resize = Resize {
+        input,
+        output: output.clone(),
+        max_pixel_size: 100,
+        quality: 80,
+        max_file_size: None,
+    };
+
+    // Act
+    resize.execute()?;
+
+    // Assert
+    let image = image::open(&output).expect("should open image");
+    assert_eq!((image.width(), image.height()), (64, 32));
+    Ok(())
+}
+
+#[test]
+fn resize_iterative_lowers_quality() -> Result<(), Error> {
+    // Arrange
+    let dir = TempDirectory::create("resize_iterative_lowers_quality");
+    let input = dir.join("cover.png");
+    create_noise(300, 300)
+        .save(&input)
+        .expect("should save image");
+    let single = Resize {
+        input: input.clone(),
+        output: dir.join("single.jpg"),
+        max_pixel_size: 1000,
+        quality: 95,
+        max_file_size: None,
+    };
+    single.execute()?;
+    let single_size = single
+        .output
+        .metadata()
+        .expect("should read metadata")
+        .len();
+    let max_file_size = single_size.div_euclid(2);
+    let iterative = Resize {
+        input,
+        output: dir.join("iterative.jpg"),
+        max_pixel_size: 1000,
+        quality: 95,
+        max_file_size: Some(max_file_size),
+    };
+
+    // Act
+    iterative.execute()?;
+
+    // Assert
+    let iterative_size = iterative
+        .output
+        .metadata()
+        .expect("should read metadata")
+        .len();
+    assert!(
+        iterative_size <= max_file_size,
+        "{iterative_size} > {max_file_size}"
+    );
+    Ok(())
+}
@@ -27,6 +27,7 @@ async fn transcode_command() -> Result<(), Error> {
     let target_options = TestOptionsFactory::from(TargetOptions {
         allow_existing: Some(true),
         target: Some(vec![Flac, _320, V0]),
+        ..TargetOptions::default()
     });
     let file_options = TestOptionsFactory::from(FileOptions {
         hard_link: Some(true),
@@ -53,7 +54,8 @@ async fn transcode_command() -> Result<(), Error> {
 
     // Assert
     let generated_files = DirectoryReader::new()
-        .with_extensions(vec!["flac", "mp3"])
+        .with_extension("flac")
+        .with_extension("mp3")
         .read(&output_dir)
         .expect("Should be able to read dir");
     let targets = host.services.get_required::<TargetFormatProvider>();
@@ -1,11 +1,17 @@
 use crate::errors::{error, io_error};
 use crate::formats::{TargetFormat, TargetFormatProvider};
-use crate::fs::{Collector, PathManager};
+use crate::fs::{
+    get_vorbis_tags, AdditionalFilePolicy, Collector, FlacFile, PathManager, TagFixer,
+};
 use crate::imdl::ImdlCommand;
 use crate::jobs::Job::Additional;
-use crate::jobs::JobRunner;
+use crate::jobs::{hard_link_or_copy, Job, JobFailure, JobJournal, JobRunner, Scheduler};
+use crate::loudness::{analyze_flacs, ReplayGain};
 use crate::naming::join_humanized;
-use crate::options::{FileOptions, Options, SharedOptions, SourceArg, TargetOptions};
+use crate::options::{
+    FileOptions, LoudnessOptions, NamingOptions, Options, RunnerOptions, SharedOptions, SourceArg,
+    TagOptions, TargetOptions,
+};
 use crate::queue::TimeStamp;
 use crate::source::*;
 use crate::transcode::{
@@ -18,21 +24,27 @@ use log::*;
 use rogue_logging::Colors;
 use rogue_logging::Error;
 use std::collections::BTreeSet;
-use tokio::fs::{copy, hard_link};
+use std::sync::Arc;
+use tokio::fs::copy;
 
 /// Transcode each track of a FLAC source to the target formats.
 #[injectable]
 pub struct TranscodeCommand {
     arg: Ref<SourceArg>,
     shared_options: Ref<SharedOptions>,
+    naming_options: Ref<NamingOptions>,
     target_options: Ref<TargetOptions>,
     source_provider: RefMut<SourceProvider>,
     file_options: Ref<FileOptions>,
+    loudness_options: Ref<LoudnessOptions>,
+    tag_options: Ref<TagOptions>,
+    runner_options: Ref<RunnerOptions>,
     paths: Ref<PathManager>,
     targets: Ref<TargetFormatProvider>,
     transcode_job_factory: Ref<TranscodeJobFactory>,
     additional_job_factory: Ref<AdditionalJobFactory>,
     runner: Ref<JobRunner>,
+    scheduler: Ref<Scheduler>,
 }
 
 impl TranscodeCommand {
@@ -44,8 +56,12 @@ impl TranscodeCommand {
     pub async fn execute_cli(&self) -> Result<bool, Error> {
         if !self.arg.validate()
             || !self.shared_options.validate()
+            || !self.naming_options.validate()
             || !self.target_options.validate()
             || !self.file_options.validate()
+            || !self.loudness_options.validate()
+            || !self.tag_options.validate()
+            || !self.runner_options.validate()
         {
             return Ok(false);
         }
@@ -67,6 +83,10 @@ impl TranscodeCommand {
     ///
     /// Returns a [`TranscodeStatus`] indicating the success of the operation and any errors.
     ///
+    /// Each completed job is recorded in a [`JobJournal`] so if the command is interrupted
+    /// the next run only executes the jobs that didn't complete. The journal is removed
+    /// once the torrents are created.
+    ///
     /// Errors are not logged so should be handled by the caller.
     #[must_use]
     pub async fn execute(&self, source: &Source) -> TranscodeStatus {
@@ -76,6 +96,7 @@ impl TranscodeCommand {
             formats: None,
             completed: TimeStamp::now(),
             error: None,
+            failures: None,
         };
         if targets.is_empty() {
             status.error = Some(error("transcode", "No transcodes to perform".to_owned()));
@@ -94,12 +115,35 @@ impl TranscodeCommand {
             status.success = true;
             return status;
         }
-        if let Err(error) = self.execute_transcode(source, &targets).await {
-            status.error = Some(error);
-            status.completed = TimeStamp::now();
-            return status;
+        let journal = match JobJournal::read(self.paths.get_journal_path(source)) {
+            Ok(journal) => Arc::new(journal),
+            Err(error) => {
+                status.error = Some(error);
+                return status;
+            }
+        };
+        if !journal.is_empty() {
+            debug!(
+                "{} {} completed jobs of {source}",
+                "Resuming".bold(),
+                journal.len().to_string().gray()
+            );
         }
-        if let Err(error) = self.execute_additional(source, &targets).await {
+        match self.execute_transcode(source, &targets, &journal).await {
+            Ok(failures) if failures.is_empty() => {}
+            Ok(failures) => {
+                status.error = Some(JobFailure::to_error(&failures));
+                status.failures = Some(failures);
+                status.completed = TimeStamp::now();
+                return status;
+            }
+            Err(error) => {
+                status.error = Some(error);
+                status.completed = TimeStamp::now();
+                return status;
+            }
+        }
+        if let Err(error) = self.execute_additional(source, &targets, &journal).await {
             status.error = Some(error);
             status.completed = TimeStamp::now();
             return status;
@@ -109,6 +153,9 @@ impl TranscodeCommand {
             status.completed = TimeStamp::now();
             return status;
         }
+        if let Err(error) = journal.remove() {
+            warn!("Failed to remove job journal: {error}");
+        }
         status.success = true;
         status
     }
@@ -136,11 +183,17 @@ impl TranscodeCommand {
         out
     }
 
+    /// Transcode each FLAC to the `targets`.
+    ///
+    /// Jobs completed by a previous run are skipped.
+    ///
+    /// Returns each transcode job that failed.
     async fn execute_transcode(
         &self,
         source: &Source,
         targets: &BTreeSet<TargetFormat>,
-    ) -> Result<(), Error> {
+        journal: &Arc<JobJournal>,
+    ) -> Result<Vec<JobFailure>, Error> {
         let flacs = Collector::get_flacs(&source.directory);
         info!(
             "{} to {} for {} FLACs in {}",
@@ -149,21 +202,71 @@ impl TranscodeCommand {
             flacs.len().to_string().gray(),
             source
         );
+        self.log_supplemented_tags(source, &flacs)?;
+        let replay_gains = self.get_replay_gains(&flacs).await?;
         for target in targets {
-            let jobs = self.transcode_job_factory.create(&flacs, source, *target)?;
-            self.runner.add(jobs);
+            let jobs = self
+                .transcode_job_factory
+                .create(&flacs, source, *target, &replay_gains)?;
+            let jobs = skip_journaled(jobs, journal);
+            self.runner.add_journaled(jobs, journal);
+        }
+        let failures = self.runner.execute_with_failures().await?;
+        if failures.is_empty() {
+            info!("{} {}", "Transcoded".bold(), source);
+        }
+        Ok(failures)
+    }
+
+    /// Log each tag that [`TagFixer`] supplements in the transcodes.
+    fn log_supplemented_tags(&self, source: &Source, flacs: &[FlacFile]) -> Result<(), Error> {
+        if !self.tag_options.fix_tags.expect("fix_tags should be set") {
+            return Ok(());
+        }
+        for flac in flacs {
+            let mut tags = get_vorbis_tags(flac)?;
+            for tag in TagFixer::execute(&mut tags, flac, source) {
+                info!(
+                    "{} {} of {} from {}: {}",
+                    "Supplementing".bold(),
+                    tag.tag,
+                    flac.file_name,
+                    tag.origin,
+                    tag.value
+                );
+            }
         }
-        self.runner.execute().await?;
-        info!("{} {}", "Transcoded".bold(), source);
         Ok(())
     }
 
+    async fn get_replay_gains(&self, flacs: &[FlacFile]) -> Result<Vec<ReplayGain>, Error> {
+        if !self
+            .loudness_options
+            .replay_gain
+            .expect("replay_gain should be set")
+        {
+            return Ok(Vec::new());
+        }
+        debug!("{} loudness of {} FLACs", "Analyzing".bold(), flacs.len());
+        let tracks = analyze_flacs(flacs, &self.scheduler).await?;
+        let reference = self
+            .loudness_options
+            .replay_gain_reference
+            .expect("replay_gain_reference should be set");
+        ReplayGain::from_tracks(&tracks, reference)
+    }
+
     async fn execute_additional(
         &self,
         source: &Source,
         targets: &BTreeSet<TargetFormat>,
+        journal: &Arc<JobJournal>,
     ) -> Result<(), Error> {
-        let files = Collector::get_additional(&source.directory);
+        let max_depth = self
+            .file_options
+            .additional_max_depth
+            .expect("additional_max_depth should be set");
+        let files = Collector::get_additional(&source.directory, max_depth);
         debug!(
             "{} {} additional files",
             "Adding".bold(),
@@ -175,44 +278,54 @@ impl TranscodeCommand {
             .create(&files, source, *first_target)
             .await?;
         let from_prefix = self.paths.get_transcode_target_dir(source, *first_target);
-        self.runner.add_without_publish(jobs);
+        let jobs = skip_journaled(jobs, journal);
+        self.runner.add_journaled_without_publish(jobs, journal);
         self.runner.execute_without_publish().await?;
         let hard_link_option = self
             .file_options
             .hard_link
             .expect("hard_link should be set");
+        let policy = AdditionalFilePolicy::new(&self.file_options)?;
         for target in targets.iter().skip(1) {
             let jobs = self
                 .additional_job_factory
                 .create(&files, source, *target)
                 .await?;
             let output = self.paths.get_transcode_target_dir(source, *target);
-            for job in jobs {
-                if let Additional(AdditionalJob { resize, .. }) = job {
-                    let from = from_prefix.clone().join(
-                        resize
-                            .output
-                            .strip_prefix(&output)
-                            .expect("should have prefix"),
-                    );
-                    let verb = if hard_link_option {
-                        hard_link(&from, &resize.output)
-                            .await
-                            .map_err(|e| io_error(e, "hard link additional file"))?;
-                        "Hard Linked"
-                    } else {
-                        copy(&from, &resize.output)
-                            .await
-                            .map_err(|e| io_error(e, "copy additional file"))?;
-                        "Copied"
-                    };
-                    trace!(
-                        "{} {} to {}",
-                        verb.bold(),
-                        from.display(),
-                        resize.output.display()
-                    );
+            let mut remaining = Vec::new();
+            for job in skip_journaled(jobs, journal) {
+                let Additional(AdditionalJob { resize, .. }) = &job else {
+                    continue;
+                };
+                let is_shared = files
+                    .iter()
+                    .find(|file| file.path == resize.input)
+                    .is_some_and(|file| policy.is_shared(file, *first_target, *target));
+                let from = from_prefix.clone().join(
+                    resize
+                        .output
+                        .strip_prefix(&output)
+                        .expect("should have prefix"),
+                );
+                if !is_shared || !from.exists() {
+                    // The rules of the first target excluded or copied the file
+                    remaining.push(job);
+                    continue;
                 }
+                let _permits = self.scheduler.acquire_io().await;
+                let verb = hard_link_or_copy(&from, &resize.output, hard_link_option).await?;
+                journal.record(&resize.output)?;
+                trace!(
+                    "{} {} to {}",
+                    verb.bold(),
+                    from.display(),
+                    resize.output.display()
+                );
+            }
+            if !remaining.is_empty() {
+                self.runner
+                    .add_journaled_without_publish(remaining, journal);
+                self.runner.execute_without_publish().await?;
             }
         }
         debug!("{} additional files {}", "Added".bold(), source);
@@ -259,3 +372,21 @@ impl TranscodeCommand {
         Ok(())
     }
 }
+
+/// Remove the jobs that were completed by a previous run.
+fn skip_journaled(jobs: Vec<Job>, journal: &JobJournal) -> Vec<Job> {
+    let count = jobs.len();
+    let jobs: Vec<Job> = jobs
+        .into_iter()
+        .filter(|job| !journal.is_completed(&job.get_output_path()))
+        .collect();
+    let skipped = count - jobs.len();
+    if skipped > 0 {
+        debug!(
+            "{} {} jobs completed by a previous run",
+            "Skipping".bold(),
+            skipped.to_string().gray()
+        );
+    }
+    jobs
+}
@@ -1,5 +1,10 @@
 use crate::errors::{command_error, error, io_error, OutputHandler};
-use crate::transcode::{Decode, Encode, Resample, Variant};
+use crate::formats::TargetFormat;
+use crate::fs::{save_vorbis_comments, Id3Version};
+use crate::jobs::{get_partial_path, rename_partial, Cancellation};
+use crate::loudness::ReplayGain;
+use crate::mp3::Mp3Inspector;
+use crate::transcode::{CoverArt, Decode, Encode, Resample, Variant};
 use lofty::config::WriteOptions;
 use lofty::prelude::TagExt;
 use lofty::tag::ItemKey::{Popularimeter, Work};
@@ -10,27 +15,54 @@ use std::fs::create_dir_all;
 use std::process::Stdio;
 use tokio::join;
 
+#[derive(Clone)]
 pub struct TranscodeJob {
     pub id: String,
     pub variant: Variant,
     pub tags: Option<Tag>,
+    pub replay_gain: Option<ReplayGain>,
+    pub cover_art: Option<CoverArt>,
+    pub id3_version: Id3Version,
 }
 
 impl TranscodeJob {
-    pub async fn execute(self) -> Result<(), Error> {
-        let output_path = match &self.variant {
-            Variant::Transcode(_, encode) => encode.output.clone(),
-            Variant::Resample(resample) => resample.output.clone(),
+    /// Execute the transcode and apply the tags.
+    ///
+    /// The output is written to a partial path and only renamed once it's complete and
+    /// tagged so an interrupted job never leaves an incomplete file at the output path.
+    pub async fn execute(self, cancellation: &Cancellation) -> Result<(), Error> {
+        let format = match &self.variant {
+            Variant::Transcode(_, encode) => encode.format,
+            Variant::Resample(_) => TargetFormat::Flac,
         };
-        let output_dir = output_path
+        let is_flac = format == TargetFormat::Flac;
+        let final_path = self.variant.get_output();
+        let output_dir = final_path
             .parent()
             .expect("output path should have a parent");
         create_dir_all(output_dir).map_err(|e| io_error(e, "create transcode output directory"))?;
-        match self.variant {
+        let output_path = get_partial_path(&final_path);
+        match self.variant.with_output(output_path.clone()) {
             Variant::Transcode(decode, encode) => execute_transcode(decode, encode).await?,
             Variant::Resample(resample) => execute_resample(resample).await?,
         };
-        if let Some(mut tags) = self.tags {
+        if !is_flac {
+            let path = output_path.clone();
+            cancellation
+                .spawn_blocking("verify MP3", move |_| Mp3Inspector::verify(&path, format))
+                .await?;
+        }
+        if is_flac {
+            if let Some(tags) = self.tags {
+                save_vorbis_comments(tags, &output_path)?;
+            }
+            if let Some(cover_art) = &self.cover_art {
+                cover_art.save_to_flac(&output_path)?;
+            }
+            if let Some(replay_gain) = &self.replay_gain {
+                replay_gain.save_to_flac(&output_path)?;
+            }
+        } else if let Some(mut tags) = self.tags {
             let exclude = [Popularimeter, Work];
             for key in exclude {
                 if let Some(value) = tags.get_string(&key) {
@@ -38,10 +70,18 @@ impl TranscodeJob {
                     tags.remove_key(&key);
                 }
             }
-            tags.save_to_path(&output_path, WriteOptions::default())
-                .map_err(|e| error("write tags", e.to_string()))?;
+            if let Some(cover_art) = &self.cover_art {
+                cover_art.apply_to_tag(&mut tags);
+            }
+            let options = WriteOptions::new().use_id3v23(self.id3_version == Id3Version::V23);
+            if let Some(replay_gain) = &self.replay_gain {
+                replay_gain.save_to_mp3(tags, &output_path, options)?;
+            } else {
+                tags.save_to_path(&output_path, options)
+                    .map_err(|e| error("write tags", e.to_string()))?;
+            }
         }
-        Ok(())
+        rename_partial(&final_path)
     }
 }
 
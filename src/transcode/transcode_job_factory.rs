@@ -2,41 +2,105 @@ use di::{injectable, Ref};
 
 use crate::errors::claxon_error;
 use crate::formats::target_format::TargetFormat;
-use crate::fs::{convert_to_id3v2, fix_track_numbering, get_vorbis_tags, FlacFile, PathManager};
+use crate::fs::{
+    convert_to_id3v2, fix_track_numbering, get_vorbis_tags, FlacFile, PathManager, TagFixer,
+    TagRules,
+};
 use crate::jobs::Job;
+use crate::loudness::ReplayGain;
+use crate::options::{FileOptions, TagOptions};
 use crate::source::Source;
 use crate::transcode::transcode_job::TranscodeJob;
 use crate::transcode::*;
+use colored::Colorize;
+use log::debug;
 use rogue_logging::Error;
 
 /// Create a [`TranscodeJob`] for each [`FlacFile`] in the [`Vec<FlacFile>`].
 #[injectable]
 pub struct TranscodeJobFactory {
+    options: Ref<FileOptions>,
+    tag_options: Ref<TagOptions>,
     paths: Ref<PathManager>,
 }
 
 impl TranscodeJobFactory {
     /// Create a [`TranscodeJob`] for each [`FlacFile`] in the [`Vec<FlacFile>`].
+    ///
+    /// If `replay_gains` is not empty it must have a [`ReplayGain`] for each [`FlacFile`].
     pub fn create(
         &self,
         flacs: &[FlacFile],
         source: &Source,
         format: TargetFormat,
+        replay_gains: &[ReplayGain],
     ) -> Result<Vec<Job>, Error> {
+        let cover_art = self.get_cover_art(source)?;
+        let rules = TagRules::new(&self.tag_options)?;
         let mut jobs = Vec::new();
         for (index, flac) in flacs.iter().enumerate() {
-            jobs.push(self.create_single(index, flac, source, format)?);
+            let replay_gain = replay_gains.get(index).copied();
+            jobs.push(self.create_single(
+                index,
+                flac,
+                source,
+                format,
+                replay_gain,
+                cover_art.clone(),
+                &rules,
+            )?);
         }
         Ok(jobs)
     }
 
+    /// Get the [`CoverArt`] to apply according to the [`CoverArtPolicy`].
+    fn get_cover_art(&self, source: &Source) -> Result<Option<CoverArt>, Error> {
+        let policy = self.options.cover_art.expect("cover_art should be set");
+        let max_file_size = self
+            .options
+            .max_file_size
+            .expect("max_file_size should be set");
+        let picture = match policy {
+            CoverArtPolicy::Keep => return Ok(None),
+            CoverArtPolicy::Strip => None,
+            CoverArtPolicy::Embed => {
+                if let Some(path) = CoverArt::get_folder_image(&source.directory) {
+                    debug!("{} cover art: {}", "Embedding".bold(), path.display());
+                    let iterative_compression = self
+                        .options
+                        .iterative_compression
+                        .expect("iterative_compression should be set");
+                    Some(CoverArt::get_picture(
+                        &path,
+                        self.options
+                            .cover_art_max_pixel_size
+                            .expect("cover_art_max_pixel_size should be set"),
+                        self.options.jpg_quality.expect("jpg_quality should be set"),
+                        iterative_compression.then_some(max_file_size),
+                    )?)
+                } else {
+                    debug!("{} folder image to embed in {}", "No".bold(), source);
+                    None
+                }
+            }
+        };
+        Ok(Some(CoverArt {
+            picture,
+            max_file_size,
+        }))
+    }
+
     /// Create a single [`TranscodeJob`] from a `flac_file`.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_single(
         &self,
         index: usize,
         flac: &FlacFile,
         source: &Source,
         format: TargetFormat,
+        replay_gain: Option<ReplayGain>,
+        cover_art: Option<CoverArt>,
+        rules: &TagRules,
     ) -> Result<Job, Error> {
         let info = flac
             .get_stream_info()
@@ -64,14 +128,33 @@ impl TranscodeJobFactory {
                 },
             )
         };
-        let tags = if matches!(format, TargetFormat::_320 | TargetFormat::V0) {
+        let is_mp3 = matches!(format, TargetFormat::_320 | TargetFormat::V0);
+        let fix_tags = self.tag_options.fix_tags.expect("fix_tags should be set");
+        // FLAC transcodes keep the source comments unless they need to be changed.
+        let tags = if is_mp3 || fix_tags || !rules.is_empty() {
             let mut tags = get_vorbis_tags(flac)?;
-            convert_to_id3v2(&mut tags);
-            let _ = fix_track_numbering(&mut tags);
+            if fix_tags {
+                let _ = TagFixer::execute(&mut tags, flac, source);
+            }
+            rules.apply(&mut tags, flac);
+            if is_mp3 {
+                convert_to_id3v2(&mut tags);
+                let _ = fix_track_numbering(&mut tags);
+            }
             Some(tags)
         } else {
             None
         };
-        Ok(Job::Transcode(TranscodeJob { id, variant, tags }))
+        Ok(Job::Transcode(TranscodeJob {
+            id,
+            variant,
+            tags,
+            replay_gain,
+            cover_art,
+            id3_version: self
+                .tag_options
+                .id3_version
+                .expect("id3_version should be set"),
+        }))
     }
 }
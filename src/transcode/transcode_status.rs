@@ -1,4 +1,5 @@
 use crate::formats::TargetFormat;
+use crate::jobs::JobFailure;
 use crate::queue::TimeStamp;
 use rogue_logging::Error;
 use serde::{Deserialize, Serialize};
@@ -16,6 +17,9 @@ pub struct TranscodeStatus {
     /// Error message if the transcode failed
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<Error>,
+    /// Each job that failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failures: Option<Vec<JobFailure>>,
 }
 
 #[derive(Clone, Deserialize, Serialize)]
@@ -1,8 +1,33 @@
+use std::path::PathBuf;
+
 use crate::transcode::decode::Decode;
 use crate::transcode::encode::Encode;
 use crate::transcode::resample::Resample;
 
+#[derive(Clone)]
 pub enum Variant {
     Transcode(Decode, Encode),
     Resample(Resample),
 }
+
+impl Variant {
+    /// Get the path of the output file.
+    #[must_use]
+    pub fn get_output(&self) -> PathBuf {
+        match self {
+            Variant::Transcode(_, encode) => encode.output.clone(),
+            Variant::Resample(resample) => resample.output.clone(),
+        }
+    }
+
+    /// Write to `output` instead.
+    #[must_use]
+    pub fn with_output(self, output: PathBuf) -> Self {
+        match self {
+            Variant::Transcode(decode, encode) => {
+                Variant::Transcode(decode, Encode { output, ..encode })
+            }
+            Variant::Resample(resample) => Variant::Resample(Resample { output, ..resample }),
+        }
+    }
+}
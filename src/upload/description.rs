@@ -0,0 +1,109 @@
+use minijinja::{Environment, UndefinedBehavior};
+use rogue_logging::Error;
+use serde::Serialize;
+
+use crate::errors::error;
+use crate::source::Metadata;
+
+/// Default template of the release description.
+pub const DEFAULT_DESCRIPTION_TEMPLATE: &str = "\
+[quote]Transcoded and uploaded with [url={{ app.repository }}][b]{{ app.name }}[/b] v{{ app.version }}[/url][/quote]\
+[quote][pad=0|10|0|20]Source[/pad] [url={{ source.url }}]{{ source.title }}[/url][/quote]\
+{% if command %}[quote][pad=0|10|0|0]Transcode[/pad] [code]{{ command }}[/code][/quote]{% endif %}\
+{% if details %}[quote][pad=0|10|0|19]Details[/pad] [hide][pre]{{ details }}[/pre][/hide][/quote]{% endif %}\
+{% if spectrograms %}[quote][pad=0|10|0|0]Spectrograms[/pad] [hide]\
+{% for url in spectrograms %}[img]{{ url }}[/img]{% endfor %}[/hide][/quote]{% endif %}\
+{% if tags_supplemented %}[quote]Missing tags of the source were supplemented from the API metadata and file names[/quote]{% endif %}\
+[quote][url={{ app.repository }}]Learn how easy it is to create and upload transcodes yourself![/url][/quote]";
+
+/// Values available to a description template.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct DescriptionContext {
+    pub app: AppDetails,
+    pub source: SourceDetails,
+    pub metadata: Metadata,
+    pub target: TargetDetails,
+    /// The decode and encode command of the first track.
+    pub command: Option<String>,
+    /// Details of the MP3 files.
+    pub details: Option<String>,
+    /// URLs of the spectrogram images.
+    pub spectrograms: Vec<String>,
+    pub tracks: Vec<TrackDetails>,
+    /// Were missing tags of the source supplemented in the transcodes?
+    pub tags_supplemented: bool,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct AppDetails {
+    pub name: String,
+    pub version: String,
+    pub repository: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SourceDetails {
+    pub name: String,
+    /// Permalink of the source torrent.
+    pub url: String,
+    /// Format of the source, for example `FLAC 24bit Lossless`.
+    pub title: String,
+    pub group_id: u32,
+    pub torrent_id: u32,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct TargetDetails {
+    /// Name of the target, for example `320`.
+    pub name: String,
+    /// Format of the target, for example `MP3`.
+    pub format: String,
+    /// Bitrate of the target, for example `V0 (VBR)`.
+    pub bitrate: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct TrackDetails {
+    /// Path of the file relative to the transcode directory.
+    pub path: String,
+    pub disc: Option<u32>,
+    pub track: Option<u32>,
+    pub artist: Option<String>,
+    pub title: Option<String>,
+    /// Duration formatted as `m:ss`.
+    pub duration: Option<String>,
+}
+
+/// Render release descriptions from a template.
+pub struct Description;
+
+impl Description {
+    pub fn render(template: &str, context: &DescriptionContext) -> Result<String, Error> {
+        let mut environment = Environment::new();
+        environment.set_undefined_behavior(UndefinedBehavior::Strict);
+        environment
+            .render_str(template, context)
+            .map_err(|e| error("render description", e.to_string()))
+    }
+
+    /// Check that a template can be rendered.
+    pub fn validate(template: &str) -> Result<(), String> {
+        let context = DescriptionContext {
+            command: Some("flac --decode | lame".to_owned()),
+            details: Some("details".to_owned()),
+            spectrograms: vec!["https://example.com/spectrogram.png".to_owned()],
+            tracks: vec![TrackDetails::default()],
+            ..DescriptionContext::default()
+        };
+        Self::render(template, &context)
+            .map(|_| ())
+            .map_err(|e| e.message)
+    }
+}
+
+/// Format a duration in seconds as `m:ss`.
+#[must_use]
+#[allow(clippy::integer_division)]
+pub fn format_duration(seconds: u32) -> String {
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
@@ -1,5 +1,9 @@
+pub use description::*;
 pub use upload_command::*;
 pub use upload_status::*;
 
+pub(crate) mod description;
+#[cfg(test)]
+mod tests;
 pub(crate) mod upload_command;
 pub(crate) mod upload_status;
@@ -0,0 +1,91 @@
+use crate::upload::*;
+
+#[test]
+fn render_default() {
+    // Arrange
+    let context = DescriptionContext {
+        app: AppDetails {
+            name: "caesura".to_owned(),
+            version: "1.2.3".to_owned(),
+            repository: "https://example.com/caesura".to_owned(),
+        },
+        source: SourceDetails {
+            url: "https://example.com/torrents.php?id=1&torrentid=2#torrent2".to_owned(),
+            title: "FLAC Lossless".to_owned(),
+            ..SourceDetails::default()
+        },
+        command: Some("flac --decode | lame".to_owned()),
+        ..DescriptionContext::default()
+    };
+    let expected = "[quote]Transcoded and uploaded with [url=https://example.com/caesura][b]caesura[/b] v1.2.3[/url][/quote]\
+[quote][pad=0|10|0|20]Source[/pad] [url=https://example.com/torrents.php?id=1&torrentid=2#torrent2]FLAC Lossless[/url][/quote]\
+[quote][pad=0|10|0|0]Transcode[/pad] [code]flac --decode | lame[/code][/quote]\
+[quote][url=https://example.com/caesura]Learn how easy it is to create and upload transcodes yourself![/url][/quote]";
+
+    // Act
+    let description = Description::render(DEFAULT_DESCRIPTION_TEMPLATE, &context);
+
+    // Assert
+    assert_eq!(description.expect("should render"), expected);
+}
+
+#[test]
+fn render_custom() {
+    // Arrange
+    let template = "{{ metadata.album }} [{{ target.bitrate }}]\
+{% for track in tracks %}\n{{ track.track }}. {{ track.title }} ({{ track.duration }}){% endfor %}";
+    let mut context = DescriptionContext::default();
+    context.metadata.album = "Album".to_owned();
+    context.target.bitrate = "V0 (VBR)".to_owned();
+    context.tracks = vec![TrackDetails {
+        track: Some(1),
+        title: Some("Title".to_owned()),
+        duration: Some(format_duration(125)),
+        ..TrackDetails::default()
+    }];
+
+    // Act
+    let description = Description::render(template, &context);
+
+    // Assert
+    assert_eq!(
+        description.expect("should render"),
+        "Album [V0 (VBR)]\n1. Title (2:05)"
+    );
+}
+
+#[test]
+fn render_default_spectrograms() {
+    // Arrange
+    let context = DescriptionContext {
+        spectrograms: vec![
+            "https://ptpimg.me/a.png".to_owned(),
+            "https://ptpimg.me/b.png".to_owned(),
+        ],
+        ..DescriptionContext::default()
+    };
+
+    // Act
+    let description = Description::render(DEFAULT_DESCRIPTION_TEMPLATE, &context);
+
+    // Assert
+    assert!(description.expect("should render").contains(
+        "[quote][pad=0|10|0|0]Spectrograms[/pad] [hide][img]https://ptpimg.me/a.png[/img][img]https://ptpimg.me/b.png[/img][/hide][/quote]"
+    ));
+}
+
+#[test]
+fn validate() {
+    // Arrange
+    let templates = ["{{ unknown }}", "{% if command %}", "{{ source.url"];
+
+    // Act
+    let default = Description::validate(DEFAULT_DESCRIPTION_TEMPLATE);
+    let invalid = templates.map(Description::validate);
+
+    // Assert
+    assert_eq!(default, Ok(()));
+    for result in invalid {
+        assert!(result.is_err());
+    }
+}
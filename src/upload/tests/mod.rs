@@ -0,0 +1 @@
+mod description_tests;
@@ -3,21 +3,29 @@ use std::path::{Path, PathBuf};
 
 use colored::Colorize;
 use di::{injectable, Ref, RefMut};
+use lofty::prelude::Accessor;
 use log::{info, trace, warn};
-use tokio::fs::{copy, hard_link};
+use tokio::fs::{copy, hard_link, read, read_to_string};
 
 use crate::built_info::*;
 use crate::errors::{error, io_error};
-use crate::eyed3::EyeD3Command;
 use crate::formats::{TargetFormat, TargetFormatProvider};
-use crate::fs::{copy_dir, Collector, PathManager};
+use crate::fs::{copy_dir, get_vorbis_tags, Collector, PathManager, TagRules};
 use crate::imdl::ImdlCommand;
 use crate::jobs::Job;
-use crate::options::{Options, SharedOptions, SourceArg, UploadOptions};
+use crate::mp3::Mp3Inspector;
+use crate::naming::SourceName;
+use crate::options::{
+    ClientOptions, ImageHostOptions, NamingOptions, Options, SharedOptions, SourceArg, TagOptions,
+    UploadOptions,
+};
 use crate::queue::TimeStamp;
 use crate::source::{get_permalink, Source, SourceProvider};
-use crate::transcode::{TranscodeJobFactory, Variant};
-use crate::upload::{UploadFormatStatus, UploadStatus};
+use crate::spectrogram::{SpectrogramStatus, SpectrogramUploader};
+use crate::torrent_client::{AddTorrentRequest, TorrentClient, TorrentClientFactory};
+use crate::transcode::{get_duration, TranscodeJobFactory, Variant};
+use crate::upload::*;
+use crate::verify::TagVerifier;
 use gazelle_api::{GazelleClient, UploadForm};
 use rogue_logging::Error;
 
@@ -28,12 +36,18 @@ const MUSIC_CATEGORY_ID: u8 = 0;
 pub struct UploadCommand {
     arg: Ref<SourceArg>,
     shared_options: Ref<SharedOptions>,
+    naming_options: Ref<NamingOptions>,
     upload_options: Ref<UploadOptions>,
+    client_options: Ref<ClientOptions>,
+    tag_options: Ref<TagOptions>,
+    image_host_options: Ref<ImageHostOptions>,
     source_provider: RefMut<SourceProvider>,
     api: RefMut<GazelleClient>,
     paths: Ref<PathManager>,
     targets: Ref<TargetFormatProvider>,
     transcode_job_factory: Ref<TranscodeJobFactory>,
+    client_factory: Ref<TorrentClientFactory>,
+    spectrogram_uploader: Ref<SpectrogramUploader>,
 }
 
 impl UploadCommand {
@@ -45,7 +59,11 @@ impl UploadCommand {
     pub async fn execute_cli(&mut self) -> Result<bool, Error> {
         if !self.arg.validate()
             || !self.shared_options.validate()
+            || !self.naming_options.validate()
             || !self.upload_options.validate()
+            || !self.client_options.validate()
+            || !self.tag_options.validate()
+            || !self.image_host_options.validate()
         {
             return Ok(false);
         }
@@ -56,19 +74,26 @@ impl UploadCommand {
             .get_from_options()
             .await
             .map_err(|e| error("get source from options", e.to_string()))?;
-        let status = self.execute(&source).await;
+        let status = self.execute(&source, None).await;
         // Errors were already printed as they occurred
         Ok(status.success)
     }
 
     /// Execute [`UploadCommand`] on a [`Source`].
     ///
+    /// Spectrogram URLs are taken from the [`SpectrogramStatus`] if it has them, otherwise
+    /// the spectrograms are uploaded if an image host is configured.
+    ///
     /// Returns an [`UploadStatus`] indicating the success of the operation and any errors.
     ///
     /// Errors are logged so do NOT need to be handled by the caller.
     #[must_use]
     #[allow(clippy::too_many_lines)]
-    pub async fn execute(&mut self, source: &Source) -> UploadStatus {
+    pub async fn execute(
+        &mut self,
+        source: &Source,
+        spectrogram: Option<&SpectrogramStatus>,
+    ) -> UploadStatus {
         let targets = self.targets.get(source.format, &source.existing);
         let mut api = self.api.write().expect("API should be available to read");
         let mut status = UploadStatus {
@@ -76,10 +101,24 @@ impl UploadCommand {
             formats: None,
             completed: TimeStamp::now(),
             errors: None,
+            not_seeding: None,
         };
         let mut errors = Vec::new();
         let mut formats = Vec::new();
+        let mut not_seeding = Vec::new();
+        let mut client = self.client_factory.create();
+        let spectrograms = self.get_spectrogram_urls(source, spectrogram).await;
         for target in targets {
+            if self
+                .upload_options
+                .preview_description
+                .expect("preview_description should be set")
+            {
+                let description = self.create_description(source, target, &spectrograms).await;
+                info!("{} description of {target} for {source}:", "Preview".bold());
+                info!("{description}");
+                continue;
+            }
             let torrent_path = self.paths.get_torrent_path(source, target, true);
             if !torrent_path.exists() {
                 warn!("In v0.19.0 the torrent file name format changed.");
@@ -133,13 +172,13 @@ impl UploadCommand {
                 path: torrent_path,
                 category_id: MUSIC_CATEGORY_ID,
                 remaster_year: source.metadata.year,
-                remaster_title: source.torrent.remaster_title.clone(),
-                remaster_record_label: source.torrent.remaster_record_label.clone(),
-                remaster_catalogue_number: source.torrent.remaster_catalogue_number.clone(),
+                remaster_title: source.metadata.remaster_title.clone(),
+                remaster_record_label: source.metadata.remaster_record_label.clone(),
+                remaster_catalogue_number: source.metadata.remaster_catalogue_number.clone(),
                 format: target.get_file_extension().to_uppercase(),
                 bitrate: target.get_bitrate().to_owned(),
-                media: source.torrent.media.clone(),
-                release_desc: self.create_description(source, target).await,
+                media: source.metadata.media.clone(),
+                release_desc: self.create_description(source, target, &spectrograms).await,
                 group_id: source.group.id,
             };
             if self.upload_options.dry_run.expect("dry_run should be set") {
@@ -160,6 +199,19 @@ impl UploadCommand {
                     let link = get_permalink(base, response.get_group_id(), id);
                     info!("{link}");
                     formats.push(UploadFormatStatus { format: target, id });
+                    if let Some(client) = &mut client {
+                        if let Err(error) = self.add_to_client(client, source, target).await {
+                            // If add_to_client fails the upload has still succeeded
+                            warn!("{error}");
+                            warn!(
+                                "{} {target} for {source} must be added to {} manually to seed",
+                                "Uploaded".bold(),
+                                client.get_kind()
+                            );
+                            errors.push(error);
+                            not_seeding.push(target);
+                        }
+                    }
                 }
                 Err(error) => {
                     error.log();
@@ -171,22 +223,13 @@ impl UploadCommand {
         }
         status.errors = errors.is_empty().not().then_some(errors);
         status.formats = formats.is_empty().not().then_some(formats);
+        status.not_seeding = not_seeding.is_empty().not().then_some(not_seeding);
         status
     }
 
     async fn copy_transcode(&self, source: &Source, target: &TargetFormat) -> Result<(), Error> {
         let source_dir = self.paths.get_transcode_target_dir(source, *target);
-        let source_dir_name = source_dir
-            .file_name()
-            .expect("source dir should have a name");
-        let target_dir = self
-            .shared_options
-            .content
-            .clone()
-            .expect("content should be set")
-            .first()
-            .expect("content should contain at least one directory")
-            .join(source_dir_name);
+        let target_dir = self.get_content_transcode_dir(source, *target);
         if target_dir.exists() {
             warn!(
                 "{} copy as the target directory already exists: {}",
@@ -215,6 +258,83 @@ impl UploadCommand {
         Ok(())
     }
 
+    /// Get the transcode directory within the first content directory.
+    fn get_content_transcode_dir(&self, source: &Source, target: TargetFormat) -> PathBuf {
+        let source_dir = self.paths.get_transcode_target_dir(source, target);
+        let source_dir_name = source_dir
+            .file_name()
+            .expect("source dir should have a name");
+        self.shared_options
+            .content
+            .clone()
+            .expect("content should be set")
+            .first()
+            .expect("content should contain at least one directory")
+            .join(source_dir_name)
+    }
+
+    /// Add the torrent to the torrent client and wait for it to confirm a 100% hash check.
+    ///
+    /// If `copy_transcode_to_content_dir` is enabled the content directory copy is seeded,
+    /// otherwise the transcode in the output directory is seeded.
+    async fn add_to_client(
+        &self,
+        client: &mut TorrentClient,
+        source: &Source,
+        target: TargetFormat,
+    ) -> Result<(), Error> {
+        let torrent_path = self.paths.get_torrent_path(source, target, true);
+        let transcode_dir = if self
+            .upload_options
+            .copy_transcode_to_content_dir
+            .expect("copy_transcode_to_content_dir should be set")
+        {
+            self.get_content_transcode_dir(source, target)
+        } else {
+            self.paths.get_transcode_target_dir(source, target)
+        };
+        let save_path = transcode_dir
+            .parent()
+            .expect("transcode dir should have a parent")
+            .to_path_buf();
+        let buffer = read(&torrent_path)
+            .await
+            .map_err(|e| io_error(e, "read torrent file"))?;
+        let summary = ImdlCommand::show(&torrent_path).await?;
+        let request = AddTorrentRequest {
+            buffer,
+            file_name: torrent_path
+                .file_name()
+                .expect("torrent path should have a name")
+                .to_string_lossy()
+                .to_string(),
+            save_path,
+            category: self.client_options.client_category.clone(),
+        };
+        trace!(
+            "{} {} to {} with save path {}",
+            "Adding".bold(),
+            torrent_path.display(),
+            client.get_kind(),
+            request.save_path.display()
+        );
+        client.add(&request).await?;
+        info!("{} {target} to {}", "Added".bold(), client.get_kind());
+        let timeout = self
+            .client_options
+            .get_timeout()
+            .expect("client_timeout should be set");
+        client
+            .wait_for_hash_check(&summary.info_hash, timeout)
+            .await?;
+        info!(
+            "{} {} reports {target} is 100% complete",
+            "Confirmed".bold(),
+            client.get_kind()
+        );
+        Ok(())
+    }
+
     async fn copy_torrent(
         &self,
         source: &Source,
@@ -250,56 +370,165 @@ impl UploadCommand {
         Ok(())
     }
 
-    #[allow(clippy::uninlined_format_args)]
-    async fn create_description(&self, source: &Source, target: TargetFormat) -> String {
+    /// Get the URLs of the spectrograms to link in the description.
+    ///
+    /// Cached URLs are used if available. Spectrograms are not uploaded for a preview or dry run.
+    async fn get_spectrogram_urls(
+        &self,
+        source: &Source,
+        spectrogram: Option<&SpectrogramStatus>,
+    ) -> Vec<String> {
+        if let Some(urls) = spectrogram.and_then(|status| status.urls.clone()) {
+            return urls;
+        }
+        let is_preview = self
+            .upload_options
+            .preview_description
+            .expect("preview_description should be set");
+        let is_dry_run = self.upload_options.dry_run.expect("dry_run should be set");
+        if is_preview || is_dry_run {
+            return Vec::new();
+        }
+        match self.spectrogram_uploader.upload(source).await {
+            Ok(urls) => urls.unwrap_or_default(),
+            Err(error) => {
+                warn!("{} to upload spectrograms: {error}", "Failed".bold());
+                Vec::new()
+            }
+        }
+    }
+
+    /// Render the release description from the template.
+    ///
+    /// If the template can't be read or rendered the built-in template is used instead.
+    async fn create_description(
+        &self,
+        source: &Source,
+        target: TargetFormat,
+        spectrograms: &[String],
+    ) -> String {
+        let context = self
+            .get_description_context(source, target, spectrograms)
+            .await;
+        let template = match &self.upload_options.description_template {
+            Some(path) => match read_to_string(path).await {
+                Ok(template) => template,
+                Err(error) => {
+                    warn!("Failed to read description template: {error}");
+                    DEFAULT_DESCRIPTION_TEMPLATE.to_owned()
+                }
+            },
+            None => DEFAULT_DESCRIPTION_TEMPLATE.to_owned(),
+        };
+        match Description::render(&template, &context) {
+            Ok(description) => description,
+            Err(error) => {
+                warn!("Failed to render description template: {error}");
+                Description::render(DEFAULT_DESCRIPTION_TEMPLATE, &context)
+                    .expect("default description template should render")
+            }
+        }
+    }
+
+    async fn get_description_context(
+        &self,
+        source: &Source,
+        target: TargetFormat,
+        spectrograms: &[String],
+    ) -> DescriptionContext {
         let base = &self
             .shared_options
             .indexer_url
             .clone()
             .expect("indexer_url should be set");
-        let source_url = get_permalink(base, source.group.id, source.torrent.id);
-        let source_title = source.format.get_title();
-        let mut lines: Vec<String> = vec![
-            format!(
-                "Transcoded and uploaded with [url={}][b]{}[/b] v{}[/url]",
-                PKG_REPOSITORY, PKG_NAME, PKG_VERSION
-            ),
-            format!("[pad=0|10|0|20]Source[/pad] [url={source_url}]{source_title}[/url]"),
-        ];
-        match self.get_command(source, target) {
-            Ok(transcode_command) => lines.push(format!(
-                "[pad=0|10|0|0]Transcode[/pad] [code]{transcode_command}[/code]"
-            )),
-            Err(error) => warn!("Failed to get transcode command: {error}"),
-        }
-        if matches!(target, TargetFormat::_320 | TargetFormat::V0) {
+        let command = match self.get_command(source, target) {
+            Ok(command) => Some(command),
+            Err(error) => {
+                warn!("Failed to get transcode command: {error}");
+                None
+            }
+        };
+        let details = if matches!(target, TargetFormat::_320 | TargetFormat::V0) {
             match self.get_details(source, target).await {
-                Ok(details) => {
-                    lines.push(format!(
-                        "[pad=0|10|0|19]Details[/pad] [hide][pre]{details}[/pre][/hide]"
-                    ));
+                Ok(details) => Some(details),
+                Err(error) => {
+                    warn!("Failed to get transcode details: {error}");
+                    None
                 }
-                Err(error) => warn!("Failed to get transcode details: {error}"),
             }
+        } else {
+            None
+        };
+        DescriptionContext {
+            app: AppDetails {
+                name: PKG_NAME.to_owned(),
+                version: PKG_VERSION.to_owned(),
+                repository: PKG_REPOSITORY.to_owned(),
+            },
+            source: SourceDetails {
+                name: SourceName::get(&source.metadata),
+                url: get_permalink(base, source.group.id, source.torrent.id),
+                title: source.format.get_title().to_owned(),
+                group_id: source.group.id,
+                torrent_id: source.torrent.id,
+            },
+            metadata: source.metadata.clone(),
+            target: TargetDetails {
+                name: target.get_name().to_owned(),
+                format: target.get_file_extension().to_uppercase(),
+                bitrate: target.get_bitrate().to_owned(),
+            },
+            command,
+            details,
+            spectrograms: spectrograms.to_vec(),
+            tracks: self.get_tracks(source, target),
+            tags_supplemented: self.is_tags_supplemented(source),
+        }
+    }
+
+    /// Get the details of each track from the tags of the source.
+    fn get_tracks(&self, source: &Source, target: TargetFormat) -> Vec<TrackDetails> {
+        let target_dir = self.paths.get_transcode_target_dir(source, target);
+        Collector::get_flacs(&source.directory)
+            .iter()
+            .map(|flac| {
+                let path = self.paths.get_transcode_path(source, target, flac);
+                let path = path.strip_prefix(&target_dir).unwrap_or(&path);
+                let tags = get_vorbis_tags(flac).ok();
+                let duration = flac
+                    .get_stream_info()
+                    .ok()
+                    .and_then(|info| get_duration(&info))
+                    .map(format_duration);
+                TrackDetails {
+                    path: path.to_string_lossy().to_string(),
+                    disc: tags.as_ref().and_then(Accessor::disk),
+                    track: tags.as_ref().and_then(Accessor::track),
+                    artist: tags.as_ref().and_then(Accessor::artist).map(String::from),
+                    title: tags.as_ref().and_then(Accessor::title).map(String::from),
+                    duration,
+                }
+            })
+            .collect()
+    }
+
+    /// Were missing tags of the source supplemented in the transcodes?
+    fn is_tags_supplemented(&self, source: &Source) -> bool {
+        if !self.tag_options.fix_tags.expect("fix_tags should be set") {
+            return false;
         }
-        lines.push(format!(
-            "[url={}]Learn how easy it is to create and upload transcodes yourself![/url]",
-            PKG_REPOSITORY
-        ));
-        lines.into_iter().fold(String::new(), |mut output, line| {
-            output.push_str("[quote]");
-            output.push_str(&line);
-            output.push_str("[/quote]");
-            output
+        Collector::get_flacs(&source.directory).iter().any(|flac| {
+            TagVerifier::execute(flac, source, false).is_ok_and(|tags| !tags.is_empty())
         })
     }
 
     pub fn get_command(&self, source: &Source, target: TargetFormat) -> Result<String, Error> {
         let flacs = Collector::get_flacs(&source.directory);
         let flac = flacs.first().expect("Should be at least one FLAC");
+        let rules = TagRules::new(&self.tag_options)?;
         let job = self
             .transcode_job_factory
-            .create_single(0, flac, source, target)?;
+            .create_single(0, flac, source, target, None, None, &rules)?;
         let Job::Transcode(job) = job else {
             return Err(error(
                 "get transcode command",
@@ -336,6 +565,6 @@ impl UploadCommand {
     }
     async fn get_details(&self, source: &Source, target: TargetFormat) -> Result<String, Error> {
         let path = self.paths.get_transcode_target_dir(source, target);
-        EyeD3Command::display(&path).await
+        Mp3Inspector::display_dir(&path).await
     }
 }
@@ -15,10 +15,16 @@ pub struct UploadStatus {
     /// Error messages
     ///
     /// It is possible for [`UploadCommand`] to succeed while still having errors.
-    /// For example `copy_transcode_to_content_dir` and `copy_torrent_to` are recoverable,
-    /// so may error but the upload still proceeds successfully.
+    /// For example `copy_transcode_to_content_dir`, `copy_torrent_to` and adding to the
+    /// torrent client are recoverable, so may error but the upload still proceeds successfully.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub errors: Option<Vec<Error>>,
+    /// Uploaded formats that could not be added to the torrent client
+    ///
+    /// The upload succeeded so it won't be retried, but these formats are not seeding
+    /// until they're added to the torrent client manually.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_seeding: Option<Vec<TargetFormat>>,
 }
 
 #[derive(Clone, Deserialize, Serialize)]
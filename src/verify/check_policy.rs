@@ -0,0 +1,28 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// How a source that fails an analysis check is handled.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckPolicy {
+    /// Source is unsuitable for transcoding
+    Fail,
+    /// Source is verified but a warning is logged
+    #[default]
+    Warn,
+    /// Analysis is skipped
+    Skip,
+}
+
+impl Display for CheckPolicy {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let output = match self {
+            CheckPolicy::Fail => "fail",
+            CheckPolicy::Warn => "warn",
+            CheckPolicy::Skip => "skip",
+        };
+        output.fmt(formatter)
+    }
+}
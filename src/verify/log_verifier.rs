@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::fs::Collector;
+use crate::rip_log::{CueSheet, LogChecksum, RipLog};
+use crate::source::SourceIssue;
+use crate::source::SourceIssue::*;
+
+/// Verify the EAC or XLD logs and cue sheets of a CD source.
+pub struct LogVerifier;
+
+impl LogVerifier {
+    pub fn execute(source_dir: &Path) -> Vec<SourceIssue> {
+        let mut issues = Vec::new();
+        let mut logs: BTreeMap<PathBuf, Vec<(PathBuf, usize)>> = BTreeMap::new();
+        for path in Collector::get_logs(source_dir) {
+            let log = match RipLog::read(&path) {
+                Ok(Some(log)) => log,
+                Ok(None) => continue,
+                Err(error) => {
+                    issues.push(LogError {
+                        path,
+                        error: error.to_string(),
+                    });
+                    continue;
+                }
+            };
+            issues.append(&mut check_log(&path, &log));
+            logs.entry(get_parent(&path))
+                .or_default()
+                .push((path, log.tracks.len()));
+        }
+        let mut cues: BTreeMap<PathBuf, Vec<(PathBuf, usize)>> = BTreeMap::new();
+        for path in Collector::get_cues(source_dir) {
+            match CueSheet::read(&path) {
+                Ok(cue) => cues
+                    .entry(get_parent(&path))
+                    .or_default()
+                    .push((path, cue.audio_tracks)),
+                Err(error) => issues.push(LogError {
+                    path,
+                    error: error.to_string(),
+                }),
+            }
+        }
+        for (path, expected, actual) in get_count_mismatches(logs) {
+            issues.push(LogTrackCount {
+                path,
+                expected,
+                actual,
+            });
+        }
+        for (path, expected, actual) in get_count_mismatches(cues) {
+            issues.push(CueTrackCount {
+                path,
+                expected,
+                actual,
+            });
+        }
+        issues
+    }
+}
+
+fn check_log(path: &Path, log: &RipLog) -> Vec<SourceIssue> {
+    let mut issues = Vec::new();
+    match log.checksum {
+        LogChecksum::Missing => issues.push(LogChecksumMissing {
+            path: path.to_path_buf(),
+        }),
+        LogChecksum::Invalid => issues.push(LogChecksumInvalid {
+            path: path.to_path_buf(),
+        }),
+        LogChecksum::Valid | LogChecksum::Unverified => {}
+    }
+    if !log.is_secure() {
+        issues.push(ReadMode {
+            path: path.to_path_buf(),
+            mode: log.read_mode.clone().unwrap_or("unknown".to_owned()),
+        });
+    }
+    let inaccurate: Vec<u32> = log
+        .tracks
+        .iter()
+        .filter(|track| track.accurate == Some(false))
+        .map(|track| track.number)
+        .collect();
+    if !inaccurate.is_empty() {
+        issues.push(AccurateRip {
+            path: path.to_path_buf(),
+            tracks: inaccurate,
+        });
+    }
+    let mismatched: Vec<u32> = log
+        .tracks
+        .iter()
+        .filter(|track| track.is_crc_mismatch())
+        .map(|track| track.number)
+        .collect();
+    if !mismatched.is_empty() {
+        issues.push(CrcMismatch {
+            path: path.to_path_buf(),
+            tracks: mismatched,
+        });
+    }
+    issues
+}
+
+fn get_parent(path: &Path) -> PathBuf {
+    path.parent()
+        .expect("file should have a parent")
+        .to_path_buf()
+}
+
+/// Compare the track counts of the files in each directory to the FLACs in that directory.
+///
+/// A directory may contain a log or cue for each disc, or several logs for the same disc,
+/// so the count is a match if either the sum or any individual count matches.
+fn get_count_mismatches(
+    directories: BTreeMap<PathBuf, Vec<(PathBuf, usize)>>,
+) -> Vec<(PathBuf, usize, usize)> {
+    let mut mismatches = Vec::new();
+    for (directory, files) in directories {
+        let expected = Collector::get_flacs(&directory).len();
+        let total: usize = files.iter().map(|(_, count)| count).sum();
+        if total == expected || files.iter().any(|(_, count)| *count == expected) {
+            continue;
+        }
+        if let Some((path, _)) = files.into_iter().next() {
+            mismatches.push((path, expected, total));
+        }
+    }
+    mismatches
+}
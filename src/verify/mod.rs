@@ -1,7 +1,16 @@
+pub use check_policy::*;
+pub use log_verifier::*;
+pub use resolution_verifier::*;
+pub use spectral_verifier::*;
 pub use stream_verifier::*;
+pub use tag_verifier::*;
 pub use verify_command::*;
 pub use verify_status::*;
 
+pub(crate) mod check_policy;
+mod log_verifier;
+mod resolution_verifier;
+mod spectral_verifier;
 mod stream_verifier;
 mod tag_verifier;
 #[cfg(test)]
@@ -0,0 +1,118 @@
+use crate::fs::FlacFile;
+use crate::source::SourceIssue;
+use crate::source::SourceIssue::*;
+use crate::spectrogram::DecodedAudio;
+use crate::verify::get_band_levels;
+
+/// Sample rates a high resolution source may have been upsampled from.
+const ORIGINAL_SAMPLE_RATES: [u32; 4] = [44100, 48000, 88200, 96000];
+
+/// Width of the frequency bands the spectrum is averaged into.
+const BAND_WIDTH: f64 = 500.0;
+
+/// Margin above the original Nyquist frequency for the transition of the resampling filter.
+const NYQUIST_MARGIN: f64 = 1000.0;
+
+/// Level in dB below which there is too little content to analyze.
+const MIN_LEVEL: f64 = -110.0;
+
+/// Level in dB below which a band is considered empty.
+///
+/// This is well below the noise floor of any analog recording, but above the
+/// quantization noise of a 24-bit file.
+const MAX_EMPTY_LEVEL: f64 = -150.0;
+
+/// Detect high resolution FLAC files that were padded or upsampled from a lower resolution.
+pub struct ResolutionVerifier;
+
+impl ResolutionVerifier {
+    /// Is `flac` higher resolution than 16-bit 48 kHz?
+    #[must_use]
+    pub fn is_high_resolution(flac: &FlacFile) -> bool {
+        flac.get_stream_info()
+            .is_ok_and(|info| info.bits_per_sample > 16 || info.sample_rate > 48000)
+    }
+
+    /// Analyze the [`decode_analysis_window`](crate::verify::decode_analysis_window) of `flac`.
+    #[must_use]
+    pub fn execute(flac: &FlacFile, audio: &DecodedAudio) -> Vec<SourceIssue> {
+        let mut issues = Vec::new();
+        if audio.bits_per_sample > 16 {
+            if let Some(effective) = get_effective_bit_depth(audio) {
+                if effective <= 16 {
+                    issues.push(PaddedBitDepth {
+                        path: flac.path.clone(),
+                        bits: audio.bits_per_sample,
+                        effective,
+                    });
+                }
+            }
+        }
+        if let Some(original) = get_upsampled_rate(audio) {
+            issues.push(Upsampled {
+                path: flac.path.clone(),
+                rate: audio.sample_rate,
+                original,
+            });
+        }
+        issues
+    }
+}
+
+/// Get the number of bits used by the samples.
+///
+/// Low-order bits that are zero in every sample are not counted.
+///
+/// Returns `None` if the audio is silent.
+#[must_use]
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn get_effective_bit_depth(audio: &DecodedAudio) -> Option<u32> {
+    let scale = f64::from(1_u32 << (audio.bits_per_sample - 1));
+    let used = audio.samples.iter().fold(0_u32, |used, sample| {
+        used | (f64::from(*sample) * scale).round() as i32 as u32
+    });
+    if used == 0 {
+        return None;
+    }
+    let unused = used.trailing_zeros().min(audio.bits_per_sample);
+    Some(audio.bits_per_sample - unused)
+}
+
+/// Get the sample rate the audio was upsampled from.
+///
+/// Upsampling leaves no content above the Nyquist frequency of the original sample rate.
+///
+/// Returns `None` if the audio has content above the Nyquist frequency of every lower
+/// sample rate.
+#[must_use]
+#[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+pub fn get_upsampled_rate(audio: &DecodedAudio) -> Option<u32> {
+    let (bands, band_width) = get_band_levels(audio, BAND_WIDTH);
+    let audible: Vec<f64> = bands
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index as f64 * band_width < 20_000.0)
+        .map(|(_, level)| *level)
+        .collect();
+    let reference = audible.iter().sum::<f64>() / audible.len().max(1) as f64;
+    if reference < MIN_LEVEL {
+        return None;
+    }
+    ORIGINAL_SAMPLE_RATES
+        .into_iter()
+        .filter(|rate| *rate < audio.sample_rate)
+        .find(|rate| {
+            let from = f64::from(*rate) / 2.0 + NYQUIST_MARGIN;
+            bands
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| *index as f64 * band_width >= from)
+                .map(|(_, level)| *level)
+                .reduce(f64::max)
+                .is_some_and(|above| above < MAX_EMPTY_LEVEL)
+        })
+}
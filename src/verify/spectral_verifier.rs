@@ -0,0 +1,184 @@
+use std::time::Duration;
+
+use crate::fs::FlacFile;
+use crate::source::SourceIssue;
+use crate::source::SourceIssue::*;
+use crate::spectrogram::{decode_flac, power_to_db, DecodedAudio, SpectrumAnalyzer};
+use crate::verify::ResolutionVerifier;
+
+/// Duration of audio analyzed from the middle of each track.
+const ANALYSIS_DURATION: Duration = Duration::from_secs(30);
+
+const WINDOW_SIZE: usize = 4096;
+
+const DYNAMIC_RANGE: f64 = 120.0;
+
+/// Width of the frequency bands the spectrum is averaged into.
+const BAND_WIDTH: f64 = 250.0;
+
+/// Number of bands averaged for the level below a cutoff.
+const BANDS_BELOW: usize = 4;
+
+/// Lowest cutoff considered.
+///
+/// Lower cutoffs are as likely to be a property of the recording as of an encoder.
+const MIN_CUTOFF: f64 = 14_000.0;
+
+/// Highest cutoff considered.
+///
+/// Higher cutoffs are indistinguishable from the anti-aliasing filter of a 44.1 kHz master.
+const MAX_CUTOFF: f64 = 20_500.0;
+
+/// Minimum difference in dB between the level below a cutoff and the loudest band above it.
+const MIN_DROP: f64 = 30.0;
+
+/// Level in dB below which there is too little content to analyze.
+const MIN_LEVEL: f64 = -110.0;
+
+/// Detect FLAC files that were likely transcoded from a lossy source.
+///
+/// MP3 and AAC encoders apply a low pass filter so their spectrum has a brick-wall
+/// cutoff, typically at 16 kHz, 19 kHz or 20.5 kHz, whereas the spectrum of a
+/// lossless source rolls off gradually towards the Nyquist frequency.
+pub struct SpectralVerifier;
+
+impl SpectralVerifier {
+    /// Analyze the [`decode_analysis_window`] of `flac`.
+    #[must_use]
+    pub fn execute(flac: &FlacFile, audio: &DecodedAudio) -> Option<SourceIssue> {
+        let cutoff = get_lossy_cutoff(audio)?;
+        Some(LossySource {
+            path: flac.path.clone(),
+            cutoff,
+        })
+    }
+}
+
+/// Issues found by analyzing the spectrum of a FLAC.
+#[derive(Debug, Default)]
+pub struct SpectrumIssues {
+    /// Issues found by [`SpectralVerifier`]
+    pub lossy: Vec<SourceIssue>,
+    /// Issues found by [`ResolutionVerifier`]
+    pub resolution: Vec<SourceIssue>,
+}
+
+impl SpectrumIssues {
+    /// Add an issue that prevented the analysis.
+    ///
+    /// The issue is added to `lossy` if the lossy check is enabled, otherwise to
+    /// `resolution`, so it's subject to the [`CheckPolicy`](crate::verify::CheckPolicy)
+    /// of an enabled check.
+    pub fn push_error(&mut self, issue: SourceIssue, lossy: bool) {
+        if lossy {
+            self.lossy.push(issue);
+        } else {
+            self.resolution.push(issue);
+        }
+    }
+}
+
+/// Decode the analysis window of `flac` once and apply the [`SpectralVerifier`] if `lossy`
+/// is set and the [`ResolutionVerifier`] if `resolution` is set.
+///
+/// Decoding and analysis are CPU bound so this should be run on a blocking thread.
+#[must_use]
+pub fn verify_spectrum(flac: &FlacFile, lossy: bool, resolution: bool) -> SpectrumIssues {
+    let mut issues = SpectrumIssues::default();
+    let resolution = resolution && ResolutionVerifier::is_high_resolution(flac);
+    if !lossy && !resolution {
+        return issues;
+    }
+    let audio = match decode_analysis_window(flac) {
+        Ok(audio) => audio,
+        Err(issue) => {
+            issues.push_error(issue, lossy);
+            return issues;
+        }
+    };
+    if lossy {
+        issues.lossy.extend(SpectralVerifier::execute(flac, &audio));
+    }
+    if resolution {
+        issues.resolution = ResolutionVerifier::execute(flac, &audio);
+    }
+    issues
+}
+
+/// Decode [`ANALYSIS_DURATION`] from the middle of a FLAC file.
+#[allow(clippy::integer_division)]
+pub(crate) fn decode_analysis_window(flac: &FlacFile) -> Result<DecodedAudio, SourceIssue> {
+    let info = flac.get_stream_info().map_err(|error| FlacError {
+        path: flac.path.clone(),
+        error: error.to_string(),
+    })?;
+    let total = info.samples.unwrap_or_default() / u64::from(info.sample_rate);
+    let start = Duration::from_secs(total / 2).saturating_sub(ANALYSIS_DURATION / 2);
+    decode_flac(&flac.path, start, Some(ANALYSIS_DURATION)).map_err(|error| FlacError {
+        path: flac.path.clone(),
+        error: error.to_string(),
+    })
+}
+
+/// Get the average level in dB of consecutive frequency bands of approximately `band_width` Hz.
+///
+/// Returns the levels and the actual width of each band in Hz.
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub(crate) fn get_band_levels(audio: &DecodedAudio, band_width: f64) -> (Vec<f64>, f64) {
+    let mut analyzer = SpectrumAnalyzer::new(WINDOW_SIZE, DYNAMIC_RANGE);
+    let power = analyzer.get_average_power(&audio.samples);
+    let bin_width = f64::from(audio.sample_rate) / WINDOW_SIZE as f64;
+    let bins_per_band = ((band_width / bin_width).round() as usize).max(1);
+    let levels = power
+        .chunks(bins_per_band)
+        .map(|chunk| power_to_db(chunk.iter().sum::<f64>() / chunk.len() as f64))
+        .collect();
+    (levels, bins_per_band as f64 * bin_width)
+}
+
+/// Get the frequency in Hz of a brick-wall cutoff typical of a lossy encoder.
+///
+/// Returns `None` if the spectrum has no such cutoff.
+#[must_use]
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn get_lossy_cutoff(audio: &DecodedAudio) -> Option<u32> {
+    let (bands, band_width) = get_band_levels(audio, BAND_WIDTH);
+    let mut best: Option<(f64, f64)> = None;
+    for index in BANDS_BELOW..bands.len() {
+        let cutoff = index as f64 * band_width;
+        if !(MIN_CUTOFF..=MAX_CUTOFF).contains(&cutoff) {
+            continue;
+        }
+        let below = bands.get(index - BANDS_BELOW..index)?;
+        let below = below.iter().sum::<f64>() / below.len() as f64;
+        if below < MIN_LEVEL {
+            continue;
+        }
+        // Skip the band containing the transition of the low pass filter
+        let Some(above) = bands
+            .get(index + 1..)
+            .and_then(|above| above.iter().copied().reduce(f64::max))
+        else {
+            continue;
+        };
+        let drop = below - above;
+        if best.is_none_or(|(_, best_drop)| drop > best_drop) {
+            best = Some((cutoff, drop));
+        }
+    }
+    let (cutoff, drop) = best?;
+    if drop < MIN_DROP {
+        return None;
+    }
+    Some(cutoff.round() as u32)
+}
@@ -1,14 +1,32 @@
-use crate::fs::{convert_to_id3v2, fix_track_numbering, get_vorbis_tags, FlacFile};
+use crate::fs::{convert_to_id3v2, fix_track_numbering, get_vorbis_tags, FlacFile, TagFixer};
 use crate::source::Source;
 use lofty::prelude::Accessor;
 use lofty::prelude::ItemKey::Composer;
+use lofty::tag::Tag;
 use rogue_logging::Error;
 
 pub struct TagVerifier;
 
 impl TagVerifier {
-    pub fn execute(flac: &FlacFile, source: &Source) -> Result<Vec<String>, Error> {
-        let mut tags = get_vorbis_tags(flac)?;
+    /// Get the names of the tags a [`FlacFile`] is missing.
+    ///
+    /// If `fix_tags` is set then tags that [`TagFixer`] can supplement are not reported.
+    pub fn execute(flac: &FlacFile, source: &Source, fix_tags: bool) -> Result<Vec<String>, Error> {
+        let tags = get_vorbis_tags(flac)?;
+        Ok(Self::get_missing(tags, flac, source, fix_tags))
+    }
+
+    /// Get the names of the tags missing from the already read `tags` of a [`FlacFile`].
+    #[must_use]
+    pub fn get_missing(
+        mut tags: Tag,
+        flac: &FlacFile,
+        source: &Source,
+        fix_tags: bool,
+    ) -> Vec<String> {
+        if fix_tags {
+            let _ = TagFixer::execute(&mut tags, flac, source);
+        }
         convert_to_id3v2(&mut tags);
         let _ = fix_track_numbering(&mut tags);
         let mut missing: Vec<String> = Vec::new();
@@ -28,6 +46,6 @@ impl TagVerifier {
         if tags.track().is_none() {
             missing.push("track_number".to_owned());
         }
-        Ok(missing)
+        missing
     }
 }
@@ -0,0 +1,76 @@
+use std::fs::{create_dir_all, write};
+
+use crate::source::SourceIssue;
+use crate::testing::TempDirectory;
+use crate::verify::LogVerifier;
+
+const EAC_LOG: &str = "Exact Audio Copy V1.6 from 23. October 2020
+
+Read mode               : Burst
+
+Track  1
+
+     Test CRC 1A2B3C4D
+     Copy CRC 1A2B3C4D
+     Accurately ripped (confidence 5)  [ABCDEF01]  (AR v2)
+
+Track  2
+
+     Test CRC 11111111
+     Copy CRC 22222222
+     Cannot be verified as accurate (confidence 3)  [12345678], AccurateRip returned [87654321]  (AR v2)
+
+End of status report
+";
+
+const CUE_SHEET: &str = r#"FILE "01.flac" WAVE
+  TRACK 01 AUDIO
+FILE "02.flac" WAVE
+  TRACK 02 AUDIO
+FILE "03.flac" WAVE
+  TRACK 03 AUDIO
+"#;
+
+#[test]
+fn log_verifier_execute() {
+    // Arrange
+    let source_dir = TempDirectory::create("caesura").join("log_verifier");
+    create_dir_all(&source_dir).expect("should create source directory");
+    write(source_dir.join("01.flac"), []).expect("should write flac");
+    write(source_dir.join("02.flac"), []).expect("should write flac");
+    write(source_dir.join("rip.log"), EAC_LOG).expect("should write log");
+    write(source_dir.join("rip.cue"), CUE_SHEET).expect("should write cue");
+
+    // Act
+    let issues = LogVerifier::execute(&source_dir);
+
+    // Assert
+    let names: Vec<String> = issues
+        .iter()
+        .map(|issue| {
+            let json = serde_json::to_value(issue).expect("should serialize");
+            json.get("type")
+                .and_then(|x| x.as_str())
+                .expect("should have type")
+                .to_owned()
+        })
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "log_checksum_missing",
+            "read_mode",
+            "accurate_rip",
+            "crc_mismatch",
+            "cue_track_count"
+        ]
+    );
+    assert!(issues.iter().any(|issue| matches!(
+        issue,
+        SourceIssue::CueTrackCount {
+            expected: 2,
+            actual: 3,
+            ..
+        }
+    )));
+}
@@ -1,2 +1,5 @@
+mod log_verifier_tests;
+mod resolution_verifier_tests;
 mod source_rules_tests;
+mod spectral_verifier_tests;
 mod verify_command_tests;
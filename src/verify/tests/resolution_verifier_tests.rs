@@ -0,0 +1,80 @@
+use crate::spectrogram::DecodedAudio;
+use crate::testing::create_sines;
+use crate::verify::{get_effective_bit_depth, get_upsampled_rate};
+
+/// Create a second of 24 bit audio with a sine every 500 Hz up to `max_frequency`.
+fn create_audio(sample_rate: u32, max_frequency: u32) -> DecodedAudio {
+    create_sines(sample_rate, 24, 1, 500, max_frequency, 0.01)
+}
+
+/// Round the samples to `bits` bits.
+fn quantize(audio: &mut DecodedAudio, bits: u32) {
+    let scale = f64::from(1_u32 << (bits - 1));
+    for sample in &mut audio.samples {
+        #[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+        let quantized = ((f64::from(*sample) * scale).round() / scale) as f32;
+        *sample = quantized;
+    }
+}
+
+#[test]
+fn get_effective_bit_depth_padded() {
+    // Arrange
+    let mut audio = create_audio(44100, 16000);
+    quantize(&mut audio, 16);
+
+    // Act
+    let bits = get_effective_bit_depth(&audio);
+
+    // Assert
+    assert_eq!(bits, Some(16));
+}
+
+#[test]
+fn get_effective_bit_depth_full() {
+    // Arrange
+    let mut audio = create_audio(44100, 16000);
+    quantize(&mut audio, 24);
+
+    // Act
+    let bits = get_effective_bit_depth(&audio);
+
+    // Assert
+    assert_eq!(bits, Some(24));
+}
+
+#[test]
+fn get_upsampled_rate_from_44100() {
+    // Arrange
+    let audio = create_audio(96000, 20000);
+
+    // Act
+    let rate = get_upsampled_rate(&audio);
+
+    // Assert
+    assert_eq!(rate, Some(44100));
+}
+
+#[test]
+fn get_upsampled_rate_from_48000() {
+    // Arrange
+    let audio = create_audio(96000, 23500);
+
+    // Act
+    let rate = get_upsampled_rate(&audio);
+
+    // Assert
+    assert_eq!(rate, Some(48000));
+}
+
+#[test]
+fn get_upsampled_rate_full_bandwidth() {
+    // Arrange
+    let audio = create_audio(96000, 47500);
+
+    // Act
+    let rate = get_upsampled_rate(&audio);
+
+    // Assert
+    assert_eq!(rate, None);
+}
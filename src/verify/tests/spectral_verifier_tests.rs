@@ -0,0 +1,86 @@
+use crate::source::SourceIssue;
+use crate::spectrogram::DecodedAudio;
+use crate::testing::create_sines;
+use crate::verify::{get_lossy_cutoff, SpectrumIssues};
+
+const SAMPLE_RATE: u32 = 44100;
+
+/// Create two seconds of 16 bit audio with a sine every 100 Hz up to `max_frequency`.
+fn create_audio(max_frequency: u32) -> DecodedAudio {
+    create_sines(SAMPLE_RATE, 16, 2, 100, max_frequency, 0.002)
+}
+
+#[test]
+fn get_lossy_cutoff_16_khz() {
+    // Arrange
+    let audio = create_audio(16000);
+
+    // Act
+    let cutoff = get_lossy_cutoff(&audio);
+
+    // Assert
+    let cutoff = cutoff.expect("cutoff should be detected");
+    assert!((15750..=16250).contains(&cutoff), "cutoff was {cutoff} Hz");
+}
+
+#[test]
+fn get_lossy_cutoff_19_khz() {
+    // Arrange
+    let audio = create_audio(19000);
+
+    // Act
+    let cutoff = get_lossy_cutoff(&audio);
+
+    // Assert
+    let cutoff = cutoff.expect("cutoff should be detected");
+    assert!((18750..=19250).contains(&cutoff), "cutoff was {cutoff} Hz");
+}
+
+#[test]
+fn get_lossy_cutoff_full_bandwidth() {
+    // Arrange
+    let audio = create_audio(22000);
+
+    // Act
+    let cutoff = get_lossy_cutoff(&audio);
+
+    // Assert
+    assert_eq!(cutoff, None);
+}
+
+#[test]
+fn get_lossy_cutoff_silence() {
+    // Arrange
+    let audio = DecodedAudio {
+        samples: vec![0.0; 44100],
+        sample_rate: SAMPLE_RATE,
+        bits_per_sample: 16,
+    };
+
+    // Act
+    let cutoff = get_lossy_cutoff(&audio);
+
+    // Assert
+    assert_eq!(cutoff, None);
+}
+
+#[test]
+fn spectrum_issues_push_error() {
+    // Arrange
+    let issue = || SourceIssue::Error {
+        domain: "Spectrum".to_owned(),
+        details: "failed".to_owned(),
+    };
+    let mut lossy = SpectrumIssues::default();
+    let mut resolution = SpectrumIssues::default();
+
+    // Act
+    lossy.push_error(issue(), true);
+    resolution.push_error(issue(), false);
+
+    // Assert
+    assert_eq!(lossy.lossy.len(), 1);
+    assert!(lossy.resolution.is_empty());
+    assert!(resolution.lossy.is_empty());
+    assert_eq!(resolution.resolution.len(), 1);
+}
@@ -1,13 +1,18 @@
 use colored::Colorize;
 use di::{injectable, Ref, RefMut};
+use futures::future::join_all;
 use log::*;
 
 use crate::formats::TargetFormatProvider;
-use crate::fs::{Collector, PathManager};
+use crate::fs::{get_vorbis_tags, Collector, PathManager};
 use crate::imdl::imdl_command::ImdlCommand;
+use crate::jobs::Scheduler;
+use crate::loudness::analyze_flacs;
 use crate::naming::Shortener;
 use crate::options::verify_options::VerifyOptions;
-use crate::options::{Options, SharedOptions, SourceArg};
+use crate::options::{
+    LoudnessOptions, NamingOptions, Options, SharedOptions, SourceArg, TagOptions, TargetOptions,
+};
 use crate::source::SourceIssue::*;
 use crate::source::*;
 use crate::verify::tag_verifier::TagVerifier;
@@ -17,17 +22,23 @@ use gazelle_api::GazelleClient;
 use rogue_logging::Error;
 use tokio::fs::File;
 use tokio::io::AsyncWriteExt;
+use tokio::task::spawn_blocking;
 
 /// Verify a FLAC source is suitable for transcoding.
 #[injectable]
 pub struct VerifyCommand {
     arg: Ref<SourceArg>,
     shared_options: Ref<SharedOptions>,
+    naming_options: Ref<NamingOptions>,
     verify_options: Ref<VerifyOptions>,
+    loudness_options: Ref<LoudnessOptions>,
+    tag_options: Ref<TagOptions>,
+    target_options: Ref<TargetOptions>,
     source_provider: RefMut<SourceProvider>,
     api: RefMut<GazelleClient>,
     targets: Ref<TargetFormatProvider>,
     paths: Ref<PathManager>,
+    scheduler: Ref<Scheduler>,
 }
 
 impl VerifyCommand {
@@ -41,7 +52,10 @@ impl VerifyCommand {
     pub async fn execute_cli(&mut self) -> Result<bool, Error> {
         if !self.arg.validate()
             || !self.shared_options.validate()
+            || !self.naming_options.validate()
             || !self.verify_options.validate()
+            || !self.loudness_options.validate()
+            || !self.tag_options.validate()
         {
             return Ok(false);
         }
@@ -55,6 +69,11 @@ impl VerifyCommand {
             Ok(source) => (self.execute(&source).await, source.to_string()),
             Err(issue) => (VerifyStatus::from_issue(issue), "unknown".to_owned()),
         };
+        if let Some(warnings) = &status.warnings {
+            for warning in warnings {
+                warn!("{warning}");
+            }
+        }
         if status.verified {
             info!("{} {id}", "Verified".bold());
         } else {
@@ -76,9 +95,28 @@ impl VerifyCommand {
         debug!("{} {}", "Verifying".bold(), source);
         let mut issues: Vec<SourceIssue> = Vec::new();
         issues.append(&mut self.api_checks(source));
-        issues.append(&mut self.flac_checks(source));
+        issues.append(&mut self.flac_checks(source).await);
         issues.append(&mut self.hash_check(source).await);
-        VerifyStatus::from_issues(issues)
+        let (mut checksum_warnings, mut log_issues): (Vec<_>, Vec<_>) = log_checks(source)
+            .await
+            .into_iter()
+            .partition(|issue| matches!(issue, LogChecksumMissing { .. }));
+        issues.append(&mut log_issues);
+        let spectrum = self.spectrum_checks(source).await;
+        let mut warnings = Vec::new();
+        for (check, mut check_issues) in [
+            (self.verify_options.lossy_check, spectrum.lossy),
+            (self.verify_options.resolution_check, spectrum.resolution),
+        ] {
+            if check == Some(CheckPolicy::Fail) {
+                issues.append(&mut check_issues);
+            } else {
+                warnings.append(&mut check_issues);
+            }
+        }
+        warnings.append(&mut checksum_warnings);
+        warnings.append(&mut self.loudness_checks(source).await);
+        VerifyStatus::from_issues(issues).with_warnings(warnings)
     }
 
     /// Validate the source against the API.
@@ -126,12 +164,15 @@ impl VerifyCommand {
         issues
     }
 
+    /// Check the path length, tags and stream of each FLAC.
+    ///
+    /// The files are read on blocking threads, limited by the IO budget of the [`Scheduler`].
     #[allow(
         clippy::cast_sign_loss,
         clippy::cast_possible_wrap,
         clippy::as_conversions
     )]
-    fn flac_checks(&self, source: &Source) -> Vec<SourceIssue> {
+    async fn flac_checks(&self, source: &Source) -> Vec<SourceIssue> {
         if !source.directory.is_dir() {
             return vec![MissingDirectory {
                 path: source.directory.clone(),
@@ -155,7 +196,31 @@ impl VerifyCommand {
             .targets
             .get_max_path_length(source.format, &source.existing);
         let mut too_long = false;
-        for flac in flacs {
+        let fix_tags = self.tag_options.fix_tags.expect("fix_tags should be set");
+        let shorten_paths = self
+            .target_options
+            .shorten_paths
+            .expect("shorten_paths should be set");
+        let reads = flacs.into_iter().map(|flac| async {
+            let _permit = self.scheduler.acquire_io().await;
+            spawn_blocking(move || {
+                let tags = get_vorbis_tags(&flac);
+                let stream_issues = StreamVerifier::execute(&flac);
+                (flac, tags, stream_issues)
+            })
+            .await
+        });
+        for read in join_all(reads).await {
+            let (flac, tags, stream_issues) = match read {
+                Ok(read) => read,
+                Err(e) => {
+                    issues.push(SourceIssue::Error {
+                        domain: "FLAC".to_owned(),
+                        details: e.to_string(),
+                    });
+                    continue;
+                }
+            };
             if let Some(max_path) = max_target {
                 let path = self.paths.get_transcode_path(source, max_path, &flac);
                 let length = path.to_string_lossy().len() as isize;
@@ -163,76 +228,131 @@ impl VerifyCommand {
                 if excess > 0 {
                     let excess = excess as usize;
                     issues.push(Length { path, excess });
-                    Shortener::suggest_track_name(&flac);
                     too_long = true;
+                    if !shorten_paths {
+                        Shortener::suggest_track_name(&flac);
+                    }
                 }
             }
-            let tags = TagVerifier::execute(&flac, source)
-                .unwrap_or(vec!["failed to retrieve tags".to_owned()]);
+            let tags = tags.map_or(vec!["failed to retrieve tags".to_owned()], |tags| {
+                TagVerifier::get_missing(tags, &flac, source, fix_tags)
+            });
             if !tags.is_empty() {
                 issues.push(MissingTags {
                     path: flac.path.clone(),
                     tags,
                 });
             }
-            for error in StreamVerifier::execute(&flac) {
+            for error in stream_issues {
                 issues.push(error);
             }
         }
-        if too_long {
+        if too_long && !shorten_paths {
             Shortener::suggest_album_name(source);
         }
         issues
     }
 
-    async fn hash_check(&mut self, source: &Source) -> Vec<SourceIssue> {
-        if self
+    /// Analyze the spectrum of each FLAC for a cutoff typical of a lossy source,
+    /// and for padding or upsampling of a high resolution source.
+    ///
+    /// Each FLAC is decoded once on a blocking thread, limited by the CPU budget of
+    /// the [`Scheduler`].
+    async fn spectrum_checks(&self, source: &Source) -> SpectrumIssues {
+        let lossy = self
             .verify_options
-            .no_hash_check
-            .expect("no_hash_check should be set")
-        {
-            debug!("{} hash check due to settings", "Skipped".bold());
-            return Vec::new();
+            .lossy_check
+            .expect("lossy_check should be set")
+            != CheckPolicy::Skip;
+        let resolution = self
+            .verify_options
+            .resolution_check
+            .expect("resolution_check should be set")
+            != CheckPolicy::Skip;
+        let mut issues = SpectrumIssues::default();
+        if (!lossy && !resolution) || !source.directory.is_dir() {
+            return issues;
         }
-        let mut api = self.api.write().expect("API should be available");
-        let torrent_path = self.paths.get_source_torrent_path(source);
-        if !torrent_path.is_file() {
-            trace!(
-                "{} torrent file as it's not cached: {}",
-                "Downloading".bold(),
-                torrent_path.display()
-            );
-            let mut file = match File::create_new(&torrent_path).await {
-                Ok(file) => file,
-                Err(e) => {
-                    return vec![SourceIssue::Error {
-                        domain: "File System".to_owned(),
-                        details: e.to_string(),
-                    }]
+        let tasks = Collector::get_flacs(&source.directory)
+            .into_iter()
+            .map(|flac| {
+                self.scheduler
+                    .execute_blocking("analyze spectrum", move || {
+                        verify_spectrum(&flac, lossy, resolution)
+                    })
+            });
+        for result in join_all(tasks).await {
+            match result {
+                Ok(mut flac_issues) => {
+                    issues.lossy.append(&mut flac_issues.lossy);
+                    issues.resolution.append(&mut flac_issues.resolution);
                 }
-            };
-            let buffer = match api.get_torrent_file_as_buffer(source.torrent.id).await {
-                Ok(buffer) => buffer,
-                Err(e) => {
-                    return vec![SourceIssue::Error {
-                        domain: "API".to_owned(),
+                Err(e) => issues.push_error(
+                    SourceIssue::Error {
+                        domain: "Spectrum".to_owned(),
                         details: e.to_string(),
-                    }]
-                }
-            };
-            if let Err(e) = file.write_all(&buffer).await {
-                return vec![SourceIssue::Error {
-                    domain: "File System".to_owned(),
-                    details: e.to_string(),
-                }];
+                    },
+                    lossy,
+                ),
             }
-            if let Err(e) = file.flush().await {
+        }
+        issues
+    }
+
+    /// Analyze the loudness of each FLAC for clipping and low peak levels.
+    ///
+    /// Issues are informational so are returned as warnings.
+    async fn loudness_checks(&self, source: &Source) -> Vec<SourceIssue> {
+        if !self
+            .loudness_options
+            .loudness_check
+            .expect("loudness_check should be set")
+            || !source.directory.is_dir()
+        {
+            return Vec::new();
+        }
+        let flacs = Collector::get_flacs(&source.directory);
+        let tracks = match analyze_flacs(&flacs, &self.scheduler).await {
+            Ok(tracks) => tracks,
+            Err(e) => {
                 return vec![SourceIssue::Error {
-                    domain: "File System".to_owned(),
+                    domain: "Loudness".to_owned(),
                     details: e.to_string(),
-                }];
+                }]
+            }
+        };
+        let mut issues = Vec::new();
+        for (flac, track) in flacs.iter().zip(tracks) {
+            if track.clipped > 0 {
+                issues.push(Clipping {
+                    path: flac.path.clone(),
+                    samples: track.clipped,
+                });
+            }
+            let peak = track.get_peak_db();
+            if peak < MIN_PEAK_DB {
+                issues.push(LowPeak {
+                    path: flac.path.clone(),
+                    peak,
+                });
             }
         }
+        issues
+    }
+
+    async fn hash_check(&self, source: &Source) -> Vec<SourceIssue> {
+        if self
+            .verify_options
+            .no_hash_check
+            .expect("no_hash_check should be set")
+        {
+            debug!("{} hash check due to settings", "Skipped".bold());
+            return Vec::new();
+        }
+        if let Err(issue) = self.download_torrent(source).await {
+            return vec![issue];
+        }
+        let torrent_path = self.paths.get_source_torrent_path(source);
         ImdlCommand::verify(&torrent_path, &source.directory)
             .await
             .unwrap_or_else(|e| {
@@ -243,4 +363,71 @@ impl VerifyCommand {
             })
             .map_or_else(Vec::new, |x| vec![x])
     }
+
+    /// Download the torrent file of the source for the hash check unless it's cached.
+    ///
+    /// This is the only API call made by [`VerifyCommand::execute`] so callers that
+    /// serialize API calls can call this first, after which `execute` makes no API calls.
+    pub async fn download_torrent(&self, source: &Source) -> Result<(), SourceIssue> {
+        let torrent_path = self.paths.get_source_torrent_path(source);
+        if torrent_path.is_file()
+            || self
+                .verify_options
+                .no_hash_check
+                .expect("no_hash_check should be set")
+        {
+            return Ok(());
+        }
+        trace!(
+            "{} torrent file as it's not cached: {}",
+            "Downloading".bold(),
+            torrent_path.display()
+        );
+        let buffer = self
+            .api
+            .write()
+            .expect("API should be available")
+            .get_torrent_file_as_buffer(source.torrent.id)
+            .await
+            .map_err(|e| SourceIssue::Error {
+                domain: "API".to_owned(),
+                details: e.to_string(),
+            })?;
+        let mut file = File::create_new(&torrent_path)
+            .await
+            .map_err(|e| SourceIssue::Error {
+                domain: "File System".to_owned(),
+                details: e.to_string(),
+            })?;
+        file.write_all(&buffer)
+            .await
+            .map_err(|e| SourceIssue::Error {
+                domain: "File System".to_owned(),
+                details: e.to_string(),
+            })?;
+        file.flush().await.map_err(|e| SourceIssue::Error {
+            domain: "File System".to_owned(),
+            details: e.to_string(),
+        })
+    }
+}
+
+/// Verify the rip logs and cue sheets of a CD source.
+///
+/// A missing log checksum is common for older rippers so it should be a warning.
+///
+/// The files are read on a blocking thread.
+async fn log_checks(source: &Source) -> Vec<SourceIssue> {
+    if source.torrent.media != "CD" || !source.directory.is_dir() {
+        return Vec::new();
+    }
+    let directory = source.directory.clone();
+    spawn_blocking(move || LogVerifier::execute(&directory))
+        .await
+        .unwrap_or_else(|e| {
+            vec![SourceIssue::Error {
+                domain: "Log".to_owned(),
+                details: e.to_string(),
+            }]
+        })
 }
@@ -7,6 +7,9 @@ pub struct VerifyStatus {
     pub verified: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub issues: Option<Vec<SourceIssue>>,
+    /// Issues that did not prevent verification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<SourceIssue>>,
     pub completed: TimeStamp,
 }
 
@@ -15,6 +18,7 @@ impl VerifyStatus {
         Self {
             verified: true,
             issues: None,
+            warnings: None,
             completed: TimeStamp::now(),
         }
     }
@@ -25,6 +29,7 @@ impl VerifyStatus {
             Self {
                 verified: false,
                 issues: Some(issues),
+                warnings: None,
                 completed: TimeStamp::now(),
             }
         }
@@ -33,7 +38,16 @@ impl VerifyStatus {
         Self {
             verified: false,
             issues: Some(vec![issue]),
+            warnings: None,
             completed: TimeStamp::now(),
         }
     }
+
+    #[must_use]
+    pub fn with_warnings(mut self, warnings: Vec<SourceIssue>) -> Self {
+        if !warnings.is_empty() {
+            self.warnings = Some(warnings);
+        }
+        self
+    }
 }